@@ -10,6 +10,7 @@ use activitystreams::{
     collection::{AsCollection, AsCollectionPage, Collection, CollectionPage},
     markers,
     object::{ApObject, AsApObject, AsObject, Object},
+    validate::{Validate, Violations},
 };
 
 impl<Inner, A, B> markers::Base for Ext2<Inner, A, B> where Inner: markers::Base {}
@@ -230,3 +231,12 @@ where
         self.inner.question_mut()
     }
 }
+
+impl<Inner, A, B> Validate for Ext2<Inner, A, B>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations)
+    }
+}
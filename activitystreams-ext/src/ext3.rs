@@ -10,6 +10,7 @@ use activitystreams::{
     collection::{AsCollection, AsCollectionPage, Collection, CollectionPage},
     markers,
     object::{ApObject, AsApObject, AsObject, Object},
+    validate::{Validate, Violations},
 };
 
 impl<Inner, A, B, C> markers::Base for Ext3<Inner, A, B, C> where Inner: markers::Base {}
@@ -233,3 +234,12 @@ where
         self.inner.question_mut()
     }
 }
+
+impl<Inner, A, B, C> Validate for Ext3<Inner, A, B, C>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations)
+    }
+}
@@ -10,6 +10,7 @@ use activitystreams::{
     collection::{AsCollection, AsCollectionPage, Collection, CollectionPage},
     markers,
     object::{ApObject, AsApObject, AsObject, Object},
+    validate::{Validate, Violations},
 };
 
 impl<Inner, A, B, C, D> markers::Base for Ext4<Inner, A, B, C, D> where Inner: markers::Base {}
@@ -236,3 +237,12 @@ where
         self.inner.question_mut()
     }
 }
+
+impl<Inner, A, B, C, D> Validate for Ext4<Inner, A, B, C, D>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations)
+    }
+}
@@ -97,6 +97,13 @@ mod ext1;
 mod ext2;
 mod ext3;
 mod ext4;
+pub mod toot;
+
+#[doc(hidden)]
+pub mod __private {
+    pub use activitystreams::unparsed::UnparsedMutExt;
+    pub use serde_json::Error as JsonError;
+}
 
 /// Transform types from and into the Unparsed structure
 pub trait UnparsedExtension<U>
@@ -114,8 +121,117 @@ where
     fn try_into_unparsed(self, unparsed_mut: &mut U) -> Result<(), Self::Error>;
 }
 
+/// Add an extension to a type, producing an `Ext1`
+///
+/// This is implemented for every type that keeps its unknown properties, so an extension can be
+/// attached at the end of a chain of setters. Further extensions are added with `Ext1::extend`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{actor::{ApActor, Person}, iri, prelude::*};
+/// use activitystreams_ext::{toot::ActorFlags, Ext1, ExtendWith};
+///
+/// let person = ApActor::new(iri!("https://example.com/users/asonix/inbox"), Person::new())
+///     .with_preferred_username("asonix")
+///     .extend_with(ActorFlags {
+///         indexable: Some(true.into()),
+///         ..ActorFlags::new()
+///     });
+///
+/// let any_base = person.into_any_base()?;
+/// let person = Ext1::<ApActor<Person>, ActorFlags>::from_any_base(any_base)?
+///     .expect("The actor is a Person");
+///
+/// assert_eq!(person.ext_one.indexable, Some(true.into()));
+/// assert_eq!(person.preferred_username(), Some("asonix"));
+/// # Ok(())
+/// # }
+/// ```
+pub trait ExtendWith: UnparsedMut + Sized {
+    /// Wrap self in an `Ext1` with the given extension
+    fn extend_with<A>(self, ext_one: A) -> Ext1<Self, A>
+    where
+        A: UnparsedExtension<Self>,
+    {
+        Ext1::new(self, ext_one)
+    }
+}
+
+impl<T> ExtendWith for T where T: UnparsedMut {}
+
+/// Implement `UnparsedExtension` for a struct by mapping each of its fields to a JSON key
+///
+/// This lets an extension be written as a plain struct of the fields it adds to a type, like
+/// "a Person plus a public key and a featured collection", and combined with the extended type
+/// through `Ext1`. Every method of the extended type stays available on the combination.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     actor::{ApActor, Person},
+///     iri_string::types::IriString,
+///     prelude::*,
+/// };
+/// use activitystreams_ext::{unparsed_extension, Ext1};
+///
+/// #[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+/// #[serde(rename_all = "camelCase")]
+/// pub struct Featured {
+///     featured: Option<IriString>,
+///     featured_tags: Option<IriString>,
+/// }
+///
+/// unparsed_extension!(Featured {
+///     featured: "featured",
+///     featured_tags: "featuredTags",
+/// });
+///
+/// let actor: ApActor<Person> = serde_json::from_str(r#"{
+///     "type": "Person",
+///     "inbox": "https://example.com/users/asonix/inbox",
+///     "featuredTags": "https://example.com/users/asonix/tags"
+/// }"#)?;
+///
+/// let person = Ext1::<ApActor<Person>, Featured>::from_any_base(actor.into_any_base()?)?
+///     .expect("The actor is a Person");
+///
+/// assert!(person.ext_one.featured.is_none());
+/// assert_eq!(
+///     person.ext_one.featured_tags.as_ref().map(|iri| iri.as_str()),
+///     Some("https://example.com/users/asonix/tags")
+/// );
+/// assert_eq!(
+///     person.inbox_unchecked().as_str(),
+///     "https://example.com/users/asonix/inbox"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! unparsed_extension {
+    ($ty:ident { $($field:ident: $key:expr),* $(,)? }) => {
+        impl<U> $crate::UnparsedExtension<U> for $ty
+        where
+            U: $crate::__private::UnparsedMutExt,
+        {
+            type Error = $crate::__private::JsonError;
+
+            fn try_from_unparsed(unparsed_mut: &mut U) -> ::core::result::Result<Self, Self::Error> {
+                ::core::result::Result::Ok($ty {
+                    $($field: unparsed_mut.remove($key)?,)*
+                })
+            }
+
+            fn try_into_unparsed(self, unparsed_mut: &mut U) -> ::core::result::Result<(), Self::Error> {
+                $(unparsed_mut.insert($key, self.$field)?;)*
+                ::core::result::Result::Ok(())
+            }
+        }
+    };
+}
+
 /// Extend a type with a single value
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Ext1<Inner, A> {
     #[serde(flatten)]
     pub ext_one: A,
@@ -126,7 +242,7 @@ pub struct Ext1<Inner, A> {
 }
 
 /// Extend a type with two values
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Ext2<Inner, A, B> {
     #[serde(flatten)]
     pub ext_one: A,
@@ -140,7 +256,7 @@ pub struct Ext2<Inner, A, B> {
 }
 
 /// Extend a type with three values
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Ext3<Inner, A, B, C> {
     #[serde(flatten)]
     pub ext_one: A,
@@ -157,7 +273,7 @@ pub struct Ext3<Inner, A, B, C> {
 }
 
 /// Extend a type with four values
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Ext4<Inner, A, B, C, D> {
     #[serde(flatten)]
     pub ext_one: A,
@@ -0,0 +1,98 @@
+//! Extensions for properties Mastodon adds to actors
+//!
+//! Mastodon binds these properties to the `http://joinmastodon.org/ns#` namespace, and its
+//! `@context` maps them to bare terms, so they're read and written as `suspended`, `memorial`,
+//! and `indexable`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{
+//!     actor::{ApActor, Person},
+//!     prelude::*,
+//! };
+//! use activitystreams_ext::{toot::ActorFlags, Ext1};
+//!
+//! type MastodonPerson = Ext1<ApActor<Person>, ActorFlags>;
+//!
+//! let person: MastodonPerson = serde_json::from_str(r#"{
+//!     "type": "Person",
+//!     "inbox": "https://example.com/users/asonix/inbox",
+//!     "preferredUsername": "asonix",
+//!     "memorial": true,
+//!     "indexable": false
+//! }"#)?;
+//!
+//! assert!(!person.ext_one.is_suspended());
+//! assert!(person.ext_one.is_memorial());
+//! assert!(!person.ext_one.is_indexable());
+//! assert_eq!(person.preferred_username(), Some("asonix"));
+//!
+//! let round_tripped: MastodonPerson = serde_json::from_str(&serde_json::to_string(&person)?)?;
+//! assert_eq!(round_tripped, person);
+//! # Ok(())
+//! # }
+//! ```
+use activitystreams::primitives::XsdBoolean;
+
+/// Moderation and discovery flags Mastodon sets on actors
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActorFlags {
+    /// Whether the actor has been suspended by their server
+    ///
+    /// - Range: `xsd:boolean`
+    /// - Functional: true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suspended: Option<XsdBoolean>,
+
+    /// Whether the actor's account has been turned into a memorial for someone who has passed
+    ///
+    /// - Range: `xsd:boolean`
+    /// - Functional: true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub memorial: Option<XsdBoolean>,
+
+    /// Whether the actor has opted in to their public posts being indexed for search
+    ///
+    /// - Range: `xsd:boolean`
+    /// - Functional: true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub indexable: Option<XsdBoolean>,
+}
+
+impl ActorFlags {
+    /// Create a new ActorFlags with no flags set
+    ///
+    /// ```rust
+    /// use activitystreams_ext::toot::ActorFlags;
+    ///
+    /// let flags = ActorFlags::new();
+    /// assert!(flags.suspended.is_none());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Whether the actor is suspended, treating a missing flag as `false`
+    pub fn is_suspended(&self) -> bool {
+        self.suspended.is_some_and(XsdBoolean::into_inner)
+    }
+
+    /// Whether the actor is a memorial, treating a missing flag as `false`
+    pub fn is_memorial(&self) -> bool {
+        self.memorial.is_some_and(XsdBoolean::into_inner)
+    }
+
+    /// Whether the actor may be indexed, treating a missing flag as `false`
+    ///
+    /// Mastodon only indexes actors that have explicitly opted in.
+    pub fn is_indexable(&self) -> bool {
+        self.indexable.is_some_and(XsdBoolean::into_inner)
+    }
+}
+
+crate::unparsed_extension!(ActorFlags {
+    suspended: "suspended",
+    memorial: "memorial",
+    indexable: "indexable",
+});
@@ -45,9 +45,75 @@ pub fn security_iri() -> iri_string::types::IriString {
     "https://w3id.org/security/v1".parse().unwrap()
 }
 
+/// The error type produced when a string doesn't match the kind it was parsed as
+#[derive(Clone, Debug)]
+pub struct KindError {
+    expected: &'static str,
+    found: String,
+}
+
+impl KindError {
+    #[doc(hidden)]
+    pub fn new(expected: &'static str, found: &str) -> Self {
+        KindError {
+            expected,
+            found: found.to_owned(),
+        }
+    }
+}
+
+impl std::fmt::Display for KindError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Expected kind {}, found {}", self.expected, self.found)
+    }
+}
+
+impl std::error::Error for KindError {}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+
+    use std::{fmt, marker::PhantomData, str::FromStr};
+
+    struct KindVisitor<T>(PhantomData<T>);
+
+    impl<'de, T> serde::de::Visitor<'de> for KindVisitor<T>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        type Value = T;
+
+        fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            f.write_str("a kind string")
+        }
+
+        fn visit_str<E>(self, s: &str) -> Result<Self::Value, E>
+        where
+            E: serde::de::Error,
+        {
+            s.parse().map_err(E::custom)
+        }
+    }
+
+    pub fn deserialize_kind<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        deserializer.deserialize_str(KindVisitor(PhantomData))
+    }
+}
+
 /// Generate an enum implementing serde's Serialize and Deserialize with a single variant
 ///
-/// This is useful for describing constants
+/// This is useful for describing constants. The enum also implements `Display`, `FromStr`, and
+/// `TryFrom<&str>`, and exposes its kind as `KIND`, for use outside of serde.
+///
+/// The generated code only refers to items through this crate, so it works in crates that don't
+/// depend on serde directly, or that reach this macro through a re-export.
 ///
 /// ```rust
 /// # fn main() -> Result<(), anyhow::Error> {
@@ -64,39 +130,220 @@ pub fn security_iri() -> iri_string::types::IriString {
 /// let s: MyStruct = serde_json::from_str(r#"{"type":"Custom"}"#)?;
 ///
 /// assert_eq!(s.kind, CustomType::Custom);
+/// assert_eq!(CustomType::KIND, "Custom");
+/// assert_eq!("Custom".parse::<CustomType>()?, CustomType::Custom);
+/// assert!("custom".parse::<CustomType>().is_err());
 /// # Ok(())
 /// # }
 /// ```
+///
+/// Other spellings of the kind, like the lowercase or legacy names some implementations send, can
+/// be accepted when deserializing. The kind is always serialized as its canonical name.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams_kinds::kind;
+///
+/// kind!(PostType, Note, aliases = ["note", "post"]);
+///
+/// let kind: PostType = serde_json::from_str(r#""post""#)?;
+///
+/// assert_eq!(kind, PostType::Note);
+/// assert_eq!(serde_json::to_string(&kind)?, r#""Note""#);
+/// assert_eq!("note".parse::<PostType>()?, PostType::Note);
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Anything other than a type name and a kind is rejected with an error explaining the expected
+/// input.
+///
+/// ```rust,compile_fail
+/// use activitystreams_kinds::kind;
+///
+/// kind!(CustomType, "Custom");
+/// ```
 #[macro_export]
 macro_rules! kind {
-    ($x:ident, $y:ident) => {
-        #[derive(
-            Clone,
-            Debug,
-            Eq,
-            Hash,
-            Ord,
-            PartialEq,
-            PartialOrd,
-            serde::Deserialize,
-            serde::Serialize,
-        )]
-        /// A type stand-in for the constant $y, deriving serde traits
+    ($x:ident, $y:ident $(, aliases = [$($alias:literal),* $(,)?])? $(,)?) => {
+        #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        /// A type stand-in for the constant $y, implementing serde traits
         pub enum $x {
             $y,
         }
 
-        impl std::fmt::Display for $x {
-            fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-                write!(f, stringify!($y))
+        #[allow(dead_code)]
+        impl $x {
+            /// The kind, as it appears in the `type` field
+            pub const KIND: &'static str = ::core::stringify!($y);
+        }
+
+        impl ::core::fmt::Display for $x {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.write_str(Self::KIND)
+            }
+        }
+
+        impl ::core::str::FromStr for $x {
+            type Err = $crate::KindError;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                match s {
+                    ::core::stringify!($y) $($(| $alias)*)? => ::core::result::Result::Ok($x::$y),
+                    _ => ::core::result::Result::Err($crate::KindError::new(Self::KIND, s)),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<&str> for $x {
+            type Error = $crate::KindError;
+
+            fn try_from(s: &str) -> ::core::result::Result<Self, Self::Error> {
+                s.parse()
             }
         }
 
-        impl Default for $x {
+        impl ::core::default::Default for $x {
             fn default() -> Self {
                 $x::$y
             }
         }
+
+        impl $crate::__private::serde::Serialize for $x {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(Self::KIND)
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $x {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::__private::deserialize_kind(deserializer)
+            }
+        }
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "kind! expects a type name, a kind identifier, and optional aliases, like `kind!(CustomType, Custom, aliases = [\"custom\"])`"
+        );
+    };
+}
+
+/// Generate an enum of several kinds, with a catch-all for kinds it doesn't know
+///
+/// Custom vocabularies often have many types, and a `type` field in them may hold any of them.
+/// Each listed kind becomes a variant, serialized as its own name, or as the string given after
+/// `=`. Any other string is kept in the `Unknown` variant rather than failing to deserialize, so
+/// parsing with `FromStr` never fails.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams_kinds::kind_enum;
+///
+/// kind_enum!(TagType { Emoji, Hashtag, Property = "PropertyValue" });
+///
+/// #[derive(serde::Deserialize, serde::Serialize)]
+/// struct Tag {
+///     #[serde(rename = "type")]
+///     kind: TagType,
+/// }
+///
+/// let tag: Tag = serde_json::from_str(r#"{"type":"PropertyValue"}"#)?;
+/// assert_eq!(tag.kind, TagType::Property);
+///
+/// let tag: Tag = serde_json::from_str(r#"{"type":"Mention"}"#)?;
+/// assert_eq!(tag.kind, TagType::Unknown("Mention".to_owned()));
+/// assert_eq!(serde_json::to_string(&tag)?, r#"{"type":"Mention"}"#);
+///
+/// assert_eq!("Emoji".parse::<TagType>()?, TagType::Emoji);
+/// assert_eq!(TagType::Property.to_string(), "PropertyValue");
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! kind_enum {
+    (@name $variant:ident) => {
+        ::core::stringify!($variant)
+    };
+    (@name $variant:ident $name:literal) => {
+        $name
+    };
+    ($x:ident { $($variant:ident $(= $name:literal)?),+ $(,)? }) => {
+        #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        /// A type stand-in for a set of kinds, keeping any other kind as a string
+        pub enum $x {
+            $(
+                $variant,
+            )+
+            /// A kind that isn't one of the known variants
+            Unknown(::std::string::String),
+        }
+
+        #[allow(dead_code)]
+        impl $x {
+            /// The kind, as it appears in the `type` field
+            pub fn as_str(&self) -> &str {
+                match self {
+                    $(
+                        $x::$variant => $crate::kind_enum!(@name $variant $($name)?),
+                    )+
+                    $x::Unknown(s) => s,
+                }
+            }
+        }
+
+        impl ::core::fmt::Display for $x {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                f.write_str(self.as_str())
+            }
+        }
+
+        impl ::core::str::FromStr for $x {
+            type Err = ::core::convert::Infallible;
+
+            fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                ::core::result::Result::Ok(<$x as ::core::convert::From<&str>>::from(s))
+            }
+        }
+
+        impl ::core::convert::From<&str> for $x {
+            fn from(s: &str) -> Self {
+                match s {
+                    $(
+                        $crate::kind_enum!(@name $variant $($name)?) => $x::$variant,
+                    )+
+                    _ => $x::Unknown(s.into()),
+                }
+            }
+        }
+
+        impl $crate::__private::serde::Serialize for $x {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                serializer.serialize_str(self.as_str())
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $x {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                $crate::__private::deserialize_kind(deserializer)
+            }
+        }
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "kind_enum! expects a type name and a list of kinds, like `kind_enum!(CustomType { Custom, Other = \"other\" })`"
+        );
     };
 }
 
@@ -106,8 +353,6 @@ pub mod activity {
     //! These types exist only to be statically-typed versions of the associated string. e.g.
     //! `CreateType` -> `"Create"`
 
-    use crate::kind;
-
     kind!(ActivityType, Activity);
     kind!(AcceptType, Accept);
     kind!(AddType, Add);
@@ -145,8 +390,6 @@ pub mod actor {
     //! These types exist only to be statically-typed versions of the associated string. e.g.
     //! `PersonType` -> `"Person"`
 
-    use crate::kind;
-
     kind!(ApplicationType, Application);
     kind!(GroupType, Group);
     kind!(OrganizationType, Organization);
@@ -160,8 +403,6 @@ pub mod collection {
     //! These types exist only to be statically-typed versions of the associated string. e.g.
     //! `CollectionType` -> `"Collection"`
 
-    use crate::kind;
-
     kind!(CollectionType, Collection);
     kind!(OrderedCollectionType, OrderedCollection);
     kind!(CollectionPageType, CollectionPage);
@@ -174,8 +415,6 @@ pub mod link {
     //! These types exist only to be statically-typed versions of the associated string. e.g.
     //! `MentionType` -> `"Mention"`
 
-    use crate::kind;
-
     kind!(LinkType, Link);
     kind!(MentionType, Mention);
 }
@@ -186,8 +425,6 @@ pub mod object {
     //! These types exist only to be statically-typed versions of the associated string. e.g.
     //! `PlaceType` -> `"Place"`
 
-    use crate::kind;
-
     kind!(ObjectType, Object);
     kind!(ArticleType, Article);
     kind!(AudioType, Audio);
@@ -205,12 +442,49 @@ pub mod object {
 
 #[cfg(test)]
 mod tests {
-    use super::kind;
-
     #[test]
     fn to_string_works() {
         kind!(MyType, My);
 
         assert_eq!(MyType::My.to_string(), "My")
     }
+
+    #[test]
+    fn from_str_matches_kind() {
+        kind!(MyType, My, aliases = ["my"]);
+
+        assert_eq!(MyType::KIND.parse::<MyType>().unwrap(), MyType::My);
+        assert_eq!("my".parse::<MyType>().unwrap(), MyType::My);
+        assert!("MY".parse::<MyType>().is_err());
+    }
+
+    mod shadowed {
+        #![allow(dead_code)]
+
+        mod serde {}
+        mod std {}
+
+        kind!(ShadowedType, Shadowed, aliases = ["shadowed"]);
+        kind_enum!(ShadowedKind { Shadowed, Other = "other" });
+    }
+
+    #[test]
+    fn macros_dont_rely_on_caller_paths() {
+        use shadowed::{ShadowedKind, ShadowedType};
+
+        let kind: ShadowedType = serde_json::from_str(r#""shadowed""#).unwrap();
+        assert_eq!(serde_json::to_string(&kind).unwrap(), r#""Shadowed""#);
+
+        let kind: ShadowedKind = serde_json::from_str(r#""other""#).unwrap();
+        assert_eq!(kind, ShadowedKind::Other);
+    }
+
+    #[test]
+    fn kind_enum_round_trips_unknown() {
+        kind_enum!(MyKind { My, Other = "other" });
+
+        assert_eq!("other".parse::<MyKind>().unwrap(), MyKind::Other);
+        assert_eq!(MyKind::from("Other"), MyKind::Unknown("Other".to_owned()));
+        assert_eq!(MyKind::Unknown("Other".to_owned()).to_string(), "Other");
+    }
 }
@@ -0,0 +1,146 @@
+use activitystreams::{activity::AnyActivity, actor::AnyActor};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+// A Create activity as delivered by Mastodon to a remote inbox
+const CREATE_NOTE: &str = r##"{
+  "@context": [
+    "https://www.w3.org/ns/activitystreams",
+    {
+      "ostatus": "http://ostatus.org#",
+      "atomUri": "ostatus:atomUri",
+      "inReplyToAtomUri": "ostatus:inReplyToAtomUri",
+      "conversation": "ostatus:conversation",
+      "sensitive": "as:sensitive",
+      "toot": "http://joinmastodon.org/ns#",
+      "votersCount": "toot:votersCount",
+      "Hashtag": "as:Hashtag"
+    }
+  ],
+  "id": "https://mastodon.example/users/asonix/statuses/109563466534245632/activity",
+  "type": "Create",
+  "actor": "https://mastodon.example/users/asonix",
+  "published": "2022-12-21T18:04:12Z",
+  "to": ["https://www.w3.org/ns/activitystreams#Public"],
+  "cc": [
+    "https://mastodon.example/users/asonix/followers",
+    "https://other.example/users/friend"
+  ],
+  "object": {
+    "id": "https://mastodon.example/users/asonix/statuses/109563466534245632",
+    "type": "Note",
+    "summary": null,
+    "inReplyTo": "https://other.example/users/friend/statuses/109563401234567890",
+    "published": "2022-12-21T18:04:12Z",
+    "url": "https://mastodon.example/@asonix/109563466534245632",
+    "attributedTo": "https://mastodon.example/users/asonix",
+    "to": ["https://www.w3.org/ns/activitystreams#Public"],
+    "cc": [
+      "https://mastodon.example/users/asonix/followers",
+      "https://other.example/users/friend"
+    ],
+    "sensitive": false,
+    "atomUri": "https://mastodon.example/users/asonix/statuses/109563466534245632",
+    "inReplyToAtomUri": "https://other.example/users/friend/statuses/109563401234567890",
+    "conversation": "tag:other.example,2022-12-21:objectId=1234567:objectType=Conversation",
+    "content": "<p><span class=\"h-card\"><a href=\"https://other.example/@friend\" class=\"u-url mention\">@<span>friend</span></a></span> sounds great, see you there! <a href=\"https://mastodon.example/tags/rust\" class=\"mention hashtag\" rel=\"tag\">#<span>rust</span></a></p>",
+    "contentMap": {
+      "en": "<p><span class=\"h-card\"><a href=\"https://other.example/@friend\" class=\"u-url mention\">@<span>friend</span></a></span> sounds great, see you there! <a href=\"https://mastodon.example/tags/rust\" class=\"mention hashtag\" rel=\"tag\">#<span>rust</span></a></p>"
+    },
+    "attachment": [],
+    "tag": [
+      {
+        "type": "Mention",
+        "href": "https://other.example/users/friend",
+        "name": "@friend@other.example"
+      },
+      {
+        "type": "Hashtag",
+        "href": "https://mastodon.example/tags/rust",
+        "name": "#rust"
+      }
+    ],
+    "replies": {
+      "id": "https://mastodon.example/users/asonix/statuses/109563466534245632/replies",
+      "type": "Collection",
+      "first": {
+        "type": "CollectionPage",
+        "next": "https://mastodon.example/users/asonix/statuses/109563466534245632/replies?only_other_accounts=true&page=true",
+        "partOf": "https://mastodon.example/users/asonix/statuses/109563466534245632/replies",
+        "items": []
+      }
+    }
+  }
+}"##;
+
+// An actor as served by Mastodon
+const PERSON: &str = r##"{
+  "@context": [
+    "https://www.w3.org/ns/activitystreams",
+    "https://w3id.org/security/v1",
+    {
+      "manuallyApprovesFollowers": "as:manuallyApprovesFollowers",
+      "toot": "http://joinmastodon.org/ns#",
+      "featured": {"@id": "toot:featured", "@type": "@id"},
+      "discoverable": "toot:discoverable",
+      "PropertyValue": "schema:PropertyValue",
+      "value": "schema:value"
+    }
+  ],
+  "id": "https://mastodon.example/users/asonix",
+  "type": "Person",
+  "following": "https://mastodon.example/users/asonix/following",
+  "followers": "https://mastodon.example/users/asonix/followers",
+  "inbox": "https://mastodon.example/users/asonix/inbox",
+  "outbox": "https://mastodon.example/users/asonix/outbox",
+  "featured": "https://mastodon.example/users/asonix/collections/featured",
+  "preferredUsername": "asonix",
+  "name": "asonix",
+  "summary": "<p>Writing rust and federating things</p>",
+  "url": "https://mastodon.example/@asonix",
+  "manuallyApprovesFollowers": false,
+  "discoverable": true,
+  "published": "2017-04-07T00:00:00Z",
+  "publicKey": {
+    "id": "https://mastodon.example/users/asonix#main-key",
+    "owner": "https://mastodon.example/users/asonix",
+    "publicKeyPem": "-----BEGIN PUBLIC KEY-----\nMIIBIjANBgkqhkiG9w0BAQEFAAOCAQ8AMIIBCgKCAQEAw7Bkfu5Nz4XqJ4B2Fm3V\nBf1j0oV5T1p1cA4Y6uE5n3l3Dk3Xm0k4aN5w8YxwQm4Xo8cQkzUjg1Lq5vQ0Sx6o\nc0lPp8m3YH3H3b8q1E6a4dYk8h1jH5m7S2c6w6a6yK5m1l8G0v3f7n2b6e8P0o9q\n-----END PUBLIC KEY-----\n"
+  },
+  "tag": [],
+  "attachment": [
+    {"type": "PropertyValue", "name": "Website", "value": "<a href=\"https://asonix.dog\">asonix.dog</a>"}
+  ],
+  "endpoints": {"sharedInbox": "https://mastodon.example/inbox"},
+  "icon": {"type": "Image", "mediaType": "image/png", "url": "https://mastodon.example/avatars/asonix.png"},
+  "image": {"type": "Image", "mediaType": "image/png", "url": "https://mastodon.example/headers/asonix.png"}
+}"##;
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("create_note");
+    group.bench_function("serde_json", |b| {
+        b.iter(|| serde_json::from_str::<AnyActivity>(black_box(CREATE_NOTE)).unwrap())
+    });
+    group.bench_function("simd_json", |b| {
+        b.iter_batched_ref(
+            || CREATE_NOTE.as_bytes().to_vec(),
+            |body| activitystreams::from_slice::<AnyActivity>(black_box(body)).unwrap(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+
+    let mut group = c.benchmark_group("person");
+    group.bench_function("serde_json", |b| {
+        b.iter(|| serde_json::from_str::<AnyActor>(black_box(PERSON)).unwrap())
+    });
+    group.bench_function("simd_json", |b| {
+        b.iter_batched_ref(
+            || PERSON.as_bytes().to_vec(),
+            |body| activitystreams::from_slice::<AnyActor>(black_box(body)).unwrap(),
+            criterion::BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
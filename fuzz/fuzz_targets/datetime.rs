@@ -0,0 +1,15 @@
+#![no_main]
+
+use activitystreams::primitives::XsdDateTime;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(datetime) = data.parse::<XsdDateTime>() {
+        let reparsed = datetime
+            .to_string()
+            .parse::<XsdDateTime>()
+            .expect("Formatted datetimes should parse");
+
+        assert_eq!(datetime, reparsed);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use activitystreams::primitives::XsdDuration;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(duration) = data.parse::<XsdDuration>() {
+        let reparsed = duration
+            .to_string()
+            .parse::<XsdDuration>()
+            .expect("Formatted durations should parse");
+
+        assert_eq!(duration, reparsed);
+    }
+});
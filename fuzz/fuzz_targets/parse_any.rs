@@ -0,0 +1,36 @@
+#![no_main]
+
+use activitystreams::{
+    activity::AnyActivity, actor::AnyActor, collection::AnyCollection, link::AnyLink,
+    object::AnyObject,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let any_base = match activitystreams::parse_any(data) {
+        Ok(any_base) => any_base,
+        Err(_) => return,
+    };
+
+    let _ = any_base.id();
+    let _ = any_base.kind_str();
+    let _ = serde_json::to_vec(&any_base);
+
+    if let Ok(Some(activity)) = AnyActivity::from_any_base(any_base.clone()) {
+        let _ = serde_json::to_vec(&activity);
+    }
+    if let Ok(Some(actor)) = AnyActor::from_any_base(any_base.clone()) {
+        let _ = actor.display_name(Some("en"));
+        let _ = serde_json::to_vec(&actor);
+    }
+    if let Ok(Some(collection)) = AnyCollection::from_any_base(any_base.clone()) {
+        let _ = collection.items_iter().count();
+        let _ = serde_json::to_vec(&collection);
+    }
+    if let Ok(Some(link)) = AnyLink::from_any_base(any_base.clone()) {
+        let _ = serde_json::to_vec(&link);
+    }
+    if let Ok(Some(object)) = AnyObject::from_any_base(any_base) {
+        let _ = serde_json::to_vec(&object);
+    }
+});
@@ -0,0 +1,15 @@
+#![no_main]
+
+use activitystreams::prelude::*;
+use iri_string::types::IriString;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: (&str, &str, &str)| {
+    let (iri, key, value) = data;
+
+    if let Ok(iri) = IriString::try_from(iri) {
+        let _ = iri.with_query_param(key, value);
+        let _ = iri.strip_fragment();
+        let _ = serde_json::to_string(&iri).and_then(|s| serde_json::from_str::<IriString>(&s));
+    }
+});
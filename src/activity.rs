@@ -1,5 +1,9 @@
 //! Types and traits for dealing with Activity attributes
 //!
+//! Every Activity also has a `Full*` alias, such as `FullCreate`, naming the `ApObject` that
+//! `ObjectExt::full` wraps it in to add the ActivityPub Object properties like `shares` and
+//! `likes`.
+//!
 //! ```rust
 //! # fn main() -> Result<(), anyhow::Error> {
 //! use activitystreams::{
@@ -23,13 +27,15 @@
 //! # }
 //! ```
 use crate::{
-    base::{AnyBase, AsBase, Base, Extends},
+    base::{AnyBase, AsBase, Base, Extends, ExtendsExt},
     checked::CheckError,
     markers,
     object::{ApObject, AsObject, Object},
     prelude::BaseExt,
-    primitives::{Either, OneOrMany, XsdBoolean, XsdDateTime},
+    primitives::{Either, Iter, OneOrMany, XsdBoolean, XsdDateTime},
+    registry::{KindRegistry, MaterializeError, Materialized},
     unparsed::{Unparsed, UnparsedMut, UnparsedMutExt},
+    validate::{Validate, Violations},
 };
 use iri_string::types::IriString;
 use std::convert::TryFrom;
@@ -161,6 +167,27 @@ pub trait ActivityExt: AsActivity {
         self.activity_ref().result.as_ref()
     }
 
+    /// Iterate over the result for the current activity
+    ///
+    /// This yields nothing if result isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::activity::Question;
+    /// # let mut question = Question::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for result in question.iter_result() {
+    ///     println!("{:?}", result);
+    /// }
+    /// ```
+    fn iter_result<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.result().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the result for the current activity
     ///
     /// This overwrites the contents of result
@@ -183,6 +210,29 @@ pub trait ActivityExt: AsActivity {
         self
     }
 
+    /// Set the result for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_result`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_result(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_result<T>(mut self, result: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_result(result);
+        self
+    }
+
     /// Set many results for the current activity
     ///
     /// This overwrites the contents of result
@@ -210,6 +260,33 @@ pub trait ActivityExt: AsActivity {
         self
     }
 
+    /// Set many results for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_results`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_many_results(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_results<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_results(items);
+        self
+    }
+
     /// Add a result to the current activity
     ///
     /// This does not overwrite the contents of result, only appends an item
@@ -297,6 +374,27 @@ pub trait ActivityExt: AsActivity {
         self.activity_ref().instrument.as_ref()
     }
 
+    /// Iterate over the instrument for the current activity
+    ///
+    /// This yields nothing if instrument isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::activity::Question;
+    /// # let mut question = Question::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for instrument in question.iter_instrument() {
+    ///     println!("{:?}", instrument);
+    /// }
+    /// ```
+    fn iter_instrument<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.instrument().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the instrument for the current activity
     ///
     /// This overwrites the contents of instrument
@@ -319,6 +417,29 @@ pub trait ActivityExt: AsActivity {
         self
     }
 
+    /// Set the instrument for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_instrument`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_instrument(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_instrument<T>(mut self, instrument: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_instrument(instrument);
+        self
+    }
+
     /// Set many instruments for the current activity
     ///
     /// This overwrites the contents of instrument
@@ -346,6 +467,33 @@ pub trait ActivityExt: AsActivity {
         self
     }
 
+    /// Set many instruments for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_instruments`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_many_instruments(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_instruments<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_instruments(items);
+        self
+    }
+
     /// Add a instrument to the current activity
     ///
     /// This does not overwrite the contents of instrument, only appends an item
@@ -500,6 +648,29 @@ pub trait AsActivityActorExt: AsActivityActor {
         self
     }
 
+    /// Set the actor for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_actor`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Create, iri};
+    /// # let create = Create::new(context(), context());
+    ///
+    /// let create = create.with_actor(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_actor<T>(mut self, actor: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_actor(actor);
+        self
+    }
+
     /// Set many actors for the current activity
     ///
     /// This overwrites the contents of actor
@@ -527,6 +698,33 @@ pub trait AsActivityActorExt: AsActivityActor {
         self
     }
 
+    /// Set many actors for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_actors`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Create, iri};
+    /// # let create = Create::new(context(), context());
+    ///
+    /// let create = create.with_many_actors(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_actors<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_actors(items);
+        self
+    }
+
     /// Add a actor to the current activity
     ///
     /// This does not overwrite the contents of actor, only appends an item
@@ -633,6 +831,29 @@ pub trait AsActivityObjectExt: AsActivityObject {
         self
     }
 
+    /// Set the object for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_object`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Create, iri};
+    /// # let create = Create::new(context(), context());
+    ///
+    /// let create = create.with_object(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_object<T>(mut self, object: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_object(object);
+        self
+    }
+
     /// Set many objects for the current activity
     ///
     /// This overwrites the contents of object
@@ -660,6 +881,33 @@ pub trait AsActivityObjectExt: AsActivityObject {
         self
     }
 
+    /// Set many objects for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_objects`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Create, iri};
+    /// # let create = Create::new(context(), context());
+    ///
+    /// let create = create.with_many_objects(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_objects<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_objects(items);
+        self
+    }
+
     /// Add a object to the current activity
     ///
     /// This does not overwrite the contents of object, only appends an item
@@ -726,6 +974,29 @@ pub trait AsTargetExt: AsTarget {
         self
     }
 
+    /// Set the target for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_target`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Invite, iri};
+    /// # let invite = Invite::new(context(), context(), context());
+    ///
+    /// let invite = invite.with_target(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_target<T>(mut self, target: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_target(target);
+        self
+    }
+
     /// Set many targets for the current activity
     ///
     /// This overwrites the contents of target
@@ -753,6 +1024,33 @@ pub trait AsTargetExt: AsTarget {
         self
     }
 
+    /// Set many targets for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_targets`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Invite, iri};
+    /// # let invite = Invite::new(context(), context(), context());
+    ///
+    /// let invite = invite.with_many_targets(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_targets<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_targets(items);
+        self
+    }
+
     /// Add a target to the current activity
     ///
     /// This does not overwrite the contents of target, only appends an item
@@ -819,6 +1117,29 @@ pub trait AsOriginExt: AsOrigin {
         self
     }
 
+    /// Set the origin for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_origin`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Arrive, iri};
+    /// # let arrive = Arrive::new(context(), context());
+    ///
+    /// let arrive = arrive.with_origin(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_origin<T>(mut self, origin: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_origin(origin);
+        self
+    }
+
     /// Set many origins for the current activity
     ///
     /// This overwrites the contents of origin
@@ -846,6 +1167,33 @@ pub trait AsOriginExt: AsOrigin {
         self
     }
 
+    /// Set many origins for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_origins`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Arrive, iri};
+    /// # let arrive = Arrive::new(context(), context());
+    ///
+    /// let arrive = arrive.with_many_origins(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_origins<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_origins(items);
+        self
+    }
+
     /// Add a origin to the current activity
     ///
     /// This does not overwrite the contents of origin, only appends an item
@@ -892,6 +1240,24 @@ pub trait AsOptTargetExt: AsOptTarget {
         self.opt_target_ref().target.as_ref()
     }
 
+    /// Iterate over the target for the current activity
+    ///
+    /// This yields nothing if target isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::{context, activity::Announce};
+    /// # let mut announce = Announce::new(context(), context());
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for target in announce.iter_target() {
+    ///     println!("{:?}", target);
+    /// }
+    /// ```
+    fn iter_target(&self) -> Iter<'_, AnyBase> {
+        self.target().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the target for the current activity
     ///
     /// This overwrites the contents of target
@@ -914,6 +1280,29 @@ pub trait AsOptTargetExt: AsOptTarget {
         self
     }
 
+    /// Set the target for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_target`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Announce, iri};
+    /// # let announce = Announce::new(context(), context());
+    ///
+    /// let announce = announce.with_target(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_target<T>(mut self, target: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_target(target);
+        self
+    }
+
     /// Set many targets for the current activity
     ///
     /// This overwrites the contents of target
@@ -941,6 +1330,33 @@ pub trait AsOptTargetExt: AsOptTarget {
         self
     }
 
+    /// Set many targets for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_targets`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Announce, iri};
+    /// # let announce = Announce::new(context(), context());
+    ///
+    /// let announce = announce.with_many_targets(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_targets<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_targets(items);
+        self
+    }
+
     /// Add a target to the current activity
     ///
     /// This does not overwrite the contents of target, only appends an item
@@ -1038,6 +1454,24 @@ pub trait AsOptOriginExt: AsOptOrigin {
         self.opt_origin_ref().origin.as_ref()
     }
 
+    /// Iterate over the origin for the current activity
+    ///
+    /// This yields nothing if origin isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::{context, activity::Delete};
+    /// # let mut delete = Delete::new(context(), context());
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for origin in delete.iter_origin() {
+    ///     println!("{:?}", origin);
+    /// }
+    /// ```
+    fn iter_origin(&self) -> Iter<'_, AnyBase> {
+        self.origin().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the origin for the current activity
     ///
     /// This overwrites the contents of origin
@@ -1060,6 +1494,29 @@ pub trait AsOptOriginExt: AsOptOrigin {
         self
     }
 
+    /// Set the origin for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_origin`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Delete, iri};
+    /// # let delete = Delete::new(context(), context());
+    ///
+    /// let delete = delete.with_origin(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_origin<T>(mut self, origin: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_origin(origin);
+        self
+    }
+
     /// Set many origins for the current activity
     ///
     /// This overwrites the contents of origin
@@ -1087,6 +1544,33 @@ pub trait AsOptOriginExt: AsOptOrigin {
         self
     }
 
+    /// Set many origins for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_origins`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{context, activity::Delete, iri};
+    /// # let delete = Delete::new(context(), context());
+    ///
+    /// let delete = delete.with_many_origins(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_origins<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_origins(items);
+        self
+    }
+
     /// Add a origin to the current activity
     ///
     /// This does not overwrite the contents of origin, only appends an item
@@ -1180,6 +1664,24 @@ pub trait QuestionExt: AsQuestion {
         self.question_ref().one_of.as_ref()
     }
 
+    /// Iterate over the one_of field for the current activity
+    ///
+    /// This yields nothing if one_of isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::activity::Question;
+    /// # let mut question = Question::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for one_of in question.iter_one_of() {
+    ///     println!("{:?}", one_of);
+    /// }
+    /// ```
+    fn iter_one_of(&self) -> Iter<'_, AnyBase> {
+        self.one_of().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the one_of field for the current activity
     ///
     /// This overwrites the contents of one_of
@@ -1202,6 +1704,29 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set the one_of field for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_one_of`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_one_of(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_one_of<T>(mut self, one_of: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_one_of(one_of);
+        self
+    }
+
     /// Set many one_of items for the current activity
     ///
     /// This overwrites the contents of one_of
@@ -1229,6 +1754,33 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set many one_of items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_one_ofs`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_many_one_ofs(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_one_ofs<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_one_ofs(items);
+        self
+    }
+
     /// Add a one_of to the current activity
     ///
     /// This does not overwrite the contents of one_of, only appends an item
@@ -1313,6 +1865,24 @@ pub trait QuestionExt: AsQuestion {
         self.question_ref().any_of.as_ref()
     }
 
+    /// Iterate over the any_of field for the current activity
+    ///
+    /// This yields nothing if any_of isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::activity::Question;
+    /// # let mut question = Question::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for any_of in question.iter_any_of() {
+    ///     println!("{:?}", any_of);
+    /// }
+    /// ```
+    fn iter_any_of(&self) -> Iter<'_, AnyBase> {
+        self.any_of().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the any_of field for the current activity
     ///
     /// This overwrites the contents of any_of
@@ -1335,6 +1905,29 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set the any_of field for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_any_of`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_any_of(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_any_of<T>(mut self, any_of: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_any_of(any_of);
+        self
+    }
+
     /// Set many any_of items for the current activity
     ///
     /// This overwrites the contents of any_of
@@ -1362,6 +1955,33 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set many any_of items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_any_ofs`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_many_any_ofs(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_any_ofs<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_any_ofs(items);
+        self
+    }
+
     /// Add an any_of to the current activity
     ///
     /// This does not overwrite the contents of any_of, only appends an item
@@ -1450,25 +2070,48 @@ pub trait QuestionExt: AsQuestion {
         })
     }
 
-    /// Set the closed field for the current activity
+    /// Set the closed field for the current activity
+    ///
+    /// This overwrites the contents of any_of
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let mut question = Question::new();
+    ///
+    /// question.set_closed_base(iri!("https://example.com/one"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_closed_base<T>(&mut self, closed: T) -> &mut Self
+    where
+        T: Into<AnyBase>,
+    {
+        self.question_mut().closed = Some(Either::Left(OneOrMany::from_one(closed.into())));
+        self
+    }
+
+    /// Set the closed field for the current activity, returning it
     ///
-    /// This overwrites the contents of any_of
+    /// This is the consuming version of `set_closed_base`
     ///
     /// ```rust
     /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::prelude::*;
     /// # use activitystreams::{activity::Question, iri};
-    /// # let mut question = Question::new();
+    /// # let question = Question::new();
     ///
-    /// question.set_closed_base(iri!("https://example.com/one"));
+    /// let question = question.with_closed_base(iri!("https://example.com/one"));
     /// # Ok(())
     /// # }
     /// ```
-    fn set_closed_base<T>(&mut self, closed: T) -> &mut Self
+    fn with_closed_base<T>(mut self, closed: T) -> Self
     where
         T: Into<AnyBase>,
+        Self: Sized,
     {
-        self.question_mut().closed = Some(Either::Left(OneOrMany::from_one(closed.into())));
+        self.set_closed_base(closed);
         self
     }
 
@@ -1499,6 +2142,33 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set many closed items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_closed_bases`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_many_closed_bases(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_closed_bases<I, T>(mut self, closed: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_closed_bases(closed);
+        self
+    }
+
     /// Set the closed field as a date
     ///
     /// This overwrites the contents of any_of
@@ -1518,6 +2188,28 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set the closed field as a date, returning it
+    ///
+    /// This is the consuming version of `set_closed_date`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_closed_date(time::OffsetDateTime::now_utc());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_closed_date(mut self, closed: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_closed_date(closed);
+        self
+    }
+
     /// Set the closed field as a boolean
     ///
     /// This overwrites the contents of any_of
@@ -1537,6 +2229,28 @@ pub trait QuestionExt: AsQuestion {
         self
     }
 
+    /// Set the closed field as a boolean, returning it
+    ///
+    /// This is the consuming version of `set_closed_bool`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{activity::Question, iri};
+    /// # let question = Question::new();
+    ///
+    /// let question = question.with_closed_bool(true);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_closed_bool(mut self, closed: bool) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_closed_bool(closed);
+        self
+    }
+
     /// Add an object or link to the closed field
     ///
     /// This overwrites the contents of any_of
@@ -1823,7 +2537,7 @@ pub type Travel = OptOrigin<OptTarget<ActivityActor<Activity<TravelType>>>>;
 ///
 /// Either of the anyOf and oneOf properties MAY be used to express possible answers, but a
 /// Question object MUST NOT have both properties.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Question {
     /// Identifies an exclusive option for a Question.
@@ -1833,7 +2547,7 @@ pub struct Question {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     one_of: Option<OneOrMany<AnyBase>>,
 
     /// Identifies an inclusive option for a Question.
@@ -1843,7 +2557,7 @@ pub struct Question {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     any_of: Option<OneOrMany<AnyBase>>,
 
     /// Indicates that a question has been closed, and answers are no longer accepted.
@@ -1858,6 +2572,263 @@ pub struct Question {
     inner: Activity<QuestionType>,
 }
 
+/// An Accept with the ActivityPub Object properties.
+pub type FullAccept = ApObject<Accept>;
+
+/// An Add with the ActivityPub Object properties.
+pub type FullAdd = ApObject<Add>;
+
+/// An Announce with the ActivityPub Object properties.
+pub type FullAnnounce = ApObject<Announce>;
+
+/// An Arrive with the ActivityPub Object properties.
+pub type FullArrive = ApObject<Arrive>;
+
+/// A Block with the ActivityPub Object properties.
+pub type FullBlock = ApObject<Block>;
+
+/// A Create with the ActivityPub Object properties.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{activity::{Create, FullCreate}, iri, prelude::*};
+///
+/// let create: FullCreate = Create::new(
+///     iri!("https://example.com/users/asonix"),
+///     iri!("https://example.com/notes/1"),
+/// )
+/// .full()
+/// .with_likes(iri!("https://example.com/activities/1/likes"));
+///
+/// assert!(create.likes().is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub type FullCreate = ApObject<Create>;
+
+/// A Delete with the ActivityPub Object properties.
+pub type FullDelete = ApObject<Delete>;
+
+/// A Dislike with the ActivityPub Object properties.
+pub type FullDislike = ApObject<Dislike>;
+
+/// A Flag with the ActivityPub Object properties.
+pub type FullFlag = ApObject<Flag>;
+
+/// A Follow with the ActivityPub Object properties.
+pub type FullFollow = ApObject<Follow>;
+
+/// An Ignore with the ActivityPub Object properties.
+pub type FullIgnore = ApObject<Ignore>;
+
+/// An Invite with the ActivityPub Object properties.
+pub type FullInvite = ApObject<Invite>;
+
+/// A Join with the ActivityPub Object properties.
+pub type FullJoin = ApObject<Join>;
+
+/// A Leave with the ActivityPub Object properties.
+pub type FullLeave = ApObject<Leave>;
+
+/// A Like with the ActivityPub Object properties.
+pub type FullLike = ApObject<Like>;
+
+/// A Listen with the ActivityPub Object properties.
+pub type FullListen = ApObject<Listen>;
+
+/// A Move with the ActivityPub Object properties.
+pub type FullMove = ApObject<Move>;
+
+/// An Offer with the ActivityPub Object properties.
+pub type FullOffer = ApObject<Offer>;
+
+/// A Question with the ActivityPub Object properties.
+pub type FullQuestion = ApObject<Question>;
+
+/// A Read with the ActivityPub Object properties.
+pub type FullRead = ApObject<Read>;
+
+/// A Reject with the ActivityPub Object properties.
+pub type FullReject = ApObject<Reject>;
+
+/// A Remove with the ActivityPub Object properties.
+pub type FullRemove = ApObject<Remove>;
+
+/// A TentativeAccept with the ActivityPub Object properties.
+pub type FullTentativeAccept = ApObject<TentativeAccept>;
+
+/// A TentativeReject with the ActivityPub Object properties.
+pub type FullTentativeReject = ApObject<TentativeReject>;
+
+/// A Travel with the ActivityPub Object properties.
+pub type FullTravel = ApObject<Travel>;
+
+/// An Undo with the ActivityPub Object properties.
+pub type FullUndo = ApObject<Undo>;
+
+/// An Update with the ActivityPub Object properties.
+pub type FullUpdate = ApObject<Update>;
+
+/// A View with the ActivityPub Object properties.
+pub type FullView = ApObject<View>;
+
+/// An activity whose `type` isn't one of the kinds defined by the spec, like `EmojiReact`
+///
+/// This is just an alias for `ActorAndObjectOptOriginAndTarget<String>`, which keeps the `type` as
+/// a string while still providing structured access to the actor, object, origin, and target.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{activity::GenericActivity, prelude::*};
+///
+/// let json = r#"{
+///     "type": "EmojiReact",
+///     "actor": "https://example.com/users/asonix",
+///     "object": "https://example.com/notes/1",
+///     "content": "🦀"
+/// }"#;
+///
+/// let react: GenericActivity = serde_json::from_str(json)?;
+///
+/// assert_eq!(react.kind().map(String::as_str), Some("EmojiReact"));
+/// assert!(react.content().is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub type GenericActivity = ActorAndObjectOptOriginAndTarget<String>;
+
+/// An Activity of any kind
+///
+/// When deserializing, the `type` field is used to pick the variant, rather than trying each
+/// variant in turn. Activities with a `type` that isn't defined by the spec are kept in the
+/// `Other` variant, as long as they have an actor and an object.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::activity::AnyActivity;
+///
+/// let json = r#"{
+///     "type": "Follow",
+///     "id": "https://example.com/follows/1",
+///     "actor": "https://example.com/users/asonix",
+///     "object": "https://example.com/users/relay"
+/// }"#;
+///
+/// let activity: AnyActivity = serde_json::from_str(json)?;
+///
+/// match activity {
+///     AnyActivity::Follow(ref follow) => println!("Follow {:?}", follow),
+///     AnyActivity::Undo(ref undo) => println!("Undo {:?}", undo),
+///     _ => return Err(anyhow::Error::msg("Unsupported activity")),
+/// }
+///
+/// assert_eq!(activity.kind_str(), Some("Follow"));
+/// assert!(activity.actor_unchecked().is_some());
+///
+/// let react: AnyActivity = serde_json::from_str(r#"{
+///     "type": "EmojiReact",
+///     "actor": "https://example.com/users/asonix",
+///     "object": "https://example.com/notes/1",
+///     "content": "🦀"
+/// }"#)?;
+///
+/// assert!(matches!(react, AnyActivity::Other(_)));
+/// assert_eq!(react.kind_str(), Some("EmojiReact"));
+/// assert!(react.object_unchecked().is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum AnyActivity {
+    /// An Accept activity
+    Accept(Accept),
+
+    /// An Add activity
+    Add(Add),
+
+    /// An Announce activity
+    Announce(Announce),
+
+    /// An Arrive activity
+    Arrive(Arrive),
+
+    /// A Block activity
+    Block(Block),
+
+    /// A Create activity
+    Create(Create),
+
+    /// A Delete activity
+    Delete(Delete),
+
+    /// A Dislike activity
+    Dislike(Dislike),
+
+    /// A Flag activity
+    Flag(Flag),
+
+    /// A Follow activity
+    Follow(Follow),
+
+    /// An Ignore activity
+    Ignore(Ignore),
+
+    /// An Invite activity
+    Invite(Invite),
+
+    /// A Join activity
+    Join(Join),
+
+    /// A Leave activity
+    Leave(Leave),
+
+    /// A Like activity
+    Like(Like),
+
+    /// A Listen activity
+    Listen(Listen),
+
+    /// A Move activity
+    Move(Move),
+
+    /// An Offer activity
+    Offer(Offer),
+
+    /// A Question activity
+    Question(Question),
+
+    /// A Read activity
+    Read(Read),
+
+    /// A Reject activity
+    Reject(Reject),
+
+    /// A Remove activity
+    Remove(Remove),
+
+    /// A TentativeAccept activity
+    TentativeAccept(TentativeAccept),
+
+    /// A TentativeReject activity
+    TentativeReject(TentativeReject),
+
+    /// A Travel activity
+    Travel(Travel),
+
+    /// An Undo activity
+    Undo(Undo),
+
+    /// An Update activity
+    Update(Update),
+
+    /// A View activity
+    View(View),
+
+    /// An activity whose `type` isn't defined by the spec
+    Other(GenericActivity),
+}
+
 /// Activity with actor and object properties
 pub type ActorAndObject<Kind> = ActivityActor<ActivityObject<Activity<Kind>>>;
 
@@ -1876,7 +2847,7 @@ pub type ActorAndObjectOptTarget<Kind> = OptTarget<ActorAndObject<Kind>>;
 /// Activity objects are specializations of the base Object type that provide information about
 /// actions that have either already occurred, are in the process of occurring, or may occur in the
 /// future.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Activity<Kind> {
     /// Describes the result of the activity.
@@ -1886,14 +2857,14 @@ pub struct Activity<Kind> {
     ///
     /// - Range: Object | Link
     /// - Funcitonal: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     result: Option<OneOrMany<AnyBase>>,
 
     /// Identifies one or more objects used (or to be used) in the completion of an Activity.
     ///
     /// - Range: Object | Link
     /// - Funcitonal: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     instrument: Option<OneOrMany<AnyBase>>,
 
     /// base fields and unparsed json ends up here
@@ -1902,7 +2873,7 @@ pub struct Activity<Kind> {
 }
 
 /// Activity with an actor property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActivityActor<Inner> {
     actor: OneOrMany<AnyBase>,
@@ -1913,7 +2884,7 @@ pub struct ActivityActor<Inner> {
 }
 
 /// Activity with an object property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ActivityObject<Inner> {
     object: OneOrMany<AnyBase>,
@@ -1924,7 +2895,7 @@ pub struct ActivityObject<Inner> {
 }
 
 /// Activity with an origin property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Origin<Inner> {
     /// Describes an indirect object of the activity from which the activity is directed.
@@ -1943,7 +2914,7 @@ pub struct Origin<Inner> {
 }
 
 /// Activity with an optional origin property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptOrigin<Inner> {
     /// Describes an indirect object of the activity from which the activity is directed.
@@ -1954,7 +2925,7 @@ pub struct OptOrigin<Inner> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     origin: Option<OneOrMany<AnyBase>>,
 
     /// base fields and unparsed json ends up here
@@ -1963,7 +2934,7 @@ pub struct OptOrigin<Inner> {
 }
 
 /// Activity with a target property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Target<Inner> {
     /// Describes the indirect object, or target, of the activity.
@@ -1983,7 +2954,7 @@ pub struct Target<Inner> {
 }
 
 /// Activity with an optional target property
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OptTarget<Inner> {
     /// Describes the indirect object, or target, of the activity.
@@ -1995,7 +2966,7 @@ pub struct OptTarget<Inner> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     target: Option<OneOrMany<AnyBase>>,
 
     /// base fields and unparsed json ends up here
@@ -2626,75 +3597,493 @@ impl<Inner> OptTarget<Inner> {
 
         inner.insert("target", target)?;
 
-        Ok(inner)
+        Ok(inner)
+    }
+}
+
+impl Question {
+    /// Create a new Question Activity
+    ///
+    /// ```rust
+    /// use activitystreams::activity::Question;
+    ///
+    /// let activity = Question::new();
+    /// ```
+    pub fn new() -> Self {
+        Question {
+            one_of: None,
+            any_of: None,
+            closed: None,
+            inner: Activity::new(),
+        }
+    }
+
+    /// Deconstruct the Question into its parts
+    ///
+    /// ```rust
+    /// use activitystreams::activity::Question;
+    ///
+    /// let activity = Question::new();
+    ///
+    /// let (one_of, any_of, activity) = activity.into_parts();
+    /// ```
+    pub fn into_parts(
+        self,
+    ) -> (
+        Option<OneOrMany<AnyBase>>,
+        Option<OneOrMany<AnyBase>>,
+        Activity<QuestionType>,
+    ) {
+        (self.one_of, self.any_of, self.inner)
+    }
+
+    fn extending(object: Object<QuestionType>) -> Result<Self, serde_json::Error> {
+        let mut inner = Activity::extending(object)?;
+
+        let one_of = inner.remove("oneOf")?;
+        let any_of = inner.remove("anyOf")?;
+        let closed = inner.remove("closed")?;
+
+        Ok(Question {
+            one_of,
+            any_of,
+            closed,
+            inner,
+        })
+    }
+
+    fn retracting(self) -> Result<Object<QuestionType>, serde_json::Error> {
+        let Question {
+            one_of,
+            any_of,
+            closed,
+            mut inner,
+        } = self;
+
+        inner
+            .insert("oneOf", one_of)?
+            .insert("anyOf", any_of)?
+            .insert("closed", closed)?;
+
+        inner.retracting()
+    }
+}
+
+macro_rules! any_activity {
+    ($any_activity:expr, $activity:ident => $expr:expr) => {
+        match $any_activity {
+            AnyActivity::Accept($activity) => $expr,
+            AnyActivity::Add($activity) => $expr,
+            AnyActivity::Announce($activity) => $expr,
+            AnyActivity::Arrive($activity) => $expr,
+            AnyActivity::Block($activity) => $expr,
+            AnyActivity::Create($activity) => $expr,
+            AnyActivity::Delete($activity) => $expr,
+            AnyActivity::Dislike($activity) => $expr,
+            AnyActivity::Flag($activity) => $expr,
+            AnyActivity::Follow($activity) => $expr,
+            AnyActivity::Ignore($activity) => $expr,
+            AnyActivity::Invite($activity) => $expr,
+            AnyActivity::Join($activity) => $expr,
+            AnyActivity::Leave($activity) => $expr,
+            AnyActivity::Like($activity) => $expr,
+            AnyActivity::Listen($activity) => $expr,
+            AnyActivity::Move($activity) => $expr,
+            AnyActivity::Offer($activity) => $expr,
+            AnyActivity::Question($activity) => $expr,
+            AnyActivity::Read($activity) => $expr,
+            AnyActivity::Reject($activity) => $expr,
+            AnyActivity::Remove($activity) => $expr,
+            AnyActivity::TentativeAccept($activity) => $expr,
+            AnyActivity::TentativeReject($activity) => $expr,
+            AnyActivity::Travel($activity) => $expr,
+            AnyActivity::Undo($activity) => $expr,
+            AnyActivity::Update($activity) => $expr,
+            AnyActivity::View($activity) => $expr,
+            AnyActivity::Other($activity) => $expr,
+        }
+    };
+}
+
+impl AnyActivity {
+    /// Create an AnyActivity from a generic Base, picking the variant based on its `type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{activity::AnyActivity, base::Base};
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"type":"Like","actor":"https://example.com/actor","object":"https://example.com/note"}"#,
+    /// )?;
+    ///
+    /// let activity = AnyActivity::from_base(base)?;
+    ///
+    /// assert_eq!(activity.kind_str(), Some("Like"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base(base: Base<serde_json::Value>) -> Result<Self, serde_json::Error> {
+        let kind = match base.kind() {
+            Some(serde_json::Value::String(kind)) => kind.to_owned(),
+            Some(kind) => {
+                return Err(serde::de::Error::custom(format!(
+                    "Invalid activity type {}",
+                    kind
+                )))
+            }
+            None => return Err(serde::de::Error::missing_field("type")),
+        };
+
+        let any_activity = match kind.as_str() {
+            "Accept" => AnyActivity::Accept(base.solidify()?.extend()?),
+            "Add" => AnyActivity::Add(base.solidify()?.extend()?),
+            "Announce" => AnyActivity::Announce(base.solidify()?.extend()?),
+            "Arrive" => AnyActivity::Arrive(base.solidify()?.extend()?),
+            "Block" => AnyActivity::Block(base.solidify()?.extend()?),
+            "Create" => AnyActivity::Create(base.solidify()?.extend()?),
+            "Delete" => AnyActivity::Delete(base.solidify()?.extend()?),
+            "Dislike" => AnyActivity::Dislike(base.solidify()?.extend()?),
+            "Flag" => AnyActivity::Flag(base.solidify()?.extend()?),
+            "Follow" => AnyActivity::Follow(base.solidify()?.extend()?),
+            "Ignore" => AnyActivity::Ignore(base.solidify()?.extend()?),
+            "Invite" => AnyActivity::Invite(base.solidify()?.extend()?),
+            "Join" => AnyActivity::Join(base.solidify()?.extend()?),
+            "Leave" => AnyActivity::Leave(base.solidify()?.extend()?),
+            "Like" => AnyActivity::Like(base.solidify()?.extend()?),
+            "Listen" => AnyActivity::Listen(base.solidify()?.extend()?),
+            "Move" => AnyActivity::Move(base.solidify()?.extend()?),
+            "Offer" => AnyActivity::Offer(base.solidify()?.extend()?),
+            "Question" => AnyActivity::Question(base.solidify()?.extend()?),
+            "Read" => AnyActivity::Read(base.solidify()?.extend()?),
+            "Reject" => AnyActivity::Reject(base.solidify()?.extend()?),
+            "Remove" => AnyActivity::Remove(base.solidify()?.extend()?),
+            "TentativeAccept" => AnyActivity::TentativeAccept(base.solidify()?.extend()?),
+            "TentativeReject" => AnyActivity::TentativeReject(base.solidify()?.extend()?),
+            "Travel" => AnyActivity::Travel(base.solidify()?.extend()?),
+            "Undo" => AnyActivity::Undo(base.solidify()?.extend()?),
+            "Update" => AnyActivity::Update(base.solidify()?.extend()?),
+            "View" => AnyActivity::View(base.solidify()?.extend()?),
+            _ => AnyActivity::Other(base.solidify()?.extend()?),
+        };
+
+        Ok(any_activity)
+    }
+
+    /// Create an AnyActivity from an AnyBase
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{activity::{AnyActivity, Create}, context, prelude::*};
+    ///
+    /// let any_base = Create::new(context(), context()).into_any_base()?;
+    ///
+    /// let activity = AnyActivity::from_any_base(any_base)?;
+    ///
+    /// assert!(matches!(activity, Some(AnyActivity::Create(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_any_base(any_base: AnyBase) -> Result<Option<Self>, serde_json::Error> {
+        any_base.take_base().map(Self::from_base).transpose()
+    }
+
+    /// Create an AnyActivity from a generic Base, or the type registered for its `type`
+    ///
+    /// Types registered with the `KindRegistry` take precedence over the variants of this enum.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{base::Base, activity::AnyActivity, registry::KindRegistry};
+    ///
+    /// let registry = KindRegistry::new();
+    /// let base: Base<serde_json::Value> = serde_json::from_str(r#"{"type":"Like","actor":"https://example.com/users/asonix","object":"https://example.com/notes/1"}"#)?;
+    ///
+    /// let activity = AnyActivity::from_base_with(base, &registry)?.unregistered();
+    /// assert!(activity.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base_with(
+        base: Base<serde_json::Value>,
+        registry: &KindRegistry,
+    ) -> Result<Materialized<Self>, MaterializeError> {
+        registry.materialize_base(base, Self::from_base)
+    }
+
+    /// Create an AnyActivity from an AnyBase, or the type registered for its `type`
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    pub fn from_any_base_with(
+        any_base: AnyBase,
+        registry: &KindRegistry,
+    ) -> Result<Option<Materialized<Self>>, MaterializeError> {
+        any_base
+            .take_base()
+            .map(|base| Self::from_base_with(base, registry))
+            .transpose()
     }
-}
 
-impl Question {
-    /// Create a new Question Activity
+    /// Convert this AnyActivity into an AnyBase
     ///
     /// ```rust
-    /// use activitystreams::activity::Question;
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{activity::{AnyActivity, Create}, context};
     ///
-    /// let activity = Question::new();
+    /// let activity = AnyActivity::from(Create::new(context(), context()));
+    ///
+    /// let any_base = activity.into_any_base()?;
+    ///
+    /// assert_eq!(any_base.kind_str(), Some("Create"));
+    /// # Ok(())
+    /// # }
     /// ```
-    pub fn new() -> Self {
-        Question {
-            one_of: None,
-            any_of: None,
-            closed: None,
-            inner: Activity::new(),
-        }
+    pub fn into_any_base(self) -> Result<AnyBase, serde_json::Error> {
+        any_activity!(self, activity => activity.into_any_base())
     }
 
-    /// Deconstruct the Question into its parts
+    /// Fetch the `type` of the current activity as a string
     ///
     /// ```rust
-    /// use activitystreams::activity::Question;
-    ///
-    /// let activity = Question::new();
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context};
+    /// let activity = AnyActivity::from(Create::new(context(), context()));
     ///
-    /// let (one_of, any_of, activity) = activity.into_parts();
+    /// assert_eq!(activity.kind_str(), Some("Create"));
     /// ```
-    pub fn into_parts(
-        self,
-    ) -> (
-        Option<OneOrMany<AnyBase>>,
-        Option<OneOrMany<AnyBase>>,
-        Activity<QuestionType>,
-    ) {
-        (self.one_of, self.any_of, self.inner)
-    }
+    pub fn kind_str(&self) -> Option<&str> {
+        let kind = match self {
+            AnyActivity::Accept(_) => "Accept",
+            AnyActivity::Add(_) => "Add",
+            AnyActivity::Announce(_) => "Announce",
+            AnyActivity::Arrive(_) => "Arrive",
+            AnyActivity::Block(_) => "Block",
+            AnyActivity::Create(_) => "Create",
+            AnyActivity::Delete(_) => "Delete",
+            AnyActivity::Dislike(_) => "Dislike",
+            AnyActivity::Flag(_) => "Flag",
+            AnyActivity::Follow(_) => "Follow",
+            AnyActivity::Ignore(_) => "Ignore",
+            AnyActivity::Invite(_) => "Invite",
+            AnyActivity::Join(_) => "Join",
+            AnyActivity::Leave(_) => "Leave",
+            AnyActivity::Like(_) => "Like",
+            AnyActivity::Listen(_) => "Listen",
+            AnyActivity::Move(_) => "Move",
+            AnyActivity::Offer(_) => "Offer",
+            AnyActivity::Question(_) => "Question",
+            AnyActivity::Read(_) => "Read",
+            AnyActivity::Reject(_) => "Reject",
+            AnyActivity::Remove(_) => "Remove",
+            AnyActivity::TentativeAccept(_) => "TentativeAccept",
+            AnyActivity::TentativeReject(_) => "TentativeReject",
+            AnyActivity::Travel(_) => "Travel",
+            AnyActivity::Undo(_) => "Undo",
+            AnyActivity::Update(_) => "Update",
+            AnyActivity::View(_) => "View",
+            AnyActivity::Other(activity) => return activity.kind().map(String::as_str),
+        };
 
-    fn extending(object: Object<QuestionType>) -> Result<Self, serde_json::Error> {
-        let mut inner = Activity::extending(object)?;
+        Some(kind)
+    }
 
-        let one_of = inner.remove("oneOf")?;
-        let any_of = inner.remove("anyOf")?;
-        let closed = inner.remove("closed")?;
+    /// Fetch the id for the current activity
+    ///
+    /// ```rust
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context};
+    /// # let activity = AnyActivity::from(Create::new(context(), context()));
+    /// if let Some(id) = activity.id_unchecked() {
+    ///     println!("{:?}", id);
+    /// }
+    /// ```
+    pub fn id_unchecked(&self) -> Option<&IriString> {
+        any_activity!(self, activity => activity.activity_ref().id_unchecked())
+    }
 
-        Ok(Question {
-            one_of,
-            any_of,
-            closed,
-            inner,
-        })
+    /// Fetch the actor for the current activity, checking it against the activity's id
+    ///
+    /// This returns `Ok(None)` for activities without an actor, such as Question
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context, iri, prelude::*};
+    /// # let mut create = Create::new(iri!("https://example.com/actor"), context());
+    /// # create.set_id(iri!("https://example.com/create"));
+    /// # let activity = AnyActivity::from(create);
+    /// if let Some(actor) = activity.actor()? {
+    ///     println!("{:?}", actor);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn actor(&self) -> Result<Option<&OneOrMany<AnyBase>>, CheckError> {
+        match self {
+            AnyActivity::Accept(activity) => activity.actor().map(Some),
+            AnyActivity::Add(activity) => activity.actor().map(Some),
+            AnyActivity::Announce(activity) => activity.actor().map(Some),
+            AnyActivity::Arrive(activity) => activity.actor().map(Some),
+            AnyActivity::Block(activity) => activity.actor().map(Some),
+            AnyActivity::Create(activity) => activity.actor().map(Some),
+            AnyActivity::Delete(activity) => activity.actor().map(Some),
+            AnyActivity::Dislike(activity) => activity.actor().map(Some),
+            AnyActivity::Flag(activity) => activity.actor().map(Some),
+            AnyActivity::Follow(activity) => activity.actor().map(Some),
+            AnyActivity::Ignore(activity) => activity.actor().map(Some),
+            AnyActivity::Invite(activity) => activity.actor().map(Some),
+            AnyActivity::Join(activity) => activity.actor().map(Some),
+            AnyActivity::Leave(activity) => activity.actor().map(Some),
+            AnyActivity::Like(activity) => activity.actor().map(Some),
+            AnyActivity::Listen(activity) => activity.actor().map(Some),
+            AnyActivity::Move(activity) => activity.actor().map(Some),
+            AnyActivity::Offer(activity) => activity.actor().map(Some),
+            AnyActivity::Read(activity) => activity.actor().map(Some),
+            AnyActivity::Reject(activity) => activity.actor().map(Some),
+            AnyActivity::Remove(activity) => activity.actor().map(Some),
+            AnyActivity::TentativeAccept(activity) => activity.actor().map(Some),
+            AnyActivity::TentativeReject(activity) => activity.actor().map(Some),
+            AnyActivity::Travel(activity) => activity.actor().map(Some),
+            AnyActivity::Undo(activity) => activity.actor().map(Some),
+            AnyActivity::Update(activity) => activity.actor().map(Some),
+            AnyActivity::View(activity) => activity.actor().map(Some),
+            AnyActivity::Other(activity) => activity.actor().map(Some),
+            AnyActivity::Question(_) => Ok(None),
+        }
     }
 
-    fn retracting(self) -> Result<Object<QuestionType>, serde_json::Error> {
-        let Question {
-            one_of,
-            any_of,
-            closed,
-            mut inner,
-        } = self;
+    /// Fetch the actor for the current activity
+    ///
+    /// This returns `None` for activities without an actor, such as Question
+    ///
+    /// ```rust
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context};
+    /// # let activity = AnyActivity::from(Create::new(context(), context()));
+    /// if let Some(actor) = activity.actor_unchecked() {
+    ///     println!("{:?}", actor);
+    /// }
+    /// ```
+    pub fn actor_unchecked(&self) -> Option<&OneOrMany<AnyBase>> {
+        match self {
+            AnyActivity::Accept(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Add(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Announce(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Arrive(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Block(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Create(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Delete(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Dislike(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Flag(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Follow(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Ignore(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Invite(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Join(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Leave(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Like(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Listen(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Move(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Offer(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Read(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Reject(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Remove(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::TentativeAccept(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::TentativeReject(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Travel(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Undo(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Update(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::View(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Other(activity) => Some(activity.actor_unchecked()),
+            AnyActivity::Question(_) => None,
+        }
+    }
 
-        inner
-            .insert("oneOf", one_of)?
-            .insert("anyOf", any_of)?
-            .insert("closed", closed)?;
+    /// Fetch the object for the current activity, checking it against the activity's id
+    ///
+    /// This returns `Ok(None)` for intransitive activities, such as Arrive, Travel, and Question
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context, iri, prelude::*};
+    /// # let mut create = Create::new(context(), iri!("https://example.com/note"));
+    /// # create.set_id(iri!("https://example.com/create"));
+    /// # let activity = AnyActivity::from(create);
+    /// if let Some(object) = activity.object()? {
+    ///     println!("{:?}", object);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn object(&self) -> Result<Option<&OneOrMany<AnyBase>>, CheckError> {
+        match self {
+            AnyActivity::Accept(activity) => activity.object().map(Some),
+            AnyActivity::Add(activity) => activity.object().map(Some),
+            AnyActivity::Announce(activity) => activity.object().map(Some),
+            AnyActivity::Block(activity) => activity.object().map(Some),
+            AnyActivity::Create(activity) => activity.object().map(Some),
+            AnyActivity::Delete(activity) => activity.object().map(Some),
+            AnyActivity::Dislike(activity) => activity.object().map(Some),
+            AnyActivity::Flag(activity) => activity.object().map(Some),
+            AnyActivity::Follow(activity) => activity.object().map(Some),
+            AnyActivity::Ignore(activity) => activity.object().map(Some),
+            AnyActivity::Invite(activity) => activity.object().map(Some),
+            AnyActivity::Join(activity) => activity.object().map(Some),
+            AnyActivity::Leave(activity) => activity.object().map(Some),
+            AnyActivity::Like(activity) => activity.object().map(Some),
+            AnyActivity::Listen(activity) => activity.object().map(Some),
+            AnyActivity::Move(activity) => activity.object().map(Some),
+            AnyActivity::Offer(activity) => activity.object().map(Some),
+            AnyActivity::Read(activity) => activity.object().map(Some),
+            AnyActivity::Reject(activity) => activity.object().map(Some),
+            AnyActivity::Remove(activity) => activity.object().map(Some),
+            AnyActivity::TentativeAccept(activity) => activity.object().map(Some),
+            AnyActivity::TentativeReject(activity) => activity.object().map(Some),
+            AnyActivity::Undo(activity) => activity.object().map(Some),
+            AnyActivity::Update(activity) => activity.object().map(Some),
+            AnyActivity::View(activity) => activity.object().map(Some),
+            AnyActivity::Other(activity) => activity.object().map(Some),
+            AnyActivity::Arrive(_) | AnyActivity::Question(_) | AnyActivity::Travel(_) => Ok(None),
+        }
+    }
 
-        inner.retracting()
+    /// Fetch the object for the current activity
+    ///
+    /// This returns `None` for intransitive activities, such as Arrive, Travel, and Question
+    ///
+    /// ```rust
+    /// # use activitystreams::{activity::{AnyActivity, Create}, context};
+    /// # let activity = AnyActivity::from(Create::new(context(), context()));
+    /// if let Some(object) = activity.object_unchecked() {
+    ///     println!("{:?}", object);
+    /// }
+    /// ```
+    pub fn object_unchecked(&self) -> Option<&OneOrMany<AnyBase>> {
+        match self {
+            AnyActivity::Accept(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Add(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Announce(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Block(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Create(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Delete(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Dislike(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Flag(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Follow(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Ignore(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Invite(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Join(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Leave(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Like(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Listen(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Move(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Offer(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Read(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Reject(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Remove(activity) => Some(activity.object_unchecked()),
+            AnyActivity::TentativeAccept(activity) => Some(activity.object_unchecked()),
+            AnyActivity::TentativeReject(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Undo(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Update(activity) => Some(activity.object_unchecked()),
+            AnyActivity::View(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Other(activity) => Some(activity.object_unchecked()),
+            AnyActivity::Arrive(_) | AnyActivity::Question(_) | AnyActivity::Travel(_) => None,
+        }
     }
 }
 
@@ -3944,6 +5333,30 @@ where
     }
 }
 
+impl AsBase for Question {
+    type Kind = QuestionType;
+
+    fn base_ref(&self) -> &Base<Self::Kind> {
+        self.inner.base_ref()
+    }
+
+    fn base_mut(&mut self) -> &mut Base<Self::Kind> {
+        self.inner.base_mut()
+    }
+}
+
+impl AsObject for Question {
+    type Kind = QuestionType;
+
+    fn object_ref(&self) -> &Object<Self::Kind> {
+        self.inner.object_ref()
+    }
+
+    fn object_mut(&mut self) -> &mut Object<Self::Kind> {
+        self.inner.object_mut()
+    }
+}
+
 impl AsActivity for Question {
     type Kind = QuestionType;
 
@@ -4093,6 +5506,190 @@ impl<T> AsOptTargetExt for T where T: AsOptTarget {}
 impl<T> AsOptOriginExt for T where T: AsOptOrigin {}
 impl<T> QuestionExt for T where T: AsQuestion {}
 
+impl<'de> serde::de::Deserialize<'de> for AnyActivity {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let base = Base::<serde_json::Value>::deserialize(deserializer)?;
+        AnyActivity::from_base(base).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Accept> for AnyActivity {
+    fn from(activity: Accept) -> Self {
+        AnyActivity::Accept(activity)
+    }
+}
+
+impl From<Add> for AnyActivity {
+    fn from(activity: Add) -> Self {
+        AnyActivity::Add(activity)
+    }
+}
+
+impl From<Announce> for AnyActivity {
+    fn from(activity: Announce) -> Self {
+        AnyActivity::Announce(activity)
+    }
+}
+
+impl From<Arrive> for AnyActivity {
+    fn from(activity: Arrive) -> Self {
+        AnyActivity::Arrive(activity)
+    }
+}
+
+impl From<Block> for AnyActivity {
+    fn from(activity: Block) -> Self {
+        AnyActivity::Block(activity)
+    }
+}
+
+impl From<Create> for AnyActivity {
+    fn from(activity: Create) -> Self {
+        AnyActivity::Create(activity)
+    }
+}
+
+impl From<Delete> for AnyActivity {
+    fn from(activity: Delete) -> Self {
+        AnyActivity::Delete(activity)
+    }
+}
+
+impl From<Dislike> for AnyActivity {
+    fn from(activity: Dislike) -> Self {
+        AnyActivity::Dislike(activity)
+    }
+}
+
+impl From<Flag> for AnyActivity {
+    fn from(activity: Flag) -> Self {
+        AnyActivity::Flag(activity)
+    }
+}
+
+impl From<Follow> for AnyActivity {
+    fn from(activity: Follow) -> Self {
+        AnyActivity::Follow(activity)
+    }
+}
+
+impl From<Ignore> for AnyActivity {
+    fn from(activity: Ignore) -> Self {
+        AnyActivity::Ignore(activity)
+    }
+}
+
+impl From<Invite> for AnyActivity {
+    fn from(activity: Invite) -> Self {
+        AnyActivity::Invite(activity)
+    }
+}
+
+impl From<Join> for AnyActivity {
+    fn from(activity: Join) -> Self {
+        AnyActivity::Join(activity)
+    }
+}
+
+impl From<Leave> for AnyActivity {
+    fn from(activity: Leave) -> Self {
+        AnyActivity::Leave(activity)
+    }
+}
+
+impl From<Like> for AnyActivity {
+    fn from(activity: Like) -> Self {
+        AnyActivity::Like(activity)
+    }
+}
+
+impl From<Listen> for AnyActivity {
+    fn from(activity: Listen) -> Self {
+        AnyActivity::Listen(activity)
+    }
+}
+
+impl From<Move> for AnyActivity {
+    fn from(activity: Move) -> Self {
+        AnyActivity::Move(activity)
+    }
+}
+
+impl From<Offer> for AnyActivity {
+    fn from(activity: Offer) -> Self {
+        AnyActivity::Offer(activity)
+    }
+}
+
+impl From<Question> for AnyActivity {
+    fn from(activity: Question) -> Self {
+        AnyActivity::Question(activity)
+    }
+}
+
+impl From<Read> for AnyActivity {
+    fn from(activity: Read) -> Self {
+        AnyActivity::Read(activity)
+    }
+}
+
+impl From<Reject> for AnyActivity {
+    fn from(activity: Reject) -> Self {
+        AnyActivity::Reject(activity)
+    }
+}
+
+impl From<Remove> for AnyActivity {
+    fn from(activity: Remove) -> Self {
+        AnyActivity::Remove(activity)
+    }
+}
+
+impl From<TentativeAccept> for AnyActivity {
+    fn from(activity: TentativeAccept) -> Self {
+        AnyActivity::TentativeAccept(activity)
+    }
+}
+
+impl From<TentativeReject> for AnyActivity {
+    fn from(activity: TentativeReject) -> Self {
+        AnyActivity::TentativeReject(activity)
+    }
+}
+
+impl From<Travel> for AnyActivity {
+    fn from(activity: Travel) -> Self {
+        AnyActivity::Travel(activity)
+    }
+}
+
+impl From<Undo> for AnyActivity {
+    fn from(activity: Undo) -> Self {
+        AnyActivity::Undo(activity)
+    }
+}
+
+impl From<Update> for AnyActivity {
+    fn from(activity: Update) -> Self {
+        AnyActivity::Update(activity)
+    }
+}
+
+impl From<View> for AnyActivity {
+    fn from(activity: View) -> Self {
+        AnyActivity::View(activity)
+    }
+}
+
+impl From<GenericActivity> for AnyActivity {
+    fn from(activity: GenericActivity) -> Self {
+        AnyActivity::Other(activity)
+    }
+}
+
 impl<Kind> Default for Activity<Kind>
 where
     Kind: Default,
@@ -4107,3 +5704,94 @@ impl Default for Question {
         Self::new()
     }
 }
+
+impl<Kind> Validate for Activity<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        violations
+            .field("result", &self.result)
+            .field("instrument", &self.instrument);
+    }
+}
+
+impl<Inner> Validate for ActivityActor<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("actor", &self.actor);
+    }
+}
+
+impl<Inner> Validate for ActivityObject<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("object", &self.object);
+    }
+}
+
+impl<Inner> Validate for Origin<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("origin", &self.origin);
+    }
+}
+
+impl<Inner> Validate for OptOrigin<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("origin", &self.origin);
+    }
+}
+
+impl<Inner> Validate for Target<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("target", &self.target);
+    }
+}
+
+impl<Inner> Validate for OptTarget<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("target", &self.target);
+    }
+}
+
+impl Validate for Question {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        if self.one_of.is_some() && self.any_of.is_some() {
+            violations.report("a Question can't have both oneOf and anyOf");
+        }
+
+        violations
+            .field("oneOf", &self.one_of)
+            .field("anyOf", &self.any_of)
+            .field("closed", &self.closed);
+    }
+}
+
+impl Validate for AnyActivity {
+    fn validate_into(&self, violations: &mut Violations) {
+        any_activity!(self, activity => activity.validate_into(violations))
+    }
+}
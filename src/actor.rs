@@ -1,5 +1,8 @@
 //! Types and traits for dealing with Actor attributes
 //!
+//! Every Actor also has a `Full*` alias, such as `FullPerson`, naming the `ApActor<ApObject<_>>`
+//! that `Actor::full` wraps it in to add both the ActivityPub Actor and Object properties.
+//!
 //! ```rust
 //! # fn main() -> Result<(), anyhow::Error> {
 //! use activitystreams::{
@@ -24,13 +27,15 @@
 use std::collections::HashMap;
 
 use crate::{
-    base::{AsBase, Base, Extends},
+    base::{AnyBase, AsBase, Base, Extends, ExtendsExt},
     checked::CheckError,
     markers,
     object::{ApObject, AsApObject, AsObject, Object},
     prelude::BaseExt,
-    primitives::OneOrMany,
+    primitives::{AnyString, OneOrMany, RdfLangStringMap},
+    registry::{KindRegistry, MaterializeError, Materialized},
     unparsed::{Unparsed, UnparsedMut, UnparsedMutExt},
+    validate::{Validate, Violations},
 };
 use iri_string::{components::AuthorityComponents, types::IriString};
 
@@ -38,6 +43,10 @@ pub use activitystreams_kinds::actor as kind;
 
 use self::kind::*;
 
+fn primary_subtag(tag: &str) -> &str {
+    tag.split('-').next().unwrap_or(tag)
+}
+
 fn check_opt(
     iri: &IriString,
     authority_components: Option<&AuthorityComponents>,
@@ -135,6 +144,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the inbox for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_inbox`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_inbox(iri!("https://example.com/inbox"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_inbox(mut self, inbox: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_inbox(inbox);
+        self
+    }
+
     /// Fetch the outbox for the current actor
     ///
     /// ```rust
@@ -205,6 +236,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the outbox for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_outbox`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_outbox(iri!("https://example.com/outbox"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_outbox(mut self, outbox: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_outbox(outbox);
+        self
+    }
+
     /// Take the outbox from the current actor, leaving nothing
     ///
     /// ```rust
@@ -314,6 +367,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the following link for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_following`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_following(iri!("https://example.com/following"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_following(mut self, following: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_following(following);
+        self
+    }
+
     /// Take the following link from the current actor, leaving nothing
     ///
     /// ```rust
@@ -423,6 +498,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the followers link for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_followers`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_followers(iri!("https://example.com/followers"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_followers(mut self, followers: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_followers(followers);
+        self
+    }
+
     /// Take the followers link from the current actor, leaving nothing
     ///
     /// ```rust
@@ -532,6 +629,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the liked link for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_liked`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_liked(iri!("https://example.com/liked"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_liked(mut self, liked: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_liked(liked);
+        self
+    }
+
     /// Take the liked link from the current actor, leaving nothing
     ///
     /// ```rust
@@ -650,6 +769,28 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the streams links for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_stream`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_stream(iri!("https://example.com/streams"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_stream(mut self, streams: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_stream(streams);
+        self
+    }
+
     /// Set many streams links for the current actor
     ///
     /// ```rust
@@ -675,6 +816,33 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set many streams links for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_many_streams`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_many_streams(vec![
+    ///     iri!("https://example.com/streams1"),
+    ///     iri!("https://example.com/streams2"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_streams<I, U>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = U>,
+        U: Into<IriString>,
+        Self: Sized,
+    {
+        self.set_many_streams(items);
+        self
+    }
+
     /// Add a streams link for the current actor
     ///
     /// ```rust
@@ -774,6 +942,29 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the preferred_username for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_preferred_username`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Person}, context};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_preferred_username("user123");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_preferred_username<T>(mut self, string: T) -> Self
+    where
+        T: Into<String>,
+        Self: Sized,
+    {
+        self.set_preferred_username(string);
+        self
+    }
+
     /// Take the preferred_username from the current actor, leaving nothing
     ///
     /// ```rust
@@ -806,6 +997,98 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Fetch a name suitable for displaying the current actor
+    ///
+    /// This falls back from `name` to `preferredUsername` to the last segment of the actor's id,
+    /// skipping any that are missing or blank.
+    ///
+    /// When a `language` is given, names tagged with that language are preferred, whether they
+    /// come from `nameMap` or from an `rdf:langString` in `name`. Language tags are compared
+    /// case-insensitively, and a tag matches if its primary subtag matches, so `en` matches
+    /// `en-US`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::{ApActor, Person}, iri, prelude::*};
+    ///
+    /// let person: ApActor<Person> = serde_json::from_str(r#"{
+    ///     "type": "Person",
+    ///     "id": "https://example.com/users/asonix",
+    ///     "inbox": "https://example.com/users/asonix/inbox",
+    ///     "name": "Aode",
+    ///     "nameMap": {
+    ///         "en": "Aode",
+    ///         "fr": "Aodé"
+    ///     }
+    /// }"#)?;
+    ///
+    /// assert_eq!(person.display_name(None), Some("Aode"));
+    /// assert_eq!(person.display_name(Some("fr-CA")), Some("Aodé"));
+    ///
+    /// let mut person = ApActor::new(iri!("https://example.com/inbox"), Person::new());
+    /// person.set_preferred_username("asonix");
+    /// assert_eq!(person.display_name(None), Some("asonix"));
+    ///
+    /// person.delete_preferred_username();
+    /// assert_eq!(person.display_name(None), None);
+    ///
+    /// person.set_id(iri!("https://example.com/@aode/"));
+    /// assert_eq!(person.display_name(None), Some("@aode"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn display_name<'a>(&'a self, language: Option<&str>) -> Option<&'a str>
+    where
+        Self: BaseExt,
+        Self::Inner: 'a,
+        <Self as AsBase>::Kind: 'a,
+    {
+        let names: Vec<&'a AnyString> = self
+            .name()
+            .map(|names| names.iter().copied().collect())
+            .unwrap_or_default();
+        let name_map = self.name_map();
+
+        let not_blank = |name: &&str| !name.trim().is_empty();
+
+        let localized = language.and_then(|language| {
+            let mapped = name_map.into_iter().flat_map(RdfLangStringMap::iter);
+            let tagged = names
+                .iter()
+                .filter_map(|name| Some((name.language()?, name.as_str())));
+            let candidates: Vec<_> = mapped
+                .chain(tagged)
+                .filter(|(_, name)| not_blank(name))
+                .collect();
+
+            candidates
+                .iter()
+                .find(|(tag, _)| tag.eq_ignore_ascii_case(language))
+                .or_else(|| {
+                    candidates.iter().find(|(tag, _)| {
+                        primary_subtag(tag).eq_ignore_ascii_case(primary_subtag(language))
+                    })
+                })
+                .map(|(_, name)| *name)
+        });
+
+        localized
+            .or_else(|| names.iter().map(|name| name.as_str()).find(not_blank))
+            .or_else(|| {
+                name_map
+                    .into_iter()
+                    .flat_map(RdfLangStringMap::iter)
+                    .map(|(_, name)| name)
+                    .find(not_blank)
+            })
+            .or_else(|| self.preferred_username().filter(not_blank))
+            .or_else(|| {
+                self.id_unchecked()
+                    .and_then(|id| id.path_str().trim_end_matches('/').rsplit('/').next())
+                    .filter(not_blank)
+            })
+    }
+
     /// Fetch the endpoints for the current actor, erroring if the Endpoints' domains do not
     /// match the ID's domain
     ///
@@ -834,39 +1117,8 @@ pub trait ApActorExt: AsApActor {
             let authority_opt = self.id_unchecked().and_then(|id| id.authority_components());
 
             endpoints
-                .proxy_url
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .oauth_authorization_endpoint
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .oauth_token_endpoint
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .provide_client_key
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .sign_client_key
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .shared_inbox
-                .as_ref()
-                .map(|i| check_opt(i, authority_opt.as_ref()))
-                .transpose()?;
-            endpoints
-                .nonstandard
-                .values()
-                .try_for_each(|v| check_opt(v, authority_opt.as_ref()))?;
+                .iter()
+                .try_for_each(|(_, v)| check_opt(v, authority_opt.as_ref()))?;
 
             return Ok(Some(endpoints));
         }
@@ -933,6 +1185,31 @@ pub trait ApActorExt: AsApActor {
         self
     }
 
+    /// Set the endpoints for the current actor, returning it
+    ///
+    /// This is the consuming version of `set_endpoints`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{ApActor, Endpoints, Person}, context, iri};
+    /// # let person = ApActor::new(context(), Person::new());
+    /// use activitystreams::prelude::*;
+    ///
+    /// let person = person.with_endpoints(Endpoints {
+    ///     shared_inbox: Some(iri!("https://example.com/inbox")),
+    ///     ..Default::default()
+    /// });
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_endpoints(mut self, endpoints: Endpoints<IriString>) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_endpoints(endpoints);
+        self
+    }
+
     /// Take the endpoints from the current actor, leaving nothing
     ///
     /// ```rust
@@ -996,6 +1273,21 @@ pub type Person = Actor<PersonType>;
 /// Service that aren't already present on an Actor.
 pub type Service = Actor<ServiceType>;
 
+/// An Application with the ActivityPub Actor and Object properties.
+pub type FullApplication = ApActor<ApObject<Application>>;
+
+/// A Group with the ActivityPub Actor and Object properties.
+pub type FullGroup = ApActor<ApObject<Group>>;
+
+/// An Organization with the ActivityPub Actor and Object properties.
+pub type FullOrganization = ApActor<ApObject<Organization>>;
+
+/// A Person with the ActivityPub Actor and Object properties.
+pub type FullPerson = ApActor<ApObject<Person>>;
+
+/// A Service with the ActivityPub Actor and Object properties.
+pub type FullService = ApActor<ApObject<Service>>;
+
 /// Actor types are Object types that are capable of performing activities.
 ///
 /// This specification intentionally defines Actors in only the most generalized way, stopping
@@ -1014,7 +1306,7 @@ pub type Service = Actor<ServiceType>;
 /// implementation MUST also specify the core vocabulary type. For instance, some vocabularies
 /// (e.g. VCard) define their own types for describing people. An implementation that wishes, for
 /// example, to use a vcard:Individual as an Actor MUST also identify that Actor as a Person.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApActor<Inner> {
     /// A reference to an [ActivityStreams] OrderedCollection comprised of all the messages received by the actor.
@@ -1055,7 +1347,7 @@ pub struct ApActor<Inner> {
     ///
     /// - Range: xsd:anyUri
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     streams: Option<OneOrMany<IriString>>,
 
     /// A short username which may be used to refer to the actor, with no uniqueness guarantees.
@@ -1086,7 +1378,28 @@ pub struct ApActor<Inner> {
 ///
 /// This mapping may be nested inside the actor document as the value or may be a link to a
 /// JSON-LD document with these properties.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+///
+/// Endpoints that aren't present are left out when serializing.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::actor::Endpoints;
+/// use iri_string::types::IriString;
+///
+/// let json = r#"{"sharedInbox":"https://a.example/inbox","uploadMedia":"https://a.example/up"}"#;
+/// let endpoints: Endpoints<IriString> = serde_json::from_str(json)?;
+///
+/// assert!(endpoints.proxy_url.is_none());
+/// assert_eq!(
+///     endpoints.upload_media.as_ref().map(|u| u.as_str()),
+///     Some("https://a.example/up")
+/// );
+/// assert!(endpoints.nonstandard.is_empty());
+/// assert_eq!(serde_json::to_string(&endpoints)?, json);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Endpoints<T> {
     /// Endpoint URI so this actor's clients may access remote ActivityStreams objects which
@@ -1152,6 +1465,18 @@ pub struct Endpoints<T> {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub shared_inbox: Option<T>,
 
+    /// Endpoint URI for uploading binary media, such as images and video, to be attached to
+    /// objects created by this actor.
+    ///
+    /// Clients post a multipart/form-data request with a `file` part holding the media and an
+    /// `object` part holding a shell object, and the server responds with `201 Created` and the
+    /// new object's id in the Location header.
+    ///
+    /// - Range: anyUri
+    /// - Functional: true
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_media: Option<T>,
+
     /// Any nonstandard endpoints present in the endpoints record end up here.
     ///
     /// Software like Pleroma and Akkoma provide additional URLs here and extending Endpoints
@@ -1169,10 +1494,60 @@ pub struct Endpoints<T> {
 /// let mut actor = Actor(object);
 /// actor.set_kind("MyCustomActor".into());
 /// ```
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
 pub struct Actor<Kind>(pub Object<Kind>);
 
+/// An ActivityPub Actor of any kind
+///
+/// When deserializing, the `type` field is used to pick the variant. Actors with a `type` that
+/// isn't defined by the spec are kept in the `Other` variant.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::actor::AnyActor;
+///
+/// let json = r#"{
+///     "type": "Service",
+///     "id": "https://example.com/actor",
+///     "inbox": "https://example.com/actor/inbox",
+///     "preferredUsername": "relay"
+/// }"#;
+///
+/// let actor: AnyActor = serde_json::from_str(json)?;
+///
+/// match actor {
+///     AnyActor::Service(ref service) => println!("Service {:?}", service),
+///     _ => return Err(anyhow::Error::msg("Not a service")),
+/// }
+///
+/// assert_eq!(actor.inbox_unchecked().as_str(), "https://example.com/actor/inbox");
+/// assert_eq!(actor.preferred_username(), Some("relay"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum AnyActor {
+    /// An Application actor
+    Application(ApActor<Application>),
+
+    /// A Group actor
+    Group(ApActor<Group>),
+
+    /// An Organization actor
+    Organization(ApActor<Organization>),
+
+    /// A Person actor
+    Person(ApActor<Person>),
+
+    /// A Service actor
+    Service(ApActor<Service>),
+
+    /// An actor whose `type` isn't defined by the spec
+    Other(ApActor<Actor<String>>),
+}
+
 impl<Kind> Actor<Kind> {
     /// Create a new Actor
     ///
@@ -1187,6 +1562,33 @@ impl<Kind> Actor<Kind> {
         Actor(Object::new())
     }
 
+    /// Extend the actor with all of the ActivityPub Actor and Object properties
+    ///
+    /// This is the actor version of `ObjectExt::full`. It takes the actor's inbox, since
+    /// ActivityPub requires every actor to have one.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     actor::{FullPerson, Person},
+    ///     iri,
+    ///     prelude::*,
+    /// };
+    ///
+    /// let person: FullPerson = Person::new()
+    ///     .full(iri!("https://example.com/users/asonix/inbox"))
+    ///     .with_outbox(iri!("https://example.com/users/asonix/outbox"))
+    ///     .with_shares(iri!("https://example.com/users/asonix/shares"));
+    ///
+    /// assert!(person.outbox_unchecked().is_some());
+    /// assert!(person.shares().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn full(self, inbox: IriString) -> ApActor<ApObject<Self>> {
+        ApActor::new(inbox, ApObject::new(self))
+    }
+
     /// Create a new actor with `None` for it's `kind` property
     ///
     /// This means that no `type` field will be present in serialized JSON
@@ -1344,7 +1746,339 @@ impl<Inner> ApActor<Inner> {
     }
 }
 
+macro_rules! any_actor {
+    ($any_actor:expr, $actor:ident => $expr:expr) => {
+        match $any_actor {
+            AnyActor::Application($actor) => $expr,
+            AnyActor::Group($actor) => $expr,
+            AnyActor::Organization($actor) => $expr,
+            AnyActor::Person($actor) => $expr,
+            AnyActor::Service($actor) => $expr,
+            AnyActor::Other($actor) => $expr,
+        }
+    };
+}
+
+impl AnyActor {
+    /// Create an AnyActor from a generic Base, picking the variant based on its `type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::AnyActor, base::Base};
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"type":"Person","inbox":"https://example.com/inbox"}"#,
+    /// )?;
+    ///
+    /// let actor = AnyActor::from_base(base)?;
+    ///
+    /// assert_eq!(actor.kind_str(), Some("Person"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base(base: Base<serde_json::Value>) -> Result<Self, serde_json::Error> {
+        let any_actor = match base.kind().and_then(|kind| kind.as_str()) {
+            Some("Application") => AnyActor::Application(base.solidify()?.extend()?),
+            Some("Group") => AnyActor::Group(base.solidify()?.extend()?),
+            Some("Organization") => AnyActor::Organization(base.solidify()?.extend()?),
+            Some("Person") => AnyActor::Person(base.solidify()?.extend()?),
+            Some("Service") => AnyActor::Service(base.solidify()?.extend()?),
+            _ => AnyActor::Other(base.solidify()?.extend()?),
+        };
+
+        Ok(any_actor)
+    }
+
+    /// Create an AnyActor from an AnyBase
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::{AnyActor, ApActor, Person}, iri, prelude::*};
+    ///
+    /// let person = ApActor::new(iri!("https://example.com/inbox"), Person::new());
+    /// let any_base = person.into_any_base()?;
+    ///
+    /// let actor = AnyActor::from_any_base(any_base)?;
+    ///
+    /// assert!(matches!(actor, Some(AnyActor::Person(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_any_base(any_base: AnyBase) -> Result<Option<Self>, serde_json::Error> {
+        any_base.take_base().map(Self::from_base).transpose()
+    }
+
+    /// Create an AnyActor from a generic Base, or the type registered for its `type`
+    ///
+    /// Types registered with the `KindRegistry` take precedence over the variants of this enum.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{base::Base, actor::AnyActor, registry::KindRegistry};
+    ///
+    /// let registry = KindRegistry::new();
+    /// let base: Base<serde_json::Value> = serde_json::from_str(r#"{"type":"Person","inbox":"https://example.com/users/asonix/inbox"}"#)?;
+    ///
+    /// let actor = AnyActor::from_base_with(base, &registry)?.unregistered();
+    /// assert!(actor.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base_with(
+        base: Base<serde_json::Value>,
+        registry: &KindRegistry,
+    ) -> Result<Materialized<Self>, MaterializeError> {
+        registry.materialize_base(base, Self::from_base)
+    }
+
+    /// Create an AnyActor from an AnyBase, or the type registered for its `type`
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    pub fn from_any_base_with(
+        any_base: AnyBase,
+        registry: &KindRegistry,
+    ) -> Result<Option<Materialized<Self>>, MaterializeError> {
+        any_base
+            .take_base()
+            .map(|base| Self::from_base_with(base, registry))
+            .transpose()
+    }
+
+    /// Convert this AnyActor into an AnyBase
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    ///
+    /// let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    ///
+    /// let any_base = actor.into_any_base()?;
+    ///
+    /// assert_eq!(any_base.kind_str(), Some("Person"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_any_base(self) -> Result<AnyBase, serde_json::Error> {
+        any_actor!(self, actor => actor.into_any_base())
+    }
+
+    /// Fetch the `type` of the current actor as a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::{AnyActor, ApActor, Group}, iri};
+    ///
+    /// let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Group::new()));
+    ///
+    /// assert_eq!(actor.kind_str(), Some("Group"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn kind_str(&self) -> Option<&str> {
+        match self {
+            AnyActor::Application(_) => Some("Application"),
+            AnyActor::Group(_) => Some("Group"),
+            AnyActor::Organization(_) => Some("Organization"),
+            AnyActor::Person(_) => Some("Person"),
+            AnyActor::Service(_) => Some("Service"),
+            AnyActor::Other(actor) => actor.kind().map(String::as_str),
+        }
+    }
+
+    /// Fetch the id for the current actor
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    /// # let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    /// if let Some(id) = actor.id_unchecked() {
+    ///     println!("{:?}", id);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn id_unchecked(&self) -> Option<&IriString> {
+        any_actor!(self, actor => actor.id_unchecked())
+    }
+
+    /// Fetch the inbox for the current actor, checking it against the actor's id
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri, prelude::*};
+    /// # let mut person = ApActor::new(iri!("https://example.com/inbox"), Person::new());
+    /// # person.set_id(iri!("https://example.com/actor"));
+    /// # let actor = AnyActor::from(person);
+    /// let inbox = actor.inbox()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inbox(&self) -> Result<&IriString, CheckError> {
+        any_actor!(self, actor => actor.inbox())
+    }
+
+    /// Fetch the inbox for the current actor
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    /// # let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    /// let inbox = actor.inbox_unchecked();
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn inbox_unchecked(&self) -> &IriString {
+        any_actor!(self, actor => actor.inbox_unchecked())
+    }
+
+    /// Fetch the outbox for the current actor, checking it against the actor's id
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    /// # let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    /// if let Some(outbox) = actor.outbox()? {
+    ///     println!("{:?}", outbox);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outbox(&self) -> Result<Option<&IriString>, CheckError> {
+        any_actor!(self, actor => actor.outbox())
+    }
+
+    /// Fetch the outbox for the current actor
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    /// # let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    /// if let Some(outbox) = actor.outbox_unchecked() {
+    ///     println!("{:?}", outbox);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn outbox_unchecked(&self) -> Option<&IriString> {
+        any_actor!(self, actor => actor.outbox_unchecked())
+    }
+
+    /// Fetch the preferred_username for the current actor
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri};
+    /// # let actor = AnyActor::from(ApActor::new(iri!("https://example.com/inbox"), Person::new()));
+    /// if let Some(preferred_username) = actor.preferred_username() {
+    ///     println!("{:?}", preferred_username);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn preferred_username(&self) -> Option<&str> {
+        any_actor!(self, actor => actor.preferred_username())
+    }
+
+    /// Fetch a name suitable for displaying the current actor
+    ///
+    /// See `ApActorExt::display_name` for how the name is chosen
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{actor::{AnyActor, ApActor, Person}, iri, prelude::*};
+    /// # let mut person = ApActor::new(iri!("https://example.com/inbox"), Person::new());
+    /// # person.set_id(iri!("https://example.com/users/asonix"));
+    /// # let actor = AnyActor::from(person);
+    /// assert_eq!(actor.display_name(Some("en")), Some("asonix"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn display_name(&self, language: Option<&str>) -> Option<&str> {
+        any_actor!(self, actor => actor.display_name(language))
+    }
+}
+
 impl<T> Endpoints<T> {
+    /// Borrow the URLs in Endpoints
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::Endpoints, iri};
+    /// use iri_string::types::IriString;
+    ///
+    /// let endpoints: Endpoints<IriString> = Endpoints {
+    ///     upload_media: Some(iri!("https://example.com/upload")),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let borrowed = endpoints.as_ref();
+    ///
+    /// assert_eq!(
+    ///     borrowed.upload_media.map(|u| u.as_str()),
+    ///     Some("https://example.com/upload")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_ref(&self) -> Endpoints<&T> {
+        Endpoints {
+            proxy_url: self.proxy_url.as_ref(),
+            oauth_authorization_endpoint: self.oauth_authorization_endpoint.as_ref(),
+            oauth_token_endpoint: self.oauth_token_endpoint.as_ref(),
+            provide_client_key: self.provide_client_key.as_ref(),
+            sign_client_key: self.sign_client_key.as_ref(),
+            shared_inbox: self.shared_inbox.as_ref(),
+            upload_media: self.upload_media.as_ref(),
+            nonstandard: self
+                .nonstandard
+                .iter()
+                .map(|(k, v)| (k.clone(), v))
+                .collect(),
+        }
+    }
+
+    /// Iterate over every endpoint that is present, paired with its json key
+    ///
+    /// Standard endpoints come first, in the order they are defined in the spec, followed by any
+    /// nonstandard endpoints in no particular order.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{actor::Endpoints, iri};
+    /// use iri_string::types::IriString;
+    ///
+    /// let endpoints: Endpoints<IriString> = Endpoints {
+    ///     shared_inbox: Some(iri!("https://example.com/inbox")),
+    ///     upload_media: Some(iri!("https://example.com/upload")),
+    ///     ..Default::default()
+    /// };
+    ///
+    /// let keys: Vec<_> = endpoints.iter().map(|(key, _)| key).collect();
+    ///
+    /// assert_eq!(keys, ["sharedInbox", "uploadMedia"]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &T)> + '_ {
+        [
+            ("proxyUrl", &self.proxy_url),
+            (
+                "oauthAuthorizationEndpoint",
+                &self.oauth_authorization_endpoint,
+            ),
+            ("oauthTokenEndpoint", &self.oauth_token_endpoint),
+            ("provideClientKey", &self.provide_client_key),
+            ("signClientKey", &self.sign_client_key),
+            ("sharedInbox", &self.shared_inbox),
+            ("uploadMedia", &self.upload_media),
+        ]
+        .into_iter()
+        .filter_map(|(key, value)| Some((key, value.as_ref()?)))
+        .chain(self.nonstandard.iter().map(|(k, v)| (k.as_str(), v)))
+    }
+
     /// Map the URLs in Endpoints from T to U
     ///
     /// ```rust
@@ -1374,6 +2108,7 @@ impl<T> Endpoints<T> {
             provide_client_key: self.provide_client_key.map(f),
             sign_client_key: self.sign_client_key.map(f),
             shared_inbox: self.shared_inbox.map(f),
+            upload_media: self.upload_media.map(f),
             nonstandard: self
                 .nonstandard
                 .into_iter()
@@ -1392,6 +2127,7 @@ impl<T> Default for Endpoints<T> {
             provide_client_key: None,
             sign_client_key: None,
             shared_inbox: None,
+            upload_media: None,
             nonstandard: HashMap::new(),
         }
     }
@@ -1556,6 +2292,52 @@ where
     }
 }
 
+impl<'de> serde::de::Deserialize<'de> for AnyActor {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let base = Base::<serde_json::Value>::deserialize(deserializer)?;
+        AnyActor::from_base(base).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<ApActor<Application>> for AnyActor {
+    fn from(actor: ApActor<Application>) -> Self {
+        AnyActor::Application(actor)
+    }
+}
+
+impl From<ApActor<Group>> for AnyActor {
+    fn from(actor: ApActor<Group>) -> Self {
+        AnyActor::Group(actor)
+    }
+}
+
+impl From<ApActor<Organization>> for AnyActor {
+    fn from(actor: ApActor<Organization>) -> Self {
+        AnyActor::Organization(actor)
+    }
+}
+
+impl From<ApActor<Person>> for AnyActor {
+    fn from(actor: ApActor<Person>) -> Self {
+        AnyActor::Person(actor)
+    }
+}
+
+impl From<ApActor<Service>> for AnyActor {
+    fn from(actor: ApActor<Service>) -> Self {
+        AnyActor::Service(actor)
+    }
+}
+
+impl From<ApActor<Actor<String>>> for AnyActor {
+    fn from(actor: ApActor<Actor<String>>) -> Self {
+        AnyActor::Other(actor)
+    }
+}
+
 impl<Kind> Default for Actor<Kind>
 where
     Kind: Default,
@@ -1564,3 +2346,31 @@ where
         Self::new()
     }
 }
+
+impl<Inner> Validate for ApActor<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        // Actors embedded in other documents commonly leave out their outbox
+        if self.outbox.is_none() && violations.at_root() {
+            violations.report("an actor must have an outbox");
+        }
+
+        violations.field("streams", &self.streams);
+    }
+}
+
+impl<Kind> Validate for Actor<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.0.validate_into(violations)
+    }
+}
+
+impl Validate for AnyActor {
+    fn validate_into(&self, violations: &mut Violations) {
+        any_actor!(self, actor => actor.validate_into(violations))
+    }
+}
@@ -0,0 +1,596 @@
+//! Proptest strategies for ActivityStreams values
+//!
+//! With the `proptest` feature enabled, the primitives in this crate implement
+//! `proptest::arbitrary::Arbitrary`, as do `Base<Kind>` and `Object<Kind>` for any kind with a
+//! default value, like the kinds in `activitystreams::object::kind`. The values generated are
+//! ones that could appear in a real document: ids are `https` IRIs on a handful of hosts, strings
+//! are tagged with common language tags, and dates fall between 1970 and 2100.
+//!
+//! ```rust
+//! use activitystreams::{object::Note, prelude::*};
+//! use proptest::prelude::*;
+//!
+//! proptest! {
+//!     fn notes_survive_json(note in any::<Note>()) {
+//!         let json = serde_json::to_string(&note).unwrap();
+//!         let parsed: Note = serde_json::from_str(&json).unwrap();
+//!
+//!         prop_assert_eq!(parsed.id_unchecked(), note.id_unchecked());
+//!         prop_assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+//!     }
+//! }
+//!
+//! notes_survive_json();
+//! ```
+use crate::{
+    base::{AnyBase, Base, BaseExt},
+    object::{Object, ObjectExt},
+    primitives::{
+        AnyString, BoundedXsdString, Direction, Either, LanguageTag, NonEmptyXsdString, OneOrMany,
+        RdfLangString, RdfLangStringMap, Unit, XsdBase64Binary, XsdBoolean, XsdDate, XsdDateTime,
+        XsdDouble, XsdDuration, XsdFloat, XsdHexBinary, XsdInteger, XsdNonNegativeInteger,
+        XsdPositiveInteger, XsdTime, XsdUnsignedLong,
+    },
+};
+use iri_string::types::IriString;
+use proptest::{
+    arbitrary::{any, Arbitrary},
+    collection::{btree_map, vec},
+    option, prop_oneof,
+    strategy::{BoxedStrategy, Just, Strategy},
+};
+use std::{fmt::Debug, num::NonZeroU64};
+
+const HOSTS: &[&str] = &[
+    "example.com",
+    "example.org",
+    "social.example",
+    "xn--mnchen-3ya.example",
+];
+
+const LANGUAGES: &[&str] = &[
+    "en",
+    "en-US",
+    "fr",
+    "de-CH",
+    "ja",
+    "pt-BR",
+    "zh-Hant-TW",
+    "und",
+];
+
+/// 1970-01-01T00:00:00Z
+const MIN_TIMESTAMP: i64 = 0;
+
+/// 2100-01-01T00:00:00Z
+const MAX_TIMESTAMP: i64 = 4_102_444_800;
+
+/// A strategy for `https` IRIs on a handful of hosts, like `https://example.com/users/a1`
+///
+/// Hosts are drawn from a small set so that generated ids often share an origin.
+pub fn iri() -> impl Strategy<Value = IriString> {
+    (
+        proptest::sample::select(HOSTS),
+        vec("[a-z0-9_-]{1,12}", 0..4),
+        option::of("[a-z]{1,8}=[a-z0-9]{1,8}"),
+    )
+        .prop_map(|(host, segments, query)| {
+            let mut s = format!("https://{}", host);
+
+            for segment in segments {
+                s.push('/');
+                s.push_str(&segment);
+            }
+
+            if let Some(query) = query {
+                s.push('?');
+                s.push_str(&query);
+            }
+
+            IriString::try_from(s).expect("Generated IRIs are valid")
+        })
+}
+
+fn one_or_many<S>(strategy: S) -> impl Strategy<Value = OneOrMany<S::Value>>
+where
+    S: Strategy + 'static,
+{
+    let strategy = strategy.boxed();
+
+    prop_oneof![
+        strategy.clone().prop_map(OneOrMany::from_one),
+        vec(strategy, 0..4).prop_map(OneOrMany::from_many),
+    ]
+}
+
+fn any_base() -> impl Strategy<Value = AnyBase> {
+    iri().prop_map(AnyBase::from_xsd_any_uri)
+}
+
+impl Arbitrary for XsdBoolean {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<bool>().prop_map(XsdBoolean).boxed()
+    }
+}
+
+impl Arbitrary for XsdInteger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<i64>().prop_map(XsdInteger).boxed()
+    }
+}
+
+impl Arbitrary for XsdNonNegativeInteger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u64>().prop_map(XsdNonNegativeInteger).boxed()
+    }
+}
+
+impl Arbitrary for XsdPositiveInteger {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<NonZeroU64>().prop_map(XsdPositiveInteger).boxed()
+    }
+}
+
+impl Arbitrary for XsdUnsignedLong {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        any::<u64>().prop_map(XsdUnsignedLong).boxed()
+    }
+}
+
+impl Arbitrary for XsdFloat {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Values have at most three decimal places, like the coordinates and sizes found in real
+    /// documents, so they survive serde_json's default float parsing unchanged
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (-1_000_000_000i64..1_000_000_000)
+            .prop_map(|thousandths| XsdFloat(thousandths as f64 / 1000.0))
+            .boxed()
+    }
+}
+
+impl Arbitrary for XsdDouble {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    /// Values use the full precision of an f64, so reading them back from JSON exactly needs the
+    /// `float-roundtrip` feature
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::num::f64::NORMAL.prop_map(XsdDouble).boxed()
+    }
+}
+
+impl Arbitrary for XsdDateTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            MIN_TIMESTAMP..MAX_TIMESTAMP,
+            prop_oneof![Just(0), 0u32..1_000_000_000],
+            option::of(-14 * 60..=14 * 60i32),
+        )
+            .prop_map(|(timestamp, nanosecond, offset)| {
+                let datetime = time::OffsetDateTime::from_unix_timestamp(timestamp)
+                    .expect("Timestamp is in range")
+                    .replace_nanosecond(nanosecond)
+                    .expect("Nanosecond is in range");
+
+                match offset {
+                    Some(minutes) => {
+                        let offset = time::UtcOffset::from_whole_seconds(minutes * 60)
+                            .expect("Offset is in range");
+                        XsdDateTime::new(datetime.to_offset(offset))
+                    }
+                    // Without an offset, the datetime is parsed from a string with none
+                    None => datetime
+                        .format(&time::format_description::well_known::Rfc3339)
+                        .expect("Datetime can be formatted")
+                        .trim_end_matches('Z')
+                        .parse()
+                        .expect("Datetime without an offset is valid"),
+                }
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for XsdDate {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (MIN_TIMESTAMP..MAX_TIMESTAMP)
+            .prop_map(|timestamp| {
+                let datetime = time::OffsetDateTime::from_unix_timestamp(timestamp)
+                    .expect("Timestamp is in range");
+                XsdDate::new(datetime.date())
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for XsdTime {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            0u8..24,
+            0u8..60,
+            0u8..60,
+            prop_oneof![Just(0), 0u32..1_000_000_000],
+            option::of(-14 * 60..=14 * 60i32),
+        )
+            .prop_map(|(hour, minute, second, nanosecond, offset)| {
+                let time = time::Time::from_hms_nano(hour, minute, second, nanosecond)
+                    .expect("Time is in range");
+
+                match offset {
+                    Some(minutes) => XsdTime::with_offset(
+                        time,
+                        time::UtcOffset::from_whole_seconds(minutes * 60)
+                            .expect("Offset is in range"),
+                    ),
+                    None => XsdTime::new(time),
+                }
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for XsdDuration {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        (
+            any::<bool>(),
+            0u64..10,
+            0u64..12,
+            0u64..31,
+            0u64..86_400,
+            prop_oneof![Just(0), 0u32..1_000_000_000],
+        )
+            .prop_map(|(negative, years, months, days, seconds, nanoseconds)| {
+                let mut s = String::new();
+
+                if negative {
+                    s.push('-');
+                }
+
+                s.push_str(&format!(
+                    "P{}Y{}M{}DT{}.{:09}S",
+                    years, months, days, seconds, nanoseconds
+                ));
+
+                s.parse().expect("Generated durations are valid")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for XsdBase64Binary {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<u8>(), 0..64).prop_map(XsdBase64Binary).boxed()
+    }
+}
+
+impl Arbitrary for XsdHexBinary {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<u8>(), 0..64).prop_map(XsdHexBinary).boxed()
+    }
+}
+
+impl Arbitrary for LanguageTag {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(LANGUAGES)
+            .prop_map(|tag| LanguageTag::parse(tag).expect("Language tags are valid"))
+            .boxed()
+    }
+}
+
+impl Arbitrary for RdfLangString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let direction = option::of(prop_oneof![Just(Direction::Ltr), Just(Direction::Rtl)]);
+
+        (any::<String>(), any::<LanguageTag>(), direction)
+            .prop_map(|(value, language, direction)| RdfLangString {
+                value,
+                language,
+                direction,
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for RdfLangStringMap {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        btree_map(any::<LanguageTag>(), any::<String>(), 1..4)
+            .prop_map(RdfLangStringMap::from)
+            .boxed()
+    }
+}
+
+impl Arbitrary for AnyString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            any::<String>().prop_map(AnyString::from_xsd_string),
+            any::<RdfLangString>().prop_map(AnyString::from_rdf_lang_string),
+        ]
+        .boxed()
+    }
+}
+
+impl<const MAX: usize> Arbitrary for BoundedXsdString<MAX> {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        vec(any::<char>(), 0..=MAX)
+            .prop_map(|chars| {
+                BoundedXsdString::new(chars.into_iter().collect::<String>())
+                    .expect("Generated strings fit")
+            })
+            .boxed()
+    }
+}
+
+impl Arbitrary for NonEmptyXsdString {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        "\\PC*\\S\\PC*"
+            .prop_map(|s| NonEmptyXsdString::new(s).expect("Generated strings aren't blank"))
+            .boxed()
+    }
+}
+
+impl Arbitrary for Unit {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            Just(Unit::centimeters()),
+            Just(Unit::meters()),
+            Just(Unit::kilometers()),
+            Just(Unit::feet()),
+            Just(Unit::inches()),
+            iri().prop_map(|iri| Unit::custom(iri.as_str())),
+        ]
+        .boxed()
+    }
+}
+
+impl<T> Arbitrary for OneOrMany<T>
+where
+    T: Arbitrary + 'static,
+{
+    type Parameters = T::Parameters;
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(args: Self::Parameters) -> Self::Strategy {
+        one_or_many(proptest::arbitrary::any_with::<T>(args)).boxed()
+    }
+}
+
+impl<L, R> Arbitrary for Either<L, R>
+where
+    L: Arbitrary + 'static,
+    R: Arbitrary + 'static,
+{
+    type Parameters = (L::Parameters, R::Parameters);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((left, right): Self::Parameters) -> Self::Strategy {
+        prop_oneof![
+            proptest::arbitrary::any_with::<L>(left).prop_map(Either::Left),
+            proptest::arbitrary::any_with::<R>(right).prop_map(Either::Right),
+        ]
+        .boxed()
+    }
+}
+
+fn base_fields() -> impl Strategy<
+    Value = (
+        Option<IriString>,
+        Option<OneOrMany<AnyString>>,
+        Option<RdfLangStringMap>,
+    ),
+> {
+    (
+        option::of(iri()),
+        option::of(one_or_many(any::<AnyString>())),
+        option::of(any::<RdfLangStringMap>()),
+    )
+}
+
+fn set_base_fields<T>(
+    base: &mut T,
+    (id, name, name_map): (
+        Option<IriString>,
+        Option<OneOrMany<AnyString>>,
+        Option<RdfLangStringMap>,
+    ),
+) where
+    T: BaseExt,
+{
+    if let Some(id) = id {
+        base.set_id(id);
+    }
+    if let Some(name) = name {
+        base.set_many_names(name);
+    }
+    if let Some(name_map) = name_map {
+        base.set_name_map(name_map);
+    }
+}
+
+impl<Kind> Arbitrary for Base<Kind>
+where
+    Kind: Default + Debug + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        base_fields()
+            .prop_map(|fields| {
+                let mut base = Base::new();
+                set_base_fields(&mut base, fields);
+                base
+            })
+            .boxed()
+    }
+}
+
+impl<Kind> Arbitrary for Object<Kind>
+where
+    Kind: Default + Debug + 'static,
+{
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        let text = || option::of(one_or_many(any::<AnyString>()));
+        let addressing = || option::of(one_or_many(any_base()));
+
+        (
+            base_fields(),
+            (text(), text()),
+            (
+                option::of(any::<XsdDateTime>()),
+                option::of(any::<XsdDuration>()),
+            ),
+            (addressing(), addressing(), addressing(), addressing()),
+        )
+            .prop_map(
+                |(
+                    fields,
+                    (content, summary),
+                    (published, duration),
+                    (attributed_to, in_reply_to, to, cc),
+                )| {
+                    let mut object = Object::new();
+                    set_base_fields(&mut object, fields);
+
+                    if let Some(content) = content {
+                        object.set_many_contents(content);
+                    }
+                    if let Some(summary) = summary {
+                        object.set_many_summaries(summary);
+                    }
+                    if let Some(published) = published {
+                        object.set_published(published.into_inner());
+                    }
+                    if let Some(duration) = duration {
+                        object.set_duration(duration.into_inner());
+                    }
+                    if let Some(attributed_to) = attributed_to {
+                        object.set_many_attributed_tos(attributed_to);
+                    }
+                    if let Some(in_reply_to) = in_reply_to {
+                        object.set_many_in_reply_tos(in_reply_to);
+                    }
+                    if let Some(to) = to {
+                        object.set_many_tos(to);
+                    }
+                    if let Some(cc) = cc {
+                        object.set_many_ccs(cc);
+                    }
+
+                    object
+                },
+            )
+            .boxed()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        object::Note,
+        primitives::{
+            AnyString, RdfLangStringMap, XsdBase64Binary, XsdDateTime, XsdDuration, XsdFloat,
+            XsdHexBinary, XsdTime,
+        },
+    };
+    use proptest::prelude::*;
+
+    fn round_trip<T>(value: &T) -> T
+    where
+        T: serde::de::DeserializeOwned + serde::ser::Serialize,
+    {
+        let json = serde_json::to_string(value).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn primitives_round_trip(
+            datetime in any::<XsdDateTime>(),
+            time in any::<XsdTime>(),
+            duration in any::<XsdDuration>(),
+            float in any::<XsdFloat>(),
+            string in any::<AnyString>(),
+            map in any::<RdfLangStringMap>(),
+            base64 in any::<XsdBase64Binary>(),
+            hex in any::<XsdHexBinary>(),
+        ) {
+            let parsed = round_trip(&datetime);
+            prop_assert_eq!(parsed.has_offset(), datetime.has_offset());
+            prop_assert_eq!(parsed, datetime);
+            prop_assert_eq!(round_trip(&time).to_string(), time.to_string());
+            prop_assert_eq!(round_trip(&duration), duration);
+            prop_assert_eq!(round_trip(&float), float);
+            prop_assert_eq!(round_trip(&string), string);
+            prop_assert_eq!(round_trip(&map), map);
+            prop_assert_eq!(round_trip(&base64), base64);
+            prop_assert_eq!(round_trip(&hex), hex);
+        }
+
+        #[test]
+        fn notes_round_trip(note in any::<Note>()) {
+            let json = serde_json::to_value(&note).unwrap();
+            let parsed: Note = serde_json::from_value(json.clone()).unwrap();
+
+            prop_assert_eq!(serde_json::to_value(&parsed).unwrap(), json);
+        }
+    }
+}
@@ -30,11 +30,13 @@
 use crate::{
     checked::{check, CheckError},
     markers,
-    primitives::{AnyString, Either, MimeMediaType, OneOrMany},
+    primitives::{AnyString, Either, Iter, MimeMediaType, OneOrMany, RdfLangStringMap, XsdStr},
     unparsed::{Unparsed, UnparsedMut},
+    validate::{validate_embedded, Validate, Violations},
 };
 use iri_string::types::{IriStr, IriString};
 use mime::Mime;
+use std::sync::Arc;
 
 /// Implements conversion between `Base<Kind>` and other ActivityStreams objects defined in this
 /// crate
@@ -144,6 +146,27 @@ pub trait BaseExt: AsBase {
         self.base_ref().context.as_ref()
     }
 
+    /// Iterate over the context for the current object
+    ///
+    /// This yields nothing if context isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for context in video.iter_context() {
+    ///     println!("{:?}", context);
+    /// }
+    /// ```
+    fn iter_context<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.context().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the context for the current object
     ///
     /// This overwrites the contents of context
@@ -164,6 +187,27 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set the context for the current object, returning it
+    ///
+    /// This is the consuming version of `set_context`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    /// #
+    /// use activitystreams::{context, prelude::*};
+    ///
+    /// let video = video.with_context(context());
+    /// ```
+    fn with_context<T>(mut self, context: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_context(context);
+        self
+    }
+
     /// Set many contexts for the current object
     ///
     /// This overwrites the contents of context
@@ -186,6 +230,28 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set many contexts for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_contexts`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    /// #
+    /// use activitystreams::{context, prelude::*, security};
+    ///
+    /// let video = video.with_many_contexts(vec![context(), security()]);
+    /// ```
+    fn with_many_contexts<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_contexts(items);
+        self
+    }
+
     /// Add a context to the current object
     ///
     /// This does not overwrite the contents of context, only appends a new item
@@ -312,7 +378,7 @@ pub trait BaseExt: AsBase {
     where
         Self::Kind: 'a,
     {
-        self.base_ref().id.as_ref()
+        self.base_ref().id.as_deref()
     }
 
     /// Mutably borrow the ID from the current object
@@ -364,7 +430,7 @@ pub trait BaseExt: AsBase {
     where
         Self::Kind: 'a,
     {
-        self.base_mut().id.as_mut()
+        self.base_mut().id.as_mut().map(Arc::make_mut)
     }
 
     /// Check if the provided id is equal to the object's id
@@ -399,7 +465,33 @@ pub trait BaseExt: AsBase {
     /// # }
     /// ```
     fn set_id(&mut self, id: IriString) -> &mut Self {
-        self.base_mut().id = Some(id);
+        self.base_mut().id = Some(Arc::new(id));
+        self
+    }
+
+    /// Set the id for the current object, returning it
+    ///
+    /// This is the consuming version of `set_id`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, object::Video, prelude::*};
+    ///
+    /// fn make_video() -> Result<Video, anyhow::Error> {
+    ///     Ok(Video::new()
+    ///         .with_id(iri!("https://example.com/videos/1"))
+    ///         .with_name("My Video"))
+    /// }
+    ///
+    /// assert!(make_video()?.id_unchecked().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_id(mut self, id: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_id(id);
         self
     }
 
@@ -416,7 +508,7 @@ pub trait BaseExt: AsBase {
     /// }
     /// ```
     fn take_id(&mut self) -> Option<IriString> {
-        self.base_mut().id.take()
+        self.base_mut().id.take().map(Arc::unwrap_or_clone)
     }
 
     /// Delete the id from the current object
@@ -497,6 +589,25 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set the kind for the current object, returning it
+    ///
+    /// This is the consuming version of `set_kind`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{Video, kind::VideoType};
+    /// # let video = Video::new();
+    /// use activitystreams::prelude::*;
+    ///
+    /// let video = video.with_kind(VideoType::Video);
+    /// ```
+    fn with_kind(mut self, kind: Self::Kind) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_kind(kind);
+        self
+    }
+
     /// Take the kind from the current object, leaving nothing
     ///
     /// ```rust
@@ -550,6 +661,31 @@ pub trait BaseExt: AsBase {
         self.base_ref().name.as_ref().map(|o| o.as_ref())
     }
 
+    /// Iterate over the name for the current object
+    ///
+    /// This yields nothing if name isn't set, so there's no need to match on one or many.
+    ///
+    /// ```
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for name in video.iter_name() {
+    ///     println!("{:?}", name);
+    /// }
+    /// ```
+    fn iter_name<'a>(&'a self) -> Iter<'a, AnyString>
+    where
+        Self::Kind: 'a,
+    {
+        self.base_ref()
+            .name
+            .as_ref()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
     /// Set the name for the current object
     ///
     /// This overwrites the contents of name
@@ -569,6 +705,26 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set the name for the current object, returning it
+    ///
+    /// This is the consuming version of `set_name`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    /// #
+    /// let video = video.with_name("hi");
+    /// ```
+    fn with_name<T>(mut self, name: T) -> Self
+    where
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_name(name);
+        self
+    }
+
     /// Set many names for the current object
     ///
     /// This overwrites the contents of name
@@ -590,6 +746,27 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set many names for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_names`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    /// #
+    /// let video = video.with_many_names(vec!["hi", "hey"]);
+    /// ```
+    fn with_many_names<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_many_names(items);
+        self
+    }
+
     /// Add a name to the current object
     ///
     /// This does not overwrite the contents of name, only appends a new item
@@ -652,7 +829,80 @@ pub trait BaseExt: AsBase {
         self
     }
 
-    /// Fetch the media type for the current object
+    /// Fetch the nameMap for the current object
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note: Note = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "nameMap": {
+    ///         "fr": "Bonjour"
+    ///     }
+    /// }"#)?;
+    ///
+    /// assert_eq!(
+    ///     note.name_map().and_then(|map| map.get("fr")),
+    ///     Some("Bonjour")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn name_map(&self) -> Option<&RdfLangStringMap> {
+        self.base_ref().name_map.as_ref()
+    }
+
+    /// Set the nameMap for the current object
+    ///
+    /// This overwrites the contents of nameMap
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "Hello");
+    ///
+    /// let mut note = Note::new();
+    /// note.set_name_map(map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_name_map<T>(&mut self, name_map: T) -> &mut Self
+    where
+        T: Into<RdfLangStringMap>,
+    {
+        self.base_mut().name_map = Some(name_map.into());
+        self
+    }
+
+    /// Set the nameMap for the current object, returning it
+    ///
+    /// This is the consuming version of `set_name_map`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "Hello");
+    ///
+    /// let note = Note::new();
+    /// let note = note.with_name_map(map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_name_map<T>(mut self, name_map: T) -> Self
+    where
+        T: Into<RdfLangStringMap>,
+        Self: Sized,
+    {
+        self.set_name_map(name_map);
+        self
+    }
+
+    /// Take the nameMap from the current object, leaving nothing
     ///
     /// ```rust
     /// # use activitystreams::object::Video;
@@ -660,10 +910,63 @@ pub trait BaseExt: AsBase {
     /// #
     /// use activitystreams::prelude::*;
     ///
-    /// if let Some(media_type) = video.media_type() {
-    ///     println!("{:?}", media_type);
+    /// if let Some(name_map) = video.take_name_map() {
+    ///     println!("{:?}", name_map);
     /// }
     /// ```
+    fn take_name_map(&mut self) -> Option<RdfLangStringMap> {
+        self.base_mut().name_map.take()
+    }
+
+    /// Delete the nameMap from the current object
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::Video, primitives::RdfLangString};
+    /// # let mut video = Video::new();
+    /// # video.set_name_map(RdfLangString {
+    /// #     value: "Hello".to_owned(),
+    /// #     language: "en".parse()?,
+    /// #     direction: None,
+    /// # });
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// assert!(video.name_map().is_some());
+    /// video.delete_name_map();
+    /// assert!(video.name_map().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn delete_name_map(&mut self) -> &mut Self {
+        self.base_mut().name_map = None;
+        self
+    }
+
+    /// Fetch the media type for the current object
+    ///
+    /// Media types are parsed into a `mime::Mime` when deserializing, so the type, subtype, and
+    /// parameters can be inspected directly, and malformed media types fail to deserialize.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Video, prelude::*};
+    ///
+    /// let video: Video = serde_json::from_str(r#"{
+    ///     "type": "Video",
+    ///     "mediaType": "video/webm; codecs=vp9"
+    /// }"#)?;
+    ///
+    /// let media_type = video.media_type().expect("mediaType is present");
+    /// assert_eq!(media_type.type_(), "video");
+    /// assert_eq!(media_type.subtype(), "webm");
+    /// assert_eq!(media_type.get_param("codecs").map(|c| c.as_str()), Some("vp9"));
+    ///
+    /// let malformed = r#"{"type":"Video","mediaType":"video"}"#;
+    /// assert!(serde_json::from_str::<Video>(malformed).is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
     fn media_type<'a>(&'a self) -> Option<&'a Mime>
     where
         Self::Kind: 'a,
@@ -690,6 +993,28 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set the media type for the current object, returning it
+    ///
+    /// This is the consuming version of `set_media_type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_media_type("video/webm".parse()?);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_media_type(mut self, media_type: Mime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_media_type(media_type);
+        self
+    }
+
     /// Take the media type from the current object, leaving nothing
     ///
     /// ```rust
@@ -746,6 +1071,31 @@ pub trait BaseExt: AsBase {
         self.base_ref().preview.as_ref().map(|o| o.as_ref())
     }
 
+    /// Iterate over the preview for the current object
+    ///
+    /// This yields nothing if preview isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for preview in video.iter_preview() {
+    ///     println!("{:?}", preview);
+    /// }
+    /// ```
+    fn iter_preview<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.base_ref()
+            .preview
+            .as_ref()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
     /// Set the preview for the current object
     ///
     /// This overwrites the contents of preview
@@ -768,6 +1118,29 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set the preview for the current object, returning it
+    ///
+    /// This is the consuming version of `set_preview`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_preview(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_preview<T>(mut self, preview: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_preview(preview);
+        self
+    }
+
     /// Set many previews for the current object
     ///
     /// This overwrites the contents of preview
@@ -795,6 +1168,33 @@ pub trait BaseExt: AsBase {
         self
     }
 
+    /// Set many previews for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_previews`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_previews(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_previews<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_previews(items);
+        self
+    }
+
     /// Add a preview to the current object
     ///
     /// This does not overwrite the contents of preview, only appends an item
@@ -862,11 +1262,54 @@ pub trait BaseExt: AsBase {
         self.base_mut().preview = None;
         self
     }
+
+    /// Calculate the number of bytes the current object takes up when serialized as JSON
+    ///
+    /// This doesn't allocate the serialized JSON, so it's a cheap way to check an object against
+    /// a payload size limit before delivering it.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// use activitystreams::prelude::*;
+    ///
+    /// video.set_name("hi");
+    ///
+    /// assert_eq!(video.estimated_json_len()?, serde_json::to_string(&video)?.len());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn estimated_json_len(&self) -> Result<usize, serde_json::Error>
+    where
+        Self: serde::ser::Serialize,
+    {
+        struct Counter(usize);
+
+        impl std::io::Write for Counter {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                self.0 += buf.len();
+                Ok(buf.len())
+            }
+
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut counter = Counter(0);
+        serde_json::to_writer(&mut counter, self)?;
+
+        Ok(counter.0)
+    }
 }
 
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+/// Embedded objects and ids are reference-counted so cloning a document doesn't deep-copy every
+/// object or IRI nested inside it. They're copied on write, the first time a shared value is
+/// mutated.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
-struct IdOrBase(Either<IriString, Box<Base<serde_json::Value>>>);
+struct IdOrBase(Either<Arc<IriString>, Arc<Base<serde_json::Value>>>);
 
 /// A type that can represent Any ActivityStreams type
 ///
@@ -875,7 +1318,7 @@ struct IdOrBase(Either<IriString, Box<Base<serde_json::Value>>>);
 /// - A Link
 /// - The ID of that Link or Object
 /// - A string representing that Link or Object
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
 pub struct AnyBase(Either<IdOrBase, String>);
 
@@ -884,7 +1327,7 @@ pub struct AnyBase(Either<IdOrBase, String>);
 /// Although the spec does not define a type more abstract that Object or Link, it does define
 /// fields present in both, so for the sake of "Everything derives from something," I've
 /// implemented a type.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Base<Kind> {
     /// Identifies the context within which the object exists or an activity was performed.
@@ -896,7 +1339,7 @@ pub struct Base<Kind> {
     /// - Range: Object | Link
     /// - Functional: false
     #[serde(rename = "@context")]
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     context: Option<OneOrMany<AnyBase>>,
 
     /// Provides the globally unique identifier for an Object or Link.
@@ -912,7 +1355,7 @@ pub struct Base<Kind> {
     /// When processing Activity Streams 1.0 documents and converting those to 2.0, implementations
     /// ought to treat id as an alias for the JSON-LD @id key word[.]
     #[serde(skip_serializing_if = "Option::is_none")]
-    id: Option<IriString>,
+    id: Option<Arc<IriString>>,
 
     /// The `type` field
     ///
@@ -948,9 +1391,19 @@ pub struct Base<Kind> {
     ///
     /// - Range: xsd:string | rdf:langString
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     name: Option<OneOrMany<AnyString>>,
 
+    /// The name of the object, keyed by language tag.
+    ///
+    /// This is the JSON-LD language map form of name, emitted instead of or alongside a list of
+    /// language-tagged values.
+    ///
+    /// - Range: rdf:langString
+    /// - Functional: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name_map: Option<RdfLangStringMap>,
+
     /// When used on an Object, identifies the MIME media type of the value of the content property.
     ///
     /// If not specified, the content property is assumed to contain text/html content.
@@ -964,7 +1417,7 @@ pub struct Base<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     preview: Option<OneOrMany<AnyBase>>,
 
     /// Any additional data present on the object if parsed from JSON
@@ -974,6 +1427,229 @@ pub struct Base<Kind> {
     unparsed: Unparsed,
 }
 
+/// A borrowed view of the fields most often read from an incoming document
+///
+/// Deserializing into `Base` or one of the concrete types allocates for every string in the
+/// payload. When an inbox only needs to look at a document before deciding whether to process
+/// it, `BaseRef` reads the `id`, `type`, `name`, and `content` straight out of the input without
+/// copying them, and skips everything else.
+///
+/// Each field is only read when it holds a single plain string. Lists, language maps, and
+/// embedded objects are skipped and reported as `None` rather than failing the parse.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::base::BaseRef;
+///
+/// let json = r#"{
+///     "id": "https://example.com/notes/1",
+///     "type": "Note",
+///     "name": ["first", "second"],
+///     "content": "<p>hi</p>",
+///     "attributedTo": "https://example.com/users/asonix"
+/// }"#;
+///
+/// let base: BaseRef = serde_json::from_str(json)?;
+///
+/// assert_eq!(base.id().map(|id| id.as_str()), Some("https://example.com/notes/1"));
+/// assert_eq!(base.kind().map(|kind| kind.as_str()), Some("Note"));
+/// assert!(base.name().is_none());
+/// assert!(base.content().unwrap().is_borrowed());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize)]
+pub struct BaseRef<'a> {
+    #[serde(borrow, default, deserialize_with = "plain_str")]
+    id: Option<XsdStr<'a>>,
+
+    #[serde(rename = "type")]
+    #[serde(alias = "@type")]
+    #[serde(alias = "objectType")]
+    #[serde(alias = "verb")]
+    #[serde(borrow, default, deserialize_with = "plain_str")]
+    kind: Option<XsdStr<'a>>,
+
+    #[serde(borrow, default, deserialize_with = "plain_str")]
+    name: Option<XsdStr<'a>>,
+
+    #[serde(borrow, default, deserialize_with = "plain_str")]
+    content: Option<XsdStr<'a>>,
+}
+
+impl<'a> BaseRef<'a> {
+    /// Fetch the id of the document
+    ///
+    /// The id is not checked to be a valid IRI.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::base::BaseRef;
+    ///
+    /// let base: BaseRef = serde_json::from_str(r#"{"id":"https://example.com/1"}"#)?;
+    ///
+    /// assert_eq!(base.id().map(|id| id.as_str()), Some("https://example.com/1"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn id(&self) -> Option<&XsdStr<'a>> {
+        self.id.as_ref()
+    }
+
+    /// Fetch the type of the document
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::base::BaseRef;
+    ///
+    /// let base: BaseRef = serde_json::from_str(r#"{"type":"Create"}"#)?;
+    ///
+    /// assert_eq!(base.kind().map(|kind| kind.as_str()), Some("Create"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn kind(&self) -> Option<&XsdStr<'a>> {
+        self.kind.as_ref()
+    }
+
+    /// Fetch the name of the document, if it is a single plain string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::base::BaseRef;
+    ///
+    /// let base: BaseRef = serde_json::from_str(r#"{"name":"hi"}"#)?;
+    /// assert_eq!(base.name().map(|name| name.as_str()), Some("hi"));
+    ///
+    /// let base: BaseRef = serde_json::from_str(r#"{"name":{"@value":"hi","@language":"en"}}"#)?;
+    /// assert!(base.name().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn name(&self) -> Option<&XsdStr<'a>> {
+        self.name.as_ref()
+    }
+
+    /// Fetch the content of the document, if it is a single plain string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::base::BaseRef;
+    ///
+    /// let base: BaseRef = serde_json::from_str(r#"{"content":"<p>hi</p>"}"#)?;
+    ///
+    /// assert_eq!(base.content().map(|content| content.as_str()), Some("<p>hi</p>"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn content(&self) -> Option<&XsdStr<'a>> {
+        self.content.as_ref()
+    }
+
+    /// Copy any borrowed fields so the view no longer borrows from the input
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::base::BaseRef;
+    ///
+    /// let json = String::from(r#"{"type":"Note"}"#);
+    /// let base = serde_json::from_str::<BaseRef>(&json)?.into_static();
+    /// drop(json);
+    ///
+    /// assert_eq!(base.kind().map(|kind| kind.as_str()), Some("Note"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_static(self) -> BaseRef<'static> {
+        BaseRef {
+            id: self.id.map(XsdStr::into_static),
+            kind: self.kind.map(XsdStr::into_static),
+            name: self.name.map(XsdStr::into_static),
+            content: self.content.map(XsdStr::into_static),
+        }
+    }
+}
+
+/// Read a plain string, skipping over any other shape of value
+fn plain_str<'de, D>(deserializer: D) -> Result<Option<XsdStr<'de>>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    struct PlainStr;
+
+    impl<'de> serde::de::Visitor<'de> for PlainStr {
+        type Value = Option<XsdStr<'de>>;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("any value")
+        }
+
+        fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E> {
+            Ok(Some(XsdStr::new(v)))
+        }
+
+        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> {
+            Ok(Some(XsdStr::new(v.to_owned())))
+        }
+
+        fn visit_string<E>(self, v: String) -> Result<Self::Value, E> {
+            Ok(Some(XsdStr::new(v)))
+        }
+
+        fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: serde::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(self)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            while seq.next_element::<serde::de::IgnoredAny>()?.is_some() {}
+            Ok(None)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            while map
+                .next_entry::<serde::de::IgnoredAny, serde::de::IgnoredAny>()?
+                .is_some()
+            {}
+            Ok(None)
+        }
+    }
+
+    deserializer.deserialize_any(PlainStr)
+}
+
 impl Base<serde_json::Value> {
     /// Convert this `Base<serde_json::Value>` into a `Base<Kind>`
     ///
@@ -1003,6 +1679,7 @@ impl<Kind> Base<Kind> {
             id: None,
             kind: Some(Kind::default()),
             name: None,
+            name_map: None,
             media_type: None,
             preview: None,
             unparsed: Default::default(),
@@ -1031,6 +1708,7 @@ impl<Kind> Base<Kind> {
             id: None,
             kind: None,
             name: None,
+            name_map: None,
             media_type: None,
             preview: None,
             unparsed: Default::default(),
@@ -1108,6 +1786,7 @@ impl<Kind> Base<Kind> {
             context: self.context,
             id: self.id,
             name: self.name,
+            name_map: self.name_map,
             media_type: self.media_type,
             preview: self.preview,
             unparsed: self.unparsed,
@@ -1143,11 +1822,16 @@ impl<Kind> Base<Kind> {
             context: self.context,
             id: self.id,
             name: self.name,
+            name_map: self.name_map,
             media_type: self.media_type,
             preview: self.preview,
             unparsed: self.unparsed,
         })
     }
+
+    pub(crate) fn unparsed(&self) -> &Unparsed {
+        &self.unparsed
+    }
 }
 
 impl AnyBase {
@@ -1197,6 +1881,55 @@ impl AnyBase {
         T::from_any_base(self)
     }
 
+    /// Extend this AnyBase into a kind-specific type, only if its `type` matches that type's kind
+    ///
+    /// Unlike `extend`, this checks the kind string before attempting to deserialize, so an AnyBase
+    /// holding a different kind of object produces `None` rather than an error, and the AnyBase is
+    /// left intact to be tried against other types.
+    ///
+    /// This only works for types whose Kind is one of the statically-typed kinds, e.g. `VideoType`.
+    /// Any spelling the Kind accepts counts as a match, including aliases declared with `kind!`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::{Image, Video}, base::AnyBase};
+    /// # let video = Video::new();
+    /// let any_base = AnyBase::from_extended(video)?;
+    ///
+    /// let image: Option<Image> = any_base.downcast_kind()?;
+    /// let video: Option<Video> = any_base.downcast_kind()?;
+    ///
+    /// assert!(image.is_none());
+    /// assert!(video.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::AnyBase, kind, object::Object, prelude::*};
+    ///
+    /// kind!(PostType, Post, aliases = ["post"]);
+    ///
+    /// let any_base: AnyBase = serde_json::from_str(r#"{"type":"post"}"#)?;
+    ///
+    /// let post: Option<Object<PostType>> = any_base.downcast_kind()?;
+    /// assert_eq!(post.and_then(|post| post.kind().cloned()), Some(PostType::Post));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn downcast_kind<T, Kind>(&self) -> Result<Option<T>, T::Error>
+    where
+        T: ExtendsExt<Kind = Kind>,
+        <T as Extends>::Error: From<serde_json::Error>,
+        for<'de> Kind: serde::Deserialize<'de> + std::str::FromStr,
+    {
+        match self.kind_str() {
+            Some(kind) if kind.parse::<Kind>().is_ok() => T::from_any_base(self.clone()),
+            _ => Ok(None),
+        }
+    }
+
     /// Convert any type that is extended from `Base<Kind>` into an AnyBase for storing
     ///
     /// ```rust
@@ -1296,7 +2029,7 @@ impl AnyBase {
     /// ```
     pub fn id(&self) -> Option<&IriString> {
         self.as_xsd_any_uri()
-            .or_else(|| self.as_base().and_then(|base| base.id.as_ref()))
+            .or_else(|| self.as_base().and_then(|base| base.id.as_deref()))
     }
 
     /// Check if the current object's id matches the provided id
@@ -1442,6 +2175,33 @@ impl AnyBase {
         self.0.as_ref().left().and_then(|l| l.as_base())
     }
 
+    /// Mutably borrow the object as a `Base<serde_json::Value>`
+    ///
+    /// Clones of an AnyBase share the same object, so if it's shared this copies it first, and
+    /// the other clones are left unchanged.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{base::AnyBase, iri, object::Video, prelude::*};
+    /// # let video = Video::new();
+    /// #
+    /// let original = AnyBase::from_extended(video)?;
+    /// let mut copy = original.clone();
+    ///
+    /// if let Some(base) = copy.as_base_mut() {
+    ///     base.set_id(iri!("https://example.com/videos/1"));
+    /// }
+    ///
+    /// assert!(original.id().is_none());
+    /// assert!(copy.id().is_some());
+    /// #
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_base_mut(&mut self) -> Option<&mut Base<serde_json::Value>> {
+        self.0.as_mut().left().and_then(|l| l.as_base_mut())
+    }
+
     /// Take the IriString from the Object
     ///
     /// ```rust
@@ -1506,7 +2266,7 @@ impl AnyBase {
     /// # }
     /// ```
     pub fn set_xsd_any_uri(&mut self, id: IriString) {
-        self.0 = Either::Left(IdOrBase::from_xsd_any_uri(id));
+        self.0 = Either::Left(IdOrBase::from_xsd_any_uri(Arc::new(id)));
     }
 
     /// Replace the object with the provided String
@@ -1561,6 +2321,29 @@ impl AnyBase {
     /// # }
     /// ```
     pub fn from_xsd_any_uri(id: IriString) -> Self {
+        Self::from_shared_xsd_any_uri(Arc::new(id))
+    }
+
+    /// Create an AnyBase from a shared IriString
+    ///
+    /// Every AnyBase created from the same `Arc` points at the same IRI, so addressing thousands
+    /// of activities to the same actor or collection doesn't copy its id each time.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::AnyBase, iri};
+    /// use std::sync::Arc;
+    ///
+    /// let followers = Arc::new(iri!("https://example.com/users/asonix/followers"));
+    ///
+    /// let first = AnyBase::from_shared_xsd_any_uri(Arc::clone(&followers));
+    /// let second = AnyBase::from(Arc::clone(&followers));
+    ///
+    /// assert!(std::ptr::eq(first.id().unwrap(), second.id().unwrap()));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_shared_xsd_any_uri(id: Arc<IriString>) -> Self {
         AnyBase(Either::Left(IdOrBase::from_xsd_any_uri(id)))
     }
 
@@ -1594,7 +2377,7 @@ impl AnyBase {
 
 impl IdOrBase {
     fn as_xsd_any_uri(&self) -> Option<&IriString> {
-        self.0.as_ref().left()
+        self.0.as_ref().left().map(|id| id.as_ref())
     }
 
     fn as_base(&self) -> Option<&Base<serde_json::Value>> {
@@ -1602,19 +2385,23 @@ impl IdOrBase {
     }
 
     fn id(self) -> Option<IriString> {
-        self.0.left()
+        self.0.left().map(Arc::unwrap_or_clone)
+    }
+
+    fn as_base_mut(&mut self) -> Option<&mut Base<serde_json::Value>> {
+        self.0.as_mut().right().map(Arc::make_mut)
     }
 
     fn base(self) -> Option<Base<serde_json::Value>> {
-        self.0.right().map(|b| *b)
+        self.0.right().map(Arc::unwrap_or_clone)
     }
 
-    fn from_xsd_any_uri(id: IriString) -> Self {
+    fn from_xsd_any_uri(id: Arc<IriString>) -> Self {
         IdOrBase(Either::Left(id))
     }
 
     fn from_base(base: Base<serde_json::Value>) -> Self {
-        IdOrBase(Either::Right(Box::new(base)))
+        IdOrBase(Either::Right(Arc::new(base)))
     }
 }
 
@@ -1985,6 +2772,12 @@ impl From<IriString> for AnyBase {
     }
 }
 
+impl From<Arc<IriString>> for AnyBase {
+    fn from(id: Arc<IriString>) -> Self {
+        Self::from_shared_xsd_any_uri(id)
+    }
+}
+
 impl From<String> for AnyBase {
     fn from(xsd_string: String) -> Self {
         Self::from_xsd_string(xsd_string)
@@ -2023,3 +2816,19 @@ where
         Self::new()
     }
 }
+
+impl Validate for AnyBase {
+    fn validate_into(&self, violations: &mut Violations) {
+        if let Some(base) = self.as_base() {
+            validate_embedded(base, violations);
+        }
+    }
+}
+
+impl<Kind> Validate for Base<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        violations
+            .field("nameMap", &self.name_map)
+            .field("preview", &self.preview);
+    }
+}
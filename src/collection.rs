@@ -1,5 +1,9 @@
 //! Types and traits for dealing with Collection attributes
 //!
+//! Every Collection also has a `Full*` alias, such as `FullOrderedCollection`, naming the
+//! `ApObject` that `ObjectExt::full` wraps it in to add the ActivityPub Object properties like
+//! `shares` and `likes`.
+//!
 //! ```rust
 //! # fn main() -> Result<(), anyhow::Error> {
 //! use activitystreams::{
@@ -23,13 +27,20 @@
 //! # }
 //! ```
 use crate::{
-    base::{AnyBase, AsBase, Base, Extends},
+    base::{AnyBase, AsBase, Base, BaseExt, Extends, ExtendsExt},
     markers,
     object::{ApObject, AsObject, Object},
-    primitives::OneOrMany,
+    primitives::{Iter, OneOrMany, XsdAnyUriExt, XsdDateTime, XsdNonNegativeInteger},
+    registry::{KindRegistry, MaterializeError, Materialized},
     unparsed::{Unparsed, UnparsedMut, UnparsedMutExt},
+    validate::{Validate, Violations},
+};
+use iri_string::{
+    percent_encode::decode::decode_whatwg_bytes,
+    types::{IriStr, IriString},
 };
 use std::convert::TryFrom;
+use time::OffsetDateTime;
 
 pub use activitystreams_kinds::collection as kind;
 
@@ -89,6 +100,27 @@ pub trait CollectionExt: AsCollection {
         self.collection_ref().items.as_ref()
     }
 
+    /// Iterate over the items for the current activity
+    ///
+    /// This yields nothing if items isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::{collection::UnorderedCollection};
+    /// # let mut collection = UnorderedCollection::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for item in collection.iter_items() {
+    ///     println!("{:?}", item);
+    /// }
+    /// ```
+    fn iter_items<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.items().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the items for the current activity
     ///
     /// This overwrites the contents of items
@@ -111,6 +143,29 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set the items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_item`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{collection::UnorderedCollection, iri};
+    /// # let collection = UnorderedCollection::new();
+    ///
+    /// let collection = collection.with_item(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_item<T>(mut self, item: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_item(item);
+        self
+    }
+
     /// Set many items for the current activity
     ///
     /// This overwrites the contents of items
@@ -138,6 +193,33 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set many items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_items`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{collection::UnorderedCollection, iri};
+    /// # let collection = UnorderedCollection::new();
+    ///
+    /// let collection = collection.with_many_items(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_items<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_items(items);
+        self
+    }
+
     /// Add an item to the current activity
     ///
     /// This does not overwrite the contents of items, only appends an item
@@ -218,6 +300,29 @@ pub trait CollectionExt: AsCollection {
         self.collection_ref().ordered_items.as_ref()
     }
 
+    /// Iterate over the ordered_items for the current activity
+    ///
+    /// This yields nothing if ordered_items isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::{collection::OrderedCollection};
+    /// # let mut collection = OrderedCollection::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for item in collection.iter_ordered_items() {
+    ///     println!("{:?}", item);
+    /// }
+    /// ```
+    fn iter_ordered_items<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.ordered_items()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
     /// Set the ordered_items for the current activity
     ///
     /// This overwrites the contents of ordered_items
@@ -240,6 +345,29 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set the ordered_items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_ordered_item`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{collection::OrderedCollection, iri};
+    /// # let collection = OrderedCollection::new();
+    ///
+    /// let collection = collection.with_ordered_item(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_ordered_item<T>(mut self, item: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_ordered_item(item);
+        self
+    }
+
     /// Set many ordered_items for the current activity
     ///
     /// This overwrites the contents of ordered_items
@@ -267,6 +395,33 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set many ordered_items for the current activity, returning it
+    ///
+    /// This is the consuming version of `set_many_ordered_items`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{collection::OrderedCollection, iri};
+    /// # let collection = OrderedCollection::new();
+    ///
+    /// let collection = collection.with_many_ordered_items(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_ordered_items<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_ordered_items(items);
+        self
+    }
+
     /// Add an ordered_item to the current activity
     ///
     /// This does not overwrite the contents of ordered_items, only appends an item
@@ -329,6 +484,77 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Iterate over the items and ordered_items published between `start` and `end`
+    ///
+    /// The range includes `start` and excludes `end`. Each item's `published` timestamp is only
+    /// parsed as the iterator reaches it, and items that are only referenced by id or have no
+    /// valid `published` are skipped.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::OrderedCollection, object::Note, prelude::*};
+    /// use time::OffsetDateTime;
+    ///
+    /// let january = OffsetDateTime::from_unix_timestamp(1577836800)?;
+    /// let february = OffsetDateTime::from_unix_timestamp(1580515200)?;
+    /// let march = OffsetDateTime::from_unix_timestamp(1583020800)?;
+    ///
+    /// let mut collection = OrderedCollection::new();
+    /// for published in [january, february, march] {
+    ///     let mut note = Note::new();
+    ///     note.set_published(published);
+    ///     collection.add_ordered_item(note.into_any_base()?);
+    /// }
+    ///
+    /// assert_eq!(collection.items_between(february, march).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn items_between<'a>(
+        &'a self,
+        start: OffsetDateTime,
+        end: OffsetDateTime,
+    ) -> impl Iterator<Item = &'a AnyBase> + 'a
+    where
+        Self::Kind: 'a,
+    {
+        all_items(self).filter(move |item| {
+            published(item).is_some_and(|published| start <= published && published < end)
+        })
+    }
+
+    /// Iterate over the items and ordered_items published at or after `since`
+    ///
+    /// Like `items_between`, items without a valid `published` timestamp are skipped.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::OrderedCollection, object::Note, prelude::*};
+    /// use time::OffsetDateTime;
+    ///
+    /// let january = OffsetDateTime::from_unix_timestamp(1577836800)?;
+    /// let february = OffsetDateTime::from_unix_timestamp(1580515200)?;
+    /// let march = OffsetDateTime::from_unix_timestamp(1583020800)?;
+    ///
+    /// let mut collection = OrderedCollection::new();
+    /// for published in [january, march] {
+    ///     let mut note = Note::new();
+    ///     note.set_published(published);
+    ///     collection.add_ordered_item(note.into_any_base()?);
+    /// }
+    ///
+    /// assert_eq!(collection.items_since(february).count(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn items_since<'a>(&'a self, since: OffsetDateTime) -> impl Iterator<Item = &'a AnyBase> + 'a
+    where
+        Self::Kind: 'a,
+    {
+        all_items(self)
+            .filter(move |item| published(item).is_some_and(|published| since <= published))
+    }
+
     /// Fetch the total_items of the current object
     ///
     /// ```rust
@@ -344,7 +570,9 @@ pub trait CollectionExt: AsCollection {
     where
         Self::Kind: 'a,
     {
-        self.collection_ref().total_items
+        self.collection_ref()
+            .total_items
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Set the total_items for the current object
@@ -362,7 +590,27 @@ pub trait CollectionExt: AsCollection {
     where
         T: Into<u64>,
     {
-        self.collection_mut().total_items = Some(total_items.into());
+        self.collection_mut().total_items = Some(XsdNonNegativeInteger::new(total_items.into()));
+        self
+    }
+
+    /// Set the total_items for the current object, returning it
+    ///
+    /// This is the consuming version of `set_total_items`
+    ///
+    /// ```rust
+    /// # use activitystreams::{collection::UnorderedCollection};
+    /// # let collection = UnorderedCollection::new();
+    /// use activitystreams::prelude::*;
+    ///
+    /// let collection = collection.with_total_items(5u64);
+    /// ```
+    fn with_total_items<T>(mut self, total_items: T) -> Self
+    where
+        T: Into<u64>,
+        Self: Sized,
+    {
+        self.set_total_items(total_items);
         self
     }
 
@@ -378,7 +626,10 @@ pub trait CollectionExt: AsCollection {
     /// }
     /// ```
     fn take_total_items(&mut self) -> Option<u64> {
-        self.collection_mut().total_items.take()
+        self.collection_mut()
+            .total_items
+            .take()
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Delete the total_items from the current object
@@ -440,6 +691,30 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set the current field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_current`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollection, iri};
+    /// # let collection = UnorderedCollection::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let collection = collection.with_current(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_current<T>(mut self, current: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_current(current);
+        self
+    }
+
     /// Take the current field from the current object, leaving nothing
     ///
     /// ```rust
@@ -516,6 +791,30 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set the first field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_first`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollection};
+    /// # let collection = UnorderedCollection::new();
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let collection = collection.with_first(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_first<T>(mut self, first: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_first(first);
+        self
+    }
+
     /// Take the first field from the current object, leaving nothing
     ///
     /// ```rust
@@ -592,6 +891,30 @@ pub trait CollectionExt: AsCollection {
         self
     }
 
+    /// Set the last field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_last`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollection};
+    /// # let collection = UnorderedCollection::new();
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let collection = collection.with_last(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_last<T>(mut self, last: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_last(last);
+        self
+    }
+
     /// Take the last field from the current object, leaving nothing
     ///
     /// ```rust
@@ -676,6 +999,30 @@ pub trait CollectionPageExt: AsCollectionPage {
         self
     }
 
+    /// Set the part_of field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_part_of`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollectionPage};
+    /// # let collection = UnorderedCollectionPage::new();
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let collection = collection.with_part_of(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_part_of<T>(mut self, part_of: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_part_of(part_of);
+        self
+    }
+
     /// Take the part_of field from the current object, leaving nothing
     ///
     /// ```rust
@@ -752,6 +1099,30 @@ pub trait CollectionPageExt: AsCollectionPage {
         self
     }
 
+    /// Set the next field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_next`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollectionPage};
+    /// # let collection = UnorderedCollectionPage::new();
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let collection = collection.with_next(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_next<T>(mut self, next: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_next(next);
+        self
+    }
+
     /// Take the next field from the current object, leaving nothing
     ///
     /// ```rust
@@ -828,6 +1199,30 @@ pub trait CollectionPageExt: AsCollectionPage {
         self
     }
 
+    /// Set the prev field for the current object, returning it
+    ///
+    /// This is the consuming version of `set_prev`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{collection::UnorderedCollectionPage};
+    /// # let collection = UnorderedCollectionPage::new();
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let collection = collection.with_prev(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_prev<T>(mut self, prev: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_prev(prev);
+        self
+    }
+
     /// Take the prev field from the current object, leaving nothing
     ///
     /// ```rust
@@ -876,7 +1271,9 @@ pub trait OrderedCollectionPageExt: AsOrderedCollectionPage {
     /// }
     /// ```
     fn start_index(&self) -> Option<u64> {
-        self.ordered_collection_page_ref().start_index
+        self.ordered_collection_page_ref()
+            .start_index
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Set the start_index for the current object
@@ -894,7 +1291,28 @@ pub trait OrderedCollectionPageExt: AsOrderedCollectionPage {
     where
         T: Into<u64>,
     {
-        self.ordered_collection_page_mut().start_index = Some(start_index.into());
+        self.ordered_collection_page_mut().start_index =
+            Some(XsdNonNegativeInteger::new(start_index.into()));
+        self
+    }
+
+    /// Set the start_index for the current object, returning it
+    ///
+    /// This is the consuming version of `set_start_index`
+    ///
+    /// ```rust
+    /// # use activitystreams::{collection::OrderedCollectionPage};
+    /// # let collection = OrderedCollectionPage::new();
+    /// use activitystreams::prelude::*;
+    ///
+    /// let collection = collection.with_start_index(5u64);
+    /// ```
+    fn with_start_index<T>(mut self, start_index: T) -> Self
+    where
+        T: Into<u64>,
+        Self: Sized,
+    {
+        self.set_start_index(start_index);
         self
     }
 
@@ -910,7 +1328,10 @@ pub trait OrderedCollectionPageExt: AsOrderedCollectionPage {
     /// }
     /// ```
     fn take_start_index(&mut self) -> Option<u64> {
-        self.ordered_collection_page_mut().start_index.take()
+        self.ordered_collection_page_mut()
+            .start_index
+            .take()
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Delete the start_index from the current object
@@ -950,6 +1371,60 @@ pub type UnorderedCollection = Collection<CollectionType>;
 /// inherent to UnorderedCollection that aren't already present on a CollectionPage.
 pub type UnorderedCollectionPage = CollectionPage<CollectionPageType>;
 
+/// An OrderedCollection with the ActivityPub Object properties.
+pub type FullOrderedCollection = ApObject<OrderedCollection>;
+
+/// An UnorderedCollection with the ActivityPub Object properties.
+pub type FullUnorderedCollection = ApObject<UnorderedCollection>;
+
+/// An UnorderedCollectionPage with the ActivityPub Object properties.
+pub type FullUnorderedCollectionPage = ApObject<UnorderedCollectionPage>;
+
+/// An OrderedCollectionPage with the ActivityPub Object properties.
+pub type FullOrderedCollectionPage = ApObject<OrderedCollectionPage>;
+
+/// A Collection or CollectionPage of any kind defined by the spec
+///
+/// Fetching an actor's `outbox` or `followers` can return any one of the four collection types,
+/// so when deserializing, the `type` field is used to pick the variant. Collections with a `type`
+/// that isn't defined by the spec fail to deserialize.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::collection::AnyCollection;
+///
+/// let json = r#"{
+///     "type": "OrderedCollectionPage",
+///     "id": "https://example.com/users/asonix/outbox?page=1",
+///     "orderedItems": [
+///         "https://example.com/activities/1",
+///         "https://example.com/activities/2"
+///     ]
+/// }"#;
+///
+/// let collection: AnyCollection = serde_json::from_str(json)?;
+///
+/// assert!(collection.is_ordered());
+/// assert_eq!(collection.items_iter().count(), 2);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum AnyCollection {
+    /// An unordered Collection
+    Collection(UnorderedCollection),
+
+    /// An OrderedCollection
+    OrderedCollection(OrderedCollection),
+
+    /// An unordered CollectionPage
+    CollectionPage(UnorderedCollectionPage),
+
+    /// An OrderedCollectionPage
+    OrderedCollectionPage(OrderedCollectionPage),
+}
+
 /// A Collection is a subtype of Object that represents ordered or unordered sets of Object or Link
 /// instances.
 ///
@@ -957,21 +1432,21 @@ pub type UnorderedCollectionPage = CollectionPage<CollectionPageType>;
 /// used to identify a Collection whose items are always ordered. In the JSON serialization, the
 /// unordered items of a Collection are represented using the items property while ordered items
 /// are represented using the orderedItems property.
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Collection<Kind> {
     /// Identifies the items contained in a collection. The items might be ordered or unordered.
     ///
     /// - Range: Object | Link | Ordered List of [ Object | Link ]
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     items: Option<OneOrMany<AnyBase>>,
 
     /// Identifies ordered items contained in a collection.
     ///
     /// - Range: Object | Link | Ordered List of [ Object | Link ]
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     ordered_items: Option<OneOrMany<AnyBase>>,
 
     /// A non-negative integer specifying the total number of objects contained by the logical view
@@ -983,7 +1458,7 @@ pub struct Collection<Kind> {
     /// - Range: xsd:nonNegativeInteger
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    total_items: Option<u64>,
+    total_items: Option<XsdNonNegativeInteger>,
 
     /// In a paged Collection, indicates the page that contains the most recently updated member
     /// items.
@@ -1018,7 +1493,7 @@ pub struct Collection<Kind> {
 /// implementation to serialize every item contained by a Collection using the items (or
 /// ordered_items) property alone. In such cases, the items within a Collection can be divided into
 /// distinct subsets or "pages". A page is identified using the CollectionPage type.
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CollectionPage<Kind> {
     /// Identifies the Collection to which a CollectionPage objects items belong.
@@ -1048,7 +1523,7 @@ pub struct CollectionPage<Kind> {
 }
 
 /// Used to represent ordered subsets of items from an OrderedCollection.
-#[derive(Debug, Clone, serde::Deserialize, serde::Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrderedCollectionPage {
     /// A non-negative integer value identifying the relative position within the logical view of a strictly ordered collection.
@@ -1056,13 +1531,62 @@ pub struct OrderedCollectionPage {
     /// - Range: xsd:nonNegativeInteger
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    start_index: Option<u64>,
+    start_index: Option<XsdNonNegativeInteger>,
 
     /// Base fields and unparsed json ends up here
     #[serde(flatten)]
     inner: CollectionPage<OrderedCollectionPageType>,
 }
 
+/// A position in a paginated collection, as found in the query of a page's IRI
+///
+/// Servers either number their pages, or use opaque cursors taken from the ids of the items on
+/// either side of a page.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Cursor {
+    /// Items older than the given cursor, `?max_id=`
+    MaxId(String),
+
+    /// Items newer than the given cursor, `?min_id=`
+    MinId(String),
+
+    /// A numbered page, `?page=`
+    Page(u64),
+}
+
+/// A helper for building and reading the `next` and `prev` links of a paginated collection
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     collection::{Cursor, CursorPage, OrderedCollectionPage},
+///     iri,
+///     prelude::*,
+/// };
+///
+/// let pages = CursorPage::new(iri!("https://example.com/users/asonix/outbox"));
+///
+/// let mut page = OrderedCollectionPage::new();
+/// pages.set_links(
+///     &mut page,
+///     Some(&Cursor::MaxId("109".into())),
+///     Some(&Cursor::MinId("120".into())),
+/// );
+///
+/// assert_eq!(
+///     page.next().and_then(|next| next.id()).map(|id| id.as_str()),
+///     Some("https://example.com/users/asonix/outbox?max_id=109")
+/// );
+/// assert_eq!(CursorPage::next_cursor(&page), Some(Cursor::MaxId("109".into())));
+/// assert_eq!(CursorPage::prev_cursor(&page), Some(Cursor::MinId("120".into())));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CursorPage {
+    collection: IriString,
+}
+
 impl<Kind> Collection<Kind> {
     /// Create a new Collection
     ///
@@ -1248,6 +1772,46 @@ impl OrderedCollectionPage {
         }
     }
 
+    /// Create a new OrderedCollectionPage holding `items`
+    ///
+    /// This is meant for pages produced on demand, such as from a database query with an offset.
+    /// The page's orderedItems are `items` in order, its startIndex is `start_index`, the index of
+    /// the first item within the whole collection, and its partOf is `part_of`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::OrderedCollectionPage, iri, prelude::*};
+    /// use iri_string::types::IriString;
+    ///
+    /// let rows: Vec<IriString> = vec![
+    ///     iri!("https://example.com/notes/21"),
+    ///     iri!("https://example.com/notes/22"),
+    /// ];
+    ///
+    /// let page = OrderedCollectionPage::from_slice(&rows, 20, iri!("https://example.com/outbox"));
+    ///
+    /// assert_eq!(page.start_index(), Some(20));
+    /// assert_eq!(page.ordered_items().map(|items| items.iter().count()), Some(2));
+    /// assert_eq!(
+    ///     page.part_of().and_then(|part_of| part_of.id()).map(|id| id.as_str()),
+    ///     Some("https://example.com/outbox")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_slice<T>(items: &[T], start_index: u64, part_of: IriString) -> Self
+    where
+        T: Clone + Into<AnyBase>,
+    {
+        let mut page = Self::new();
+
+        page.set_many_ordered_items(items.iter().cloned())
+            .set_start_index(start_index)
+            .set_part_of(part_of);
+
+        page
+    }
+
     fn extending(object: Object<OrderedCollectionPageType>) -> Result<Self, serde_json::Error> {
         let mut inner = CollectionPage::extending(object)?;
 
@@ -1268,6 +1832,398 @@ impl OrderedCollectionPage {
     }
 }
 
+impl Cursor {
+    /// Read the cursor from a page's IRI
+    ///
+    /// `max_id` and `min_id` take precedence over `page`, and a `page` that isn't a number, like
+    /// the `?page=true` Mastodon uses for the first page of a collection, is ignored. Parameters
+    /// that aren't valid UTF-8 once percent-decoded are skipped.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::Cursor, iri};
+    ///
+    /// let id = iri!("https://example.com/outbox?max_id=a%20b&page=true");
+    /// assert_eq!(Cursor::from_iri(&id), Some(Cursor::MaxId("a b".into())));
+    ///
+    /// let id = iri!("https://example.com/outbox?page=3");
+    /// assert_eq!(Cursor::from_iri(&id), Some(Cursor::Page(3)));
+    ///
+    /// let id = iri!("https://example.com/outbox?page=true");
+    /// assert_eq!(Cursor::from_iri(&id), None);
+    ///
+    /// let id = iri!("https://example.com/outbox?utm=%FF&min_id=%FF&page=2");
+    /// assert_eq!(Cursor::from_iri(&id), Some(Cursor::Page(2)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_iri(iri: &IriStr) -> Option<Self> {
+        let mut page = None;
+
+        for pair in iri.query_str()?.split('&') {
+            let (key, value) = pair.split_once('=').unwrap_or((pair, ""));
+            let value = match decode_whatwg_bytes(value.as_bytes()).into_string() {
+                Ok(value) => value,
+                Err(_) => continue,
+            };
+
+            match key {
+                "max_id" => return Some(Cursor::MaxId(value)),
+                "min_id" => return Some(Cursor::MinId(value)),
+                "page" => page = page.or_else(|| value.parse().ok().map(Cursor::Page)),
+                _ => {}
+            }
+        }
+
+        page
+    }
+
+    fn key(&self) -> &'static str {
+        match self {
+            Cursor::MaxId(_) => "max_id",
+            Cursor::MinId(_) => "min_id",
+            Cursor::Page(_) => "page",
+        }
+    }
+
+    fn value(&self) -> String {
+        match self {
+            Cursor::MaxId(s) | Cursor::MinId(s) => s.clone(),
+            Cursor::Page(page) => page.to_string(),
+        }
+    }
+}
+
+impl CursorPage {
+    /// Create a helper for the pages of the given collection
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::CursorPage, iri};
+    ///
+    /// let pages = CursorPage::new(iri!("https://example.com/users/asonix/outbox"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new(collection: IriString) -> Self {
+        CursorPage { collection }
+    }
+
+    /// Fetch the IRI of the collection
+    pub fn collection(&self) -> &IriString {
+        &self.collection
+    }
+
+    /// Produce the IRI of the page at `cursor`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     collection::{Cursor, CursorPage},
+    ///     iri,
+    /// };
+    ///
+    /// let pages = CursorPage::new(iri!("https://example.com/users/asonix/outbox"));
+    ///
+    /// assert_eq!(
+    ///     pages.page_iri(&Cursor::Page(2)).as_str(),
+    ///     "https://example.com/users/asonix/outbox?page=2"
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn page_iri(&self, cursor: &Cursor) -> IriString {
+        self.collection
+            .with_query_param(cursor.key(), &cursor.value())
+    }
+
+    /// Set the `partOf`, `next`, and `prev` links of a page
+    ///
+    /// Links for cursors that are `None` are removed.
+    pub fn set_links<T>(&self, page: &mut T, next: Option<&Cursor>, prev: Option<&Cursor>)
+    where
+        T: CollectionPageExt,
+    {
+        page.set_part_of(self.collection.clone());
+
+        match next {
+            Some(next) => page.set_next(self.page_iri(next)),
+            None => page.delete_next(),
+        };
+
+        match prev {
+            Some(prev) => page.set_prev(self.page_iri(prev)),
+            None => page.delete_prev(),
+        };
+    }
+
+    /// Read the cursor from a fetched page's `next` link
+    pub fn next_cursor<T>(page: &T) -> Option<Cursor>
+    where
+        T: CollectionPageExt,
+    {
+        page.next()
+            .and_then(AnyBase::id)
+            .and_then(|id| Cursor::from_iri(id))
+    }
+
+    /// Read the cursor from a fetched page's `prev` link
+    pub fn prev_cursor<T>(page: &T) -> Option<Cursor>
+    where
+        T: CollectionPageExt,
+    {
+        page.prev()
+            .and_then(AnyBase::id)
+            .and_then(|id| Cursor::from_iri(id))
+    }
+}
+
+macro_rules! any_collection {
+    ($any_collection:expr, $collection:ident => $expr:expr) => {
+        match $any_collection {
+            AnyCollection::Collection($collection) => $expr,
+            AnyCollection::OrderedCollection($collection) => $expr,
+            AnyCollection::CollectionPage($collection) => $expr,
+            AnyCollection::OrderedCollectionPage($collection) => $expr,
+        }
+    };
+}
+
+impl AnyCollection {
+    /// Create an AnyCollection from a generic Base, picking the variant based on its `type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::Base, collection::AnyCollection};
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"type":"OrderedCollection","totalItems":12}"#,
+    /// )?;
+    ///
+    /// let collection = AnyCollection::from_base(base)?;
+    ///
+    /// assert_eq!(collection.kind_str(), "OrderedCollection");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base(base: Base<serde_json::Value>) -> Result<Self, serde_json::Error> {
+        let kind = match base.kind() {
+            Some(serde_json::Value::String(kind)) => kind.to_owned(),
+            Some(kind) => {
+                return Err(serde::de::Error::custom(format!(
+                    "Invalid collection type {}",
+                    kind
+                )))
+            }
+            None => return Err(serde::de::Error::missing_field("type")),
+        };
+
+        let any_collection = match kind.as_str() {
+            "Collection" => AnyCollection::Collection(base.solidify()?.extend()?),
+            "OrderedCollection" => AnyCollection::OrderedCollection(base.solidify()?.extend()?),
+            "CollectionPage" => AnyCollection::CollectionPage(base.solidify()?.extend()?),
+            "OrderedCollectionPage" => {
+                AnyCollection::OrderedCollectionPage(base.solidify()?.extend()?)
+            }
+            _ => {
+                return Err(serde::de::Error::custom(format!(
+                    "Unknown collection type {}",
+                    kind
+                )))
+            }
+        };
+
+        Ok(any_collection)
+    }
+
+    /// Create an AnyCollection from an AnyBase
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     collection::{AnyCollection, UnorderedCollectionPage},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let any_base = UnorderedCollectionPage::new().into_any_base()?;
+    ///
+    /// let collection = AnyCollection::from_any_base(any_base)?;
+    ///
+    /// assert!(matches!(collection, Some(AnyCollection::CollectionPage(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_any_base(any_base: AnyBase) -> Result<Option<Self>, serde_json::Error> {
+        any_base.take_base().map(Self::from_base).transpose()
+    }
+
+    /// Create an AnyCollection from a generic Base, or the type registered for its `type`
+    ///
+    /// Types registered with the `KindRegistry` take precedence over the variants of this enum.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{base::Base, collection::AnyCollection, registry::KindRegistry};
+    ///
+    /// let registry = KindRegistry::new();
+    /// let base: Base<serde_json::Value> = serde_json::from_str(r#"{"type":"Collection"}"#)?;
+    ///
+    /// let collection = AnyCollection::from_base_with(base, &registry)?.unregistered();
+    /// assert!(collection.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base_with(
+        base: Base<serde_json::Value>,
+        registry: &KindRegistry,
+    ) -> Result<Materialized<Self>, MaterializeError> {
+        registry.materialize_base(base, Self::from_base)
+    }
+
+    /// Create an AnyCollection from an AnyBase, or the type registered for its `type`
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    pub fn from_any_base_with(
+        any_base: AnyBase,
+        registry: &KindRegistry,
+    ) -> Result<Option<Materialized<Self>>, MaterializeError> {
+        any_base
+            .take_base()
+            .map(|base| Self::from_base_with(base, registry))
+            .transpose()
+    }
+
+    /// Convert this AnyCollection into an AnyBase
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::collection::{AnyCollection, OrderedCollection};
+    ///
+    /// let collection = AnyCollection::from(OrderedCollection::new());
+    ///
+    /// let any_base = collection.into_any_base()?;
+    ///
+    /// assert_eq!(any_base.kind_str(), Some("OrderedCollection"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_any_base(self) -> Result<AnyBase, serde_json::Error> {
+        any_collection!(self, collection => collection.into_any_base())
+    }
+
+    /// Fetch the `type` of the current collection as a string
+    ///
+    /// ```rust
+    /// # use activitystreams::collection::{AnyCollection, UnorderedCollection};
+    /// let collection = AnyCollection::from(UnorderedCollection::new());
+    ///
+    /// assert_eq!(collection.kind_str(), "Collection");
+    /// ```
+    pub fn kind_str(&self) -> &'static str {
+        match self {
+            AnyCollection::Collection(_) => "Collection",
+            AnyCollection::OrderedCollection(_) => "OrderedCollection",
+            AnyCollection::CollectionPage(_) => "CollectionPage",
+            AnyCollection::OrderedCollectionPage(_) => "OrderedCollectionPage",
+        }
+    }
+
+    /// Check whether the current collection is an OrderedCollection or OrderedCollectionPage
+    ///
+    /// ```rust
+    /// # use activitystreams::collection::{AnyCollection, OrderedCollectionPage, UnorderedCollection};
+    /// assert!(AnyCollection::from(OrderedCollectionPage::new()).is_ordered());
+    /// assert!(!AnyCollection::from(UnorderedCollection::new()).is_ordered());
+    /// ```
+    pub fn is_ordered(&self) -> bool {
+        matches!(
+            self,
+            AnyCollection::OrderedCollection(_) | AnyCollection::OrderedCollectionPage(_)
+        )
+    }
+
+    /// Check whether the current collection is a CollectionPage or OrderedCollectionPage
+    ///
+    /// ```rust
+    /// # use activitystreams::collection::{AnyCollection, OrderedCollection, UnorderedCollectionPage};
+    /// assert!(AnyCollection::from(UnorderedCollectionPage::new()).is_page());
+    /// assert!(!AnyCollection::from(OrderedCollection::new()).is_page());
+    /// ```
+    pub fn is_page(&self) -> bool {
+        matches!(
+            self,
+            AnyCollection::CollectionPage(_) | AnyCollection::OrderedCollectionPage(_)
+        )
+    }
+
+    /// Iterate over the items in the current collection
+    ///
+    /// This yields both `items` and `orderedItems`, since implementations don't always pick the
+    /// property matching the collection's type.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     collection::{AnyCollection, OrderedCollection},
+    ///     iri,
+    ///     prelude::*,
+    /// };
+    ///
+    /// let mut outbox = OrderedCollection::new();
+    /// outbox.set_many_ordered_items(vec![
+    ///     iri!("https://example.com/activities/1"),
+    ///     iri!("https://example.com/activities/2"),
+    /// ]);
+    ///
+    /// let collection = AnyCollection::from(outbox);
+    ///
+    /// for item in collection.items_iter() {
+    ///     println!("{:?}", item.id());
+    /// }
+    ///
+    /// assert_eq!(collection.items_iter().count(), 2);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn items_iter(&self) -> impl Iterator<Item = &AnyBase> + '_ {
+        let (items, ordered_items) =
+            any_collection!(self, collection => (collection.items(), collection.ordered_items()));
+
+        items
+            .into_iter()
+            .flatten()
+            .chain(ordered_items.into_iter().flatten())
+    }
+
+    /// Fetch the id for the current collection
+    ///
+    /// ```rust
+    /// # use activitystreams::collection::{AnyCollection, OrderedCollection};
+    /// # let collection = AnyCollection::from(OrderedCollection::new());
+    /// if let Some(id) = collection.id_unchecked() {
+    ///     println!("{:?}", id);
+    /// }
+    /// ```
+    pub fn id_unchecked(&self) -> Option<&IriString> {
+        any_collection!(self, collection => collection.id_unchecked())
+    }
+
+    /// Fetch the total_items for the current collection
+    ///
+    /// ```rust
+    /// # use activitystreams::collection::{AnyCollection, OrderedCollection};
+    /// # let collection = AnyCollection::from(OrderedCollection::new());
+    /// if let Some(total_items) = collection.total_items() {
+    ///     println!("{:?}", total_items);
+    /// }
+    /// ```
+    pub fn total_items(&self) -> Option<u64> {
+        any_collection!(self, collection => collection.total_items())
+    }
+}
+
 impl<Kind> markers::Base for Collection<Kind> {}
 impl<Kind> markers::Object for Collection<Kind> {}
 impl<Kind> markers::Collection for Collection<Kind> {}
@@ -1588,6 +2544,61 @@ impl<T> CollectionExt for T where T: AsCollection {}
 impl<T> CollectionPageExt for T where T: AsCollectionPage {}
 impl<T> OrderedCollectionPageExt for T where T: AsOrderedCollectionPage {}
 
+impl<'de> serde::de::Deserialize<'de> for AnyCollection {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let base = Base::<serde_json::Value>::deserialize(deserializer)?;
+        AnyCollection::from_base(base).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<UnorderedCollection> for AnyCollection {
+    fn from(collection: UnorderedCollection) -> Self {
+        AnyCollection::Collection(collection)
+    }
+}
+
+impl From<OrderedCollection> for AnyCollection {
+    fn from(collection: OrderedCollection) -> Self {
+        AnyCollection::OrderedCollection(collection)
+    }
+}
+
+impl From<UnorderedCollectionPage> for AnyCollection {
+    fn from(collection: UnorderedCollectionPage) -> Self {
+        AnyCollection::CollectionPage(collection)
+    }
+}
+
+impl From<OrderedCollectionPage> for AnyCollection {
+    fn from(collection: OrderedCollectionPage) -> Self {
+        AnyCollection::OrderedCollectionPage(collection)
+    }
+}
+
+fn all_items<T>(collection: &T) -> impl Iterator<Item = &AnyBase>
+where
+    T: AsCollection + ?Sized,
+{
+    let collection = collection.collection_ref();
+
+    collection
+        .items
+        .iter()
+        .flatten()
+        .chain(collection.ordered_items.iter().flatten())
+}
+
+fn published(item: &AnyBase) -> Option<OffsetDateTime> {
+    let published = item.as_base()?.unparsed().get("published")?;
+
+    <XsdDateTime as serde::Deserialize>::deserialize(published)
+        .ok()
+        .map(XsdDateTime::into_inner)
+}
+
 impl<Kind> Default for Collection<Kind>
 where
     Kind: Default,
@@ -1611,3 +2622,57 @@ impl Default for OrderedCollectionPage {
         Self::new()
     }
 }
+
+impl<Kind> Validate for Collection<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        let count = [&self.items, &self.ordered_items]
+            .into_iter()
+            .flatten()
+            .map(|items| items.iter().count() as u64)
+            .max();
+
+        if let (Some(total_items), Some(count)) = (&self.total_items, count) {
+            if total_items.0 < count {
+                violations.report_field(
+                    "totalItems",
+                    format!(
+                        "totalItems is {} but {} items are present",
+                        total_items.0, count
+                    ),
+                );
+            }
+        }
+
+        violations
+            .field("items", &self.items)
+            .field("orderedItems", &self.ordered_items)
+            .field("current", &self.current)
+            .field("first", &self.first)
+            .field("last", &self.last);
+    }
+}
+
+impl<Kind> Validate for CollectionPage<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        violations
+            .field("partOf", &self.part_of)
+            .field("next", &self.next)
+            .field("prev", &self.prev);
+    }
+}
+
+impl Validate for OrderedCollectionPage {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations)
+    }
+}
+
+impl Validate for AnyCollection {
+    fn validate_into(&self, violations: &mut Violations) {
+        any_collection!(self, collection => collection.validate_into(violations))
+    }
+}
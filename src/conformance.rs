@@ -0,0 +1,281 @@
+//! Types and functions for checking that documents survive a trip through this crate
+//!
+//! `check_roundtrip` parses a document into the most specific type this crate has for its `type`,
+//! serializes it again, and reports every place the output differs from the input. Differences
+//! that don't change a document's meaning, like a one-element array written as a single value, or
+//! `15` written as `15.0`, are not reported.
+//!
+//! `DOCUMENTS` holds a set of examples from the ActivityStreams 2.0 Vocabulary specification that
+//! this crate is expected to round-trip cleanly.
+//!
+//! ```rust
+//! use activitystreams::conformance::{check_roundtrip, DOCUMENTS};
+//!
+//! for document in DOCUMENTS {
+//!     let report = check_roundtrip(document.json);
+//!
+//!     assert!(report.is_ok(), "{}: {}", document.name, report);
+//! }
+//! ```
+use crate::{
+    activity::AnyActivity,
+    actor::AnyActor,
+    base::Base,
+    collection::AnyCollection,
+    link::AnyLink,
+    object::AnyObject,
+    validate::{ACTIVITY_KINDS, ACTOR_KINDS, COLLECTION_KINDS, LINK_KINDS},
+};
+use serde_json::Value;
+use std::fmt;
+
+/// A test document shipped with this crate
+#[derive(Clone, Copy, Debug)]
+pub struct TestDocument {
+    /// A short name describing the document
+    pub name: &'static str,
+
+    /// The document's json
+    pub json: &'static str,
+}
+
+macro_rules! documents {
+    ($($name:literal),* $(,)?) => {
+        &[
+            $(
+                TestDocument {
+                    name: $name,
+                    json: include_str!(concat!("../conformance/vocabulary/", $name, ".json")),
+                },
+            )*
+        ]
+    };
+}
+
+/// Examples from the ActivityStreams 2.0 Vocabulary specification
+pub const DOCUMENTS: &[TestDocument] = documents![
+    "accept",
+    "activity",
+    "collection",
+    "collection-page",
+    "content-language",
+    "create",
+    "event",
+    "intransitive-activity",
+    "link",
+    "mention",
+    "name-map",
+    "object",
+    "ordered-collection",
+    "ordered-collection-page",
+    "person",
+    "place",
+    "question",
+    "tombstone",
+];
+
+/// The outcome of `check_roundtrip`
+#[derive(Debug)]
+pub struct Report {
+    /// The `type` of the document, if it had one
+    pub kind: Option<String>,
+
+    /// The error encountered while parsing or serializing the document, if any
+    pub error: Option<serde_json::Error>,
+
+    /// Every place the serialized output differs from the input
+    pub differences: Vec<Difference>,
+}
+
+/// A single place where a round-tripped document differs from the original
+#[derive(Clone, Debug, PartialEq)]
+pub struct Difference {
+    /// The JSON Pointer to the differing value
+    pub path: String,
+
+    /// The value in the original document, or `None` if it was dropped
+    pub expected: Option<Value>,
+
+    /// The value in the round-tripped document, or `None` if it was added
+    pub actual: Option<Value>,
+}
+
+impl Report {
+    /// Whether the document round-tripped without errors or differences
+    ///
+    /// ```rust
+    /// use activitystreams::conformance::check_roundtrip;
+    ///
+    /// assert!(check_roundtrip(r#"{"type":"Note","content":"hi"}"#).is_ok());
+    /// assert!(!check_roundtrip(r#"{"type":"Note","published":"yesterday"}"#).is_ok());
+    /// ```
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none() && self.differences.is_empty()
+    }
+}
+
+/// Parse a document, serialize it again, and report how the output differs from the input
+///
+/// Documents are parsed as an `AnyActivity`, `AnyActor`, `AnyCollection`, `AnyLink`, or
+/// `AnyObject` depending on their `type`. Actors that aren't valid ActivityPub actors are parsed
+/// as an `AnyObject`.
+///
+/// ```rust
+/// use activitystreams::conformance::check_roundtrip;
+///
+/// let report = check_roundtrip(r#"{"type":"Collection","totalItems":"2"}"#);
+///
+/// assert_eq!(report.kind.as_deref(), Some("Collection"));
+/// assert_eq!(report.differences.len(), 1);
+/// assert_eq!(report.differences[0].path, "/totalItems");
+/// ```
+pub fn check_roundtrip(json: &str) -> Report {
+    let mut report = Report {
+        kind: None,
+        error: None,
+        differences: Vec::new(),
+    };
+
+    let expected: Value = match serde_json::from_str(json) {
+        Ok(expected) => expected,
+        Err(e) => {
+            report.error = Some(e);
+            return report;
+        }
+    };
+
+    report.kind = expected
+        .get("type")
+        .and_then(Value::as_str)
+        .map(String::from);
+
+    match roundtrip(expected.clone(), report.kind.as_deref()) {
+        Ok(actual) => diff(
+            &expected,
+            &actual,
+            &mut String::new(),
+            &mut report.differences,
+        ),
+        Err(e) => report.error = Some(e),
+    }
+
+    report
+}
+
+fn roundtrip(value: Value, kind: Option<&str>) -> Result<Value, serde_json::Error> {
+    let base: Base<Value> = serde_json::from_value(value)?;
+
+    match kind {
+        Some(kind) if ACTIVITY_KINDS.contains(&kind) => {
+            serde_json::to_value(AnyActivity::from_base(base)?)
+        }
+        Some(kind) if ACTOR_KINDS.contains(&kind) => match AnyActor::from_base(base.clone()) {
+            Ok(actor) => serde_json::to_value(actor),
+            // ActivityStreams actors don't need the inbox and outbox ActivityPub requires
+            Err(_) => serde_json::to_value(AnyObject::from_base(base)?),
+        },
+        Some(kind) if COLLECTION_KINDS.contains(&kind) => {
+            serde_json::to_value(AnyCollection::from_base(base)?)
+        }
+        Some(kind) if LINK_KINDS.contains(&kind) => serde_json::to_value(AnyLink::from_base(base)?),
+        _ => serde_json::to_value(AnyObject::from_base(base)?),
+    }
+}
+
+fn diff(expected: &Value, actual: &Value, path: &mut String, differences: &mut Vec<Difference>) {
+    match (expected, actual) {
+        (Value::Array(expected), actual) if expected.len() == 1 && !actual.is_array() => {
+            diff(&expected[0], actual, path, differences)
+        }
+        (expected, Value::Array(actual)) if actual.len() == 1 && !expected.is_array() => {
+            diff(expected, &actual[0], path, differences)
+        }
+        (Value::Object(expected), Value::Object(actual)) => {
+            for (key, expected) in expected {
+                with_segment(path, key, |path| match actual.get(key) {
+                    Some(actual) => diff(expected, actual, path, differences),
+                    None => differences.push(Difference {
+                        path: path.clone(),
+                        expected: Some(expected.clone()),
+                        actual: None,
+                    }),
+                });
+            }
+
+            for (key, actual) in actual {
+                if !expected.contains_key(key) {
+                    with_segment(path, key, |path| {
+                        differences.push(Difference {
+                            path: path.clone(),
+                            expected: None,
+                            actual: Some(actual.clone()),
+                        })
+                    });
+                }
+            }
+        }
+        (Value::Array(expected_items), Value::Array(actual_items))
+            if expected_items.len() == actual_items.len() =>
+        {
+            for (index, (expected, actual)) in expected_items.iter().zip(actual_items).enumerate() {
+                with_segment(path, &index.to_string(), |path| {
+                    diff(expected, actual, path, differences)
+                });
+            }
+        }
+        (Value::Number(e), Value::Number(a)) if e.as_f64() == a.as_f64() => {}
+        (expected, actual) if expected == actual => {}
+        (expected, actual) => differences.push(Difference {
+            path: path.clone(),
+            expected: Some(expected.clone()),
+            actual: Some(actual.clone()),
+        }),
+    }
+}
+
+fn with_segment(path: &mut String, segment: &str, f: impl FnOnce(&mut String)) {
+    let len = path.len();
+
+    path.push('/');
+    path.push_str(&segment.replace('~', "~0").replace('/', "~1"));
+    f(path);
+    path.truncate(len);
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(error) = &self.error {
+            return write!(f, "failed to round-trip: {}", error);
+        }
+
+        if self.differences.is_empty() {
+            return write!(f, "round-tripped cleanly");
+        }
+
+        write!(f, "{} differences", self.differences.len())?;
+        for difference in &self.differences {
+            write!(f, "\n  {}", difference)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl fmt::Display for Difference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+
+        match (&self.expected, &self.actual) {
+            (Some(expected), Some(actual)) => {
+                write!(f, "{}: expected {}, found {}", path, expected, actual)
+            }
+            (Some(expected), None) => write!(f, "{}: {} was dropped", path, expected),
+            (None, Some(actual)) => write!(f, "{}: {} was added", path, actual),
+            (None, None) => write!(f, "{}", path),
+        }
+    }
+}
@@ -297,24 +297,345 @@
 
 pub mod activity;
 pub mod actor;
+#[cfg(feature = "proptest")]
+pub mod arbitrary;
 pub mod base;
 pub mod checked;
 pub mod collection;
+#[cfg(feature = "conformance")]
+pub mod conformance;
 pub mod link;
 mod macros;
 pub mod markers;
+pub mod namespace;
 pub mod object;
+pub mod persist;
 pub mod primitives;
+pub mod registry;
+pub mod resolve;
 pub mod unparsed;
+pub mod validate;
+pub mod walk;
 
+#[cfg(feature = "chrono")]
+pub extern crate chrono;
 pub extern crate iri_string;
 pub extern crate mime;
 pub extern crate time;
 
+#[doc(hidden)]
+pub mod __private {
+    pub use serde;
+    pub use serde_json;
+}
+
 pub use activitystreams_kinds::{
-    context_iri as context, kind, public_iri as public, security_iri as security,
+    context_iri as context, kind, kind_enum, public_iri as public, security_iri as security,
 };
 
+/// Serialize the given value as JSON directly into the writer
+///
+/// This avoids building an intermediate `String`, which matters for large collection pages being
+/// written to sockets or files. Wrap unbuffered writers in a `std::io::BufWriter`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{collection::OrderedCollection, prelude::*};
+///
+/// let mut outbox = OrderedCollection::new();
+/// outbox.set_total_items(0u64);
+///
+/// let mut buf = Vec::new();
+/// activitystreams::to_writer(&outbox, &mut buf)?;
+///
+/// assert_eq!(buf, br#"{"totalItems":0,"type":"OrderedCollection"}"#);
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_writer<T, W>(value: &T, writer: W) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize + ?Sized,
+    W: std::io::Write,
+{
+    serde_json::to_writer(writer, value)
+}
+
+/// Serialize the given value as pretty-printed JSON directly into the writer
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::object::Note;
+///
+/// let mut buf = Vec::new();
+/// activitystreams::to_writer_pretty(&Note::new(), &mut buf)?;
+///
+/// assert_eq!(String::from_utf8(buf)?, "{\n  \"type\": \"Note\"\n}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_writer_pretty<T, W>(value: &T, writer: W) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize + ?Sized,
+    W: std::io::Write,
+{
+    serde_json::to_writer_pretty(writer, value)
+}
+
+/// Serialize the given value as canonical JSON into a buffered writer
+///
+/// Canonical JSON has the keys of every object sorted and no insignificant whitespace, so equal
+/// documents always produce identical bytes, which is useful for hashing or signing them. Since
+/// the keys need to be sorted, the document is built in memory before being written.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, object::Note, prelude::*};
+///
+/// let mut note = Note::new();
+/// note.set_id(iri!("https://example.com/notes/1"))
+///     .set_content("hi");
+///
+/// let mut buf = Vec::new();
+/// activitystreams::to_writer_canonical(&note, &mut buf)?;
+///
+/// assert_eq!(
+///     buf,
+///     br#"{"content":"hi","id":"https://example.com/notes/1","type":"Note"}"#
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn to_writer_canonical<T, W>(value: &T, writer: W) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize + ?Sized,
+    W: std::io::Write,
+{
+    fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+        match value {
+            serde_json::Value::Array(values) => {
+                serde_json::Value::Array(values.into_iter().map(canonicalize).collect())
+            }
+            serde_json::Value::Object(map) => {
+                let mut entries: Vec<_> = map.into_iter().collect();
+                entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+                serde_json::Value::Object(
+                    entries
+                        .into_iter()
+                        .map(|(key, value)| (key, canonicalize(value)))
+                        .collect(),
+                )
+            }
+            value => value,
+        }
+    }
+
+    let value = canonicalize(serde_json::to_value(value)?);
+
+    let mut writer = std::io::BufWriter::new(writer);
+    serde_json::to_writer(&mut writer, &value)?;
+
+    std::io::Write::flush(&mut writer).map_err(serde_json::Error::io)
+}
+
+/// Parse untrusted bytes into an AnyBase
+///
+/// This is the entry point the fuzz targets in `fuzz/` exercise. It accepts any JSON value an
+/// `AnyBase` can hold, and relies on serde_json's recursion limit to reject deeply nested
+/// documents rather than overflowing the stack.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// let any_base = activitystreams::parse_any(br#"{"type":"Note","content":"hi"}"#)?;
+///
+/// assert_eq!(any_base.kind_str(), Some("Note"));
+/// assert!(activitystreams::parse_any(&[b'['; 4096]).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_any(data: &[u8]) -> Result<base::AnyBase, serde_json::Error> {
+    serde_json::from_slice(data)
+}
+
+/// Deserialize a value from JSON, rejecting documents that contain duplicate keys
+///
+/// serde_json keeps the last value when an object repeats a key, so a document with two `id`s can
+/// be read one way by a signature check and another way by the code that acts on it. This
+/// rejects duplicate keys in objects at any depth before deserializing the value.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::object::Note;
+///
+/// let note: Note = activitystreams::parse_strict(br#"{"type":"Note","content":"hi"}"#)?;
+///
+/// let duplicated = br#"{
+///     "type": "Note",
+///     "attributedTo": {
+///         "id": "https://example.com/users/asonix",
+///         "id": "https://example.com/users/mallory"
+///     }
+/// }"#;
+///
+/// assert!(activitystreams::parse_strict::<Note>(duplicated).is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_strict<T>(data: &[u8]) -> Result<T, serde_json::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    struct NoDuplicateKeys;
+
+    impl<'de> serde::de::Deserialize<'de> for NoDuplicateKeys {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: serde::de::Deserializer<'de>,
+        {
+            deserializer.deserialize_any(NoDuplicateKeys)
+        }
+    }
+
+    impl<'de> serde::de::Visitor<'de> for NoDuplicateKeys {
+        type Value = NoDuplicateKeys;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.write_str("any json value")
+        }
+
+        fn visit_bool<E>(self, _: bool) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_i64<E>(self, _: i64) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_u64<E>(self, _: u64) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_f64<E>(self, _: f64) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_str<E>(self, _: &str) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(self)
+        }
+
+        fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::SeqAccess<'de>,
+        {
+            while seq.next_element::<NoDuplicateKeys>()?.is_some() {}
+            Ok(self)
+        }
+
+        fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+        where
+            A: serde::de::MapAccess<'de>,
+        {
+            let mut keys = std::collections::HashSet::new();
+
+            while let Some(key) = map.next_key::<String>()? {
+                map.next_value::<NoDuplicateKeys>()?;
+
+                if keys.contains(&key) {
+                    return Err(serde::de::Error::custom(format!("duplicate key `{}`", key)));
+                }
+                keys.insert(key);
+            }
+
+            Ok(self)
+        }
+    }
+
+    serde_json::from_slice::<NoDuplicateKeys>(data)?;
+    serde_json::from_slice(data)
+}
+
+/// Deserialize a value from JSON, resolving relative IRI references against `base`
+///
+/// IRI-valued fields in this crate hold absolute IRIs, so documents that use relative
+/// references, like `"id": "/notes/1"`, fail to deserialize directly. This resolves the
+/// references in IRI-valued properties against `base`, usually the IRI the document was fetched
+/// from, before deserializing the value.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, object::Note, prelude::*};
+///
+/// let json = br#"{
+///     "type": "Note",
+///     "id": "/notes/1",
+///     "attributedTo": "../users/asonix",
+///     "content": "/not/an/iri"
+/// }"#;
+///
+/// assert!(serde_json::from_slice::<Note>(json).is_err());
+///
+/// let note: Note = activitystreams::parse_with_base(json, &iri!("https://example.com/notes/"))?;
+///
+/// assert_eq!(note.id_unchecked().map(|id| id.as_str()), Some("https://example.com/notes/1"));
+/// assert_eq!(
+///     note.attributed_to().and_then(|a| a.as_single_id()).map(|id| id.as_str()),
+///     Some("https://example.com/users/asonix")
+/// );
+/// assert_eq!(
+///     note.content().and_then(|c| c.one()).map(|c| c.as_str()),
+///     Some("/not/an/iri")
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_with_base<T>(
+    data: &[u8],
+    base: &iri_string::types::IriStr,
+) -> Result<T, serde_json::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    let mut value: serde_json::Value = serde_json::from_slice(data)?;
+
+    primitives::resolve_json_references(&mut value, base);
+
+    serde_json::from_value(value)
+}
+
+/// Deserialize a value from a mutable JSON buffer using simd-json
+///
+/// This is considerably faster than `serde_json::from_slice` for parsing inbox payloads, but
+/// simd-json parses the buffer in place, so its contents are unspecified afterwards.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::activity::AnyActivity;
+///
+/// let mut body = br#"{
+///     "type": "Follow",
+///     "actor": "https://example.com/users/asonix",
+///     "object": "https://example.com/users/relay"
+/// }"#
+/// .to_vec();
+///
+/// let activity: AnyActivity = activitystreams::from_slice(&mut body)?;
+///
+/// assert_eq!(activity.kind_str(), Some("Follow"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "simd-json")]
+pub fn from_slice<T>(data: &mut [u8]) -> Result<T, simd_json::Error>
+where
+    T: serde::de::DeserializeOwned,
+{
+    simd_json::serde::from_slice(data)
+}
+
 pub mod prelude {
     //! Extension traits that provide the majority of the helper methods of the crate
     //!
@@ -383,5 +704,23 @@ pub mod prelude {
         collection::{CollectionExt, CollectionPageExt, OrderedCollectionPageExt},
         link::LinkExt,
         object::{ApObjectExt, ObjectExt, PlaceExt, ProfileExt, RelationshipExt, TombstoneExt},
+        primitives::XsdAnyUriExt,
     };
 }
+
+/// Parsed documents are handed between tasks and threads, so make sure none of the types that
+/// hold them lose their Send and Sync implementations
+#[allow(dead_code)]
+fn assert_send_sync() {
+    fn send_sync<T: Send + Sync>() {}
+
+    send_sync::<activity::AnyActivity>();
+    send_sync::<actor::AnyActor>();
+    send_sync::<base::AnyBase>();
+    send_sync::<base::Base<serde_json::Value>>();
+    send_sync::<collection::AnyCollection>();
+    send_sync::<link::AnyLink>();
+    send_sync::<object::AnyObject>();
+    send_sync::<primitives::OneOrMany<base::AnyBase>>();
+    send_sync::<unparsed::Unparsed>();
+}
@@ -21,17 +21,28 @@
 //! # }
 //! ```
 use crate::{
-    base::{AsBase, Base, Extends},
+    base::{AnyBase, AsBase, Base, BaseExt, Extends, ExtendsExt},
     markers,
-    primitives::OneOrMany,
+    primitives::{AnyString, Iter, LanguageTag, OneOrMany, XsdNonNegativeInteger},
+    registry::{KindRegistry, MaterializeError, Materialized},
     unparsed::{Unparsed, UnparsedMut, UnparsedMutExt},
+    validate::{Validate, Violations},
 };
 use iri_string::types::IriString;
 use std::convert::TryFrom;
 
-pub use activitystreams_kinds::link as kind;
+pub mod kind {
+    //! Kinds of links defined by the spec
+    //!
+    //! These types exist only to be statically-typed versions of the associated string. e.g.
+    //! `MentionType` -> `"Mention"`
 
-use self::kind::MentionType;
+    pub use activitystreams_kinds::link::*;
+
+    crate::kind!(HashtagType, Hashtag);
+}
+
+use self::kind::{HashtagType, LinkType, MentionType};
 
 /// Implementation trait for deriving Link methods for a type
 ///
@@ -88,6 +99,29 @@ pub trait LinkExt: AsLink {
         self
     }
 
+    /// Set the href for the current object, returning it
+    ///
+    /// This is the consuming version of `set_href`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{link::Mention, iri};
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_href(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_href(mut self, href: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_href(href);
+        self
+    }
+
     /// Take the href from the current object, leaving nothing
     ///
     /// ```rust
@@ -162,6 +196,27 @@ pub trait LinkExt: AsLink {
         self
     }
 
+    /// Set the hreflang for the current object, returning it
+    ///
+    /// This is the consuming version of `set_hreflang`
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_hreflang("en");
+    /// ```
+    fn with_hreflang<T>(mut self, hreflang: T) -> Self
+    where
+        T: Into<String>,
+        Self: Sized,
+    {
+        self.set_hreflang(hreflang);
+        self
+    }
+
     /// Take the hreflang from the current object, leaving nothing
     ///
     /// ```rust
@@ -216,6 +271,27 @@ pub trait LinkExt: AsLink {
         self.link_ref().rel.as_ref()
     }
 
+    /// Iterate over the rel for the current object
+    ///
+    /// This yields nothing if rel isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for rel in mention.iter_rel() {
+    ///     println!("{:?}", rel);
+    /// }
+    /// ```
+    fn iter_rel<'a>(&'a self) -> Iter<'a, String>
+    where
+        Self::Kind: 'a,
+    {
+        self.rel().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the rel for the current object
     ///
     /// This overwrites the contents of rel
@@ -236,6 +312,27 @@ pub trait LinkExt: AsLink {
         self
     }
 
+    /// Set the rel for the current object, returning it
+    ///
+    /// This is the consuming version of `set_rel`
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_rel("link");
+    /// ```
+    fn with_rel<T>(mut self, rel: T) -> Self
+    where
+        T: Into<String>,
+        Self: Sized,
+    {
+        self.set_rel(rel);
+        self
+    }
+
     /// Set many rels for the current object
     ///
     /// This overwrites the contents of rel
@@ -257,6 +354,27 @@ pub trait LinkExt: AsLink {
         self
     }
 
+    /// Set many rels for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_rels`
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_many_rels(vec!["link".into(), "stylesheet".into()]);
+    /// ```
+    fn with_many_rels<I>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = String>,
+        Self: Sized,
+    {
+        self.set_many_rels(items);
+        self
+    }
+
     /// Add a rel to the current object
     ///
     /// This does not overwrite the contents of rel, only appends an item
@@ -333,7 +451,9 @@ pub trait LinkExt: AsLink {
     where
         Self::Kind: 'a,
     {
-        self.link_ref().height
+        self.link_ref()
+            .height
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Set the height for the current object
@@ -352,7 +472,28 @@ pub trait LinkExt: AsLink {
     where
         T: Into<u64>,
     {
-        self.link_mut().height = Some(height.into());
+        self.link_mut().height = Some(XsdNonNegativeInteger::new(height.into()));
+        self
+    }
+
+    /// Set the height for the current object, returning it
+    ///
+    /// This is the consuming version of `set_height`
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_height(5u64);
+    /// ```
+    fn with_height<T>(mut self, height: T) -> Self
+    where
+        T: Into<u64>,
+        Self: Sized,
+    {
+        self.set_height(height);
         self
     }
 
@@ -369,7 +510,10 @@ pub trait LinkExt: AsLink {
     /// }
     /// ```
     fn take_height(&mut self) -> Option<u64> {
-        self.link_mut().height.take()
+        self.link_mut()
+            .height
+            .take()
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Delete the height from the current object
@@ -406,7 +550,7 @@ pub trait LinkExt: AsLink {
     where
         Self::Kind: 'a,
     {
-        self.link_ref().width
+        self.link_ref().width.map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Set the width for the current object
@@ -425,7 +569,28 @@ pub trait LinkExt: AsLink {
     where
         T: Into<u64>,
     {
-        self.link_mut().width = Some(width.into());
+        self.link_mut().width = Some(XsdNonNegativeInteger::new(width.into()));
+        self
+    }
+
+    /// Set the width for the current object, returning it
+    ///
+    /// This is the consuming version of `set_width`
+    ///
+    /// ```rust
+    /// # use activitystreams::link::Mention;
+    /// # let mention = Mention::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mention = mention.with_width(5u64);
+    /// ```
+    fn with_width<T>(mut self, width: T) -> Self
+    where
+        T: Into<u64>,
+        Self: Sized,
+    {
+        self.set_width(width);
         self
     }
 
@@ -442,7 +607,10 @@ pub trait LinkExt: AsLink {
     /// }
     /// ```
     fn take_width(&mut self) -> Option<u64> {
-        self.link_mut().width.take()
+        self.link_mut()
+            .width
+            .take()
+            .map(XsdNonNegativeInteger::into_inner)
     }
 
     /// Delete the width from the current object
@@ -470,6 +638,60 @@ pub trait LinkExt: AsLink {
 /// that aren't already present on a Link.
 pub type Mention = Link<MentionType>;
 
+/// A specialized Link that represents a #hashtag.
+///
+/// Hashtag isn't part of the ActivityStreams vocabulary, but is defined in the ActivityStreams
+/// JSON-LD context as an extension and is widely used for tags on Objects.
+///
+/// This is just an alias for `Link<HashtagType>` because there's no fields inherent to Hashtag
+/// that aren't already present on a Link.
+pub type Hashtag = Link<HashtagType>;
+
+/// A Link of any kind
+///
+/// When deserializing, the `type` field is used to pick the variant. Links with a `type` that
+/// isn't one of the known kinds are kept in the `Other` variant.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::link::AnyLink;
+///
+/// let json = r##"[
+///     {"type":"Mention","href":"https://example.com/users/asonix","name":"@asonix"},
+///     {"type":"Hashtag","href":"https://example.com/tags/rust","name":"#rust"}
+/// ]"##;
+///
+/// let links: Vec<AnyLink> = serde_json::from_str(json)?;
+///
+/// for link in &links {
+///     match link {
+///         AnyLink::Mention(mention) => println!("Mention {:?}", mention),
+///         AnyLink::Hashtag(hashtag) => println!("Hashtag {:?}", hashtag),
+///         AnyLink::Link(link) => println!("Link {:?}", link),
+///         AnyLink::Other(other) => println!("Unknown link {:?}", other),
+///     }
+/// }
+///
+/// assert_eq!(links[1].kind_str(), Some("Hashtag"));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(untagged)]
+pub enum AnyLink {
+    /// A plain Link
+    Link(Link<LinkType>),
+
+    /// A Mention link
+    Mention(Mention),
+
+    /// A Hashtag link
+    Hashtag(Hashtag),
+
+    /// A link whose `type` isn't one of the known kinds
+    Other(Link<String>),
+}
+
 /// Define all the properties of the Object base type as described by the Activity Streams
 /// vocabulary.
 ///
@@ -486,7 +708,7 @@ pub type Mention = Link<MentionType>;
 /// object might have multiple such visual representations -- multiple screenshots, for instance,
 /// or the same image at different resolutions. In Activity Streams 2.0, there are essentially
 /// three ways of describing such references.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Link<Kind> {
     /// The target resource pointed to by a Link.
@@ -513,7 +735,7 @@ pub struct Link<Kind> {
     ///
     /// - Range: [RFC5988] or [HTML5] Link Relation
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     rel: Option<OneOrMany<String>>,
 
     /// On a Link, specifies a hint as to the rendering height in device-independent pixels of the linked resource.
@@ -521,14 +743,14 @@ pub struct Link<Kind> {
     /// - Range: xsd:nonNegativeInteger
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    height: Option<u64>,
+    height: Option<XsdNonNegativeInteger>,
 
     /// On a Link, specifies a hint as to the rendering width in device-independent pixels of the linked resource.
     ///
     /// Range: xsd:nonNegativeInteger
     /// Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    width: Option<u64>,
+    width: Option<XsdNonNegativeInteger>,
 
     /// Base fields and unparsed json ends up here
     #[serde(flatten)]
@@ -616,6 +838,176 @@ impl<Kind> Link<Kind> {
     }
 }
 
+macro_rules! any_link {
+    ($any_link:expr, $link:ident => $expr:expr) => {
+        match $any_link {
+            AnyLink::Link($link) => $expr,
+            AnyLink::Mention($link) => $expr,
+            AnyLink::Hashtag($link) => $expr,
+            AnyLink::Other($link) => $expr,
+        }
+    };
+}
+
+impl AnyLink {
+    /// Create an AnyLink from a generic Base, picking the variant based on its `type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::Base, link::AnyLink};
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"type":"Mention","href":"https://example.com/users/asonix"}"#,
+    /// )?;
+    ///
+    /// let link = AnyLink::from_base(base)?;
+    ///
+    /// assert!(matches!(link, AnyLink::Mention(_)));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base(base: Base<serde_json::Value>) -> Result<Self, serde_json::Error> {
+        let any_link = match base.kind().and_then(|kind| kind.as_str()) {
+            Some("Link") => AnyLink::Link(base.solidify()?.extend()?),
+            Some("Mention") => AnyLink::Mention(base.solidify()?.extend()?),
+            Some("Hashtag") => AnyLink::Hashtag(base.solidify()?.extend()?),
+            _ => AnyLink::Other(base.solidify()?.extend()?),
+        };
+
+        Ok(any_link)
+    }
+
+    /// Create an AnyLink from an AnyBase
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{link::{AnyLink, Hashtag}, prelude::*};
+    ///
+    /// let any_base = Hashtag::new().into_any_base()?;
+    ///
+    /// let link = AnyLink::from_any_base(any_base)?;
+    ///
+    /// assert!(matches!(link, Some(AnyLink::Hashtag(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_any_base(any_base: AnyBase) -> Result<Option<Self>, serde_json::Error> {
+        any_base.take_base().map(Self::from_base).transpose()
+    }
+
+    /// Create an AnyLink from a generic Base, or the type registered for its `type`
+    ///
+    /// Types registered with the `KindRegistry` take precedence over the variants of this enum.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{base::Base, link::AnyLink, registry::KindRegistry};
+    ///
+    /// let registry = KindRegistry::new();
+    /// let base: Base<serde_json::Value> = serde_json::from_str(r#"{"type":"Mention","href":"https://example.com/users/asonix"}"#)?;
+    ///
+    /// let link = AnyLink::from_base_with(base, &registry)?.unregistered();
+    /// assert!(link.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base_with(
+        base: Base<serde_json::Value>,
+        registry: &KindRegistry,
+    ) -> Result<Materialized<Self>, MaterializeError> {
+        registry.materialize_base(base, Self::from_base)
+    }
+
+    /// Create an AnyLink from an AnyBase, or the type registered for its `type`
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    pub fn from_any_base_with(
+        any_base: AnyBase,
+        registry: &KindRegistry,
+    ) -> Result<Option<Materialized<Self>>, MaterializeError> {
+        any_base
+            .take_base()
+            .map(|base| Self::from_base_with(base, registry))
+            .transpose()
+    }
+
+    /// Convert this AnyLink into an AnyBase
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::link::{AnyLink, Mention};
+    ///
+    /// let link = AnyLink::from(Mention::new());
+    ///
+    /// let any_base = link.into_any_base()?;
+    ///
+    /// assert_eq!(any_base.kind_str(), Some("Mention"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_any_base(self) -> Result<AnyBase, serde_json::Error> {
+        any_link!(self, link => link.into_any_base())
+    }
+
+    /// Fetch the `type` of the current link as a string
+    ///
+    /// ```rust
+    /// # use activitystreams::link::{AnyLink, Mention};
+    /// let link = AnyLink::from(Mention::new());
+    ///
+    /// assert_eq!(link.kind_str(), Some("Mention"));
+    /// ```
+    pub fn kind_str(&self) -> Option<&str> {
+        match self {
+            AnyLink::Link(_) => Some("Link"),
+            AnyLink::Mention(_) => Some("Mention"),
+            AnyLink::Hashtag(_) => Some("Hashtag"),
+            AnyLink::Other(link) => link.kind().map(String::as_str),
+        }
+    }
+
+    /// Fetch the id for the current link
+    ///
+    /// ```rust
+    /// # use activitystreams::link::{AnyLink, Mention};
+    /// # let link = AnyLink::from(Mention::new());
+    /// if let Some(id) = link.id_unchecked() {
+    ///     println!("{:?}", id);
+    /// }
+    /// ```
+    pub fn id_unchecked(&self) -> Option<&IriString> {
+        any_link!(self, link => link.id_unchecked())
+    }
+
+    /// Fetch the href for the current link
+    ///
+    /// ```rust
+    /// # use activitystreams::link::{AnyLink, Mention};
+    /// # let link = AnyLink::from(Mention::new());
+    /// if let Some(href) = link.href() {
+    ///     println!("{:?}", href);
+    /// }
+    /// ```
+    pub fn href(&self) -> Option<&IriString> {
+        any_link!(self, link => link.href())
+    }
+
+    /// Fetch the name for the current link
+    ///
+    /// ```rust
+    /// # use activitystreams::link::{AnyLink, Mention};
+    /// # let link = AnyLink::from(Mention::new());
+    /// if let Some(name) = link.name() {
+    ///     println!("{:?}", name);
+    /// }
+    /// ```
+    pub fn name(&self) -> Option<OneOrMany<&AnyString>> {
+        any_link!(self, link => link.name())
+    }
+}
+
 impl<Kind> markers::Base for Link<Kind> {}
 impl<Kind> markers::Link for Link<Kind> {}
 
@@ -687,6 +1079,40 @@ impl<Kind> AsLink for Link<Kind> {
 
 impl<T> LinkExt for T where T: AsLink {}
 
+impl<'de> serde::de::Deserialize<'de> for AnyLink {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let base = Base::<serde_json::Value>::deserialize(deserializer)?;
+        AnyLink::from_base(base).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<Link<LinkType>> for AnyLink {
+    fn from(link: Link<LinkType>) -> Self {
+        AnyLink::Link(link)
+    }
+}
+
+impl From<Mention> for AnyLink {
+    fn from(link: Mention) -> Self {
+        AnyLink::Mention(link)
+    }
+}
+
+impl From<Hashtag> for AnyLink {
+    fn from(link: Hashtag) -> Self {
+        AnyLink::Hashtag(link)
+    }
+}
+
+impl From<Link<String>> for AnyLink {
+    fn from(link: Link<String>) -> Self {
+        AnyLink::Other(link)
+    }
+}
+
 impl<Kind> Default for Link<Kind>
 where
     Kind: Default,
@@ -695,3 +1121,27 @@ where
         Self::new()
     }
 }
+
+impl<Kind> Validate for Link<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        if let Some(hreflang) = &self.hreflang {
+            if LanguageTag::parse(hreflang).is_err() {
+                violations.report_field("hreflang", "hreflang must be a BCP47 language tag");
+            }
+        }
+
+        for rel in self.rel.iter().flat_map(OneOrMany::iter) {
+            if rel.is_empty() || rel.contains(|c: char| c.is_whitespace() || c == ',') {
+                violations.report_field("rel", format!("{:?} is not a valid link relation", rel));
+            }
+        }
+    }
+}
+
+impl Validate for AnyLink {
+    fn validate_into(&self, violations: &mut Violations) {
+        any_link!(self, link => link.validate_into(violations))
+    }
+}
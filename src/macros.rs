@@ -13,7 +13,7 @@
 #[macro_export]
 macro_rules! iri {
     ( $x:expr ) => {{
-        use activitystreams::iri_string::types::IriString;
+        use $crate::iri_string::types::IriString;
 
         $x.parse::<IriString>()?
     }};
@@ -32,7 +32,7 @@ macro_rules! iri {
 #[macro_export]
 macro_rules! fragment {
     ( $x:expr ) => {{
-        use activitystreams::iri_string::types::IriFragmentString;
+        use $crate::iri_string::types::IriFragmentString;
 
         $x.parse::<IriFragmentString>()?
     }};
@@ -51,8 +51,97 @@ macro_rules! fragment {
 #[macro_export]
 macro_rules! datetime {
     ( $x:expr ) => {{
-        use activitystreams::time::{format_description::well_known::Rfc3339, OffsetDateTime};
+        use $crate::time::{format_description::well_known::Rfc3339, OffsetDateTime};
 
         OffsetDateTime::parse($x, &Rfc3339)?
     }};
 }
+
+/// A macro to generate a module for reading and writing properties from a vendor namespace
+///
+/// The generated module contains a `Namespace` type implementing
+/// `activitystreams::namespace::Namespace`, along with `context`, `context_with_terms`, `get`,
+/// `take`, and `set` functions specialized to the namespace.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{actor::{ApActor, Person}, namespace};
+///
+/// namespace!(toot = "http://joinmastodon.org/ns#");
+///
+/// let person: ApActor<Person> = serde_json::from_str(r#"{
+///     "type": "Person",
+///     "inbox": "https://example.com/inbox",
+///     "http://joinmastodon.org/ns#discoverable": true
+/// }"#)?;
+///
+/// assert_eq!(toot::get::<bool, _>(&person, "discoverable")?, Some(true));
+/// # Ok(())
+/// # }
+/// ```
+///
+/// Anything other than `prefix = "iri"` is rejected with an error explaining the expected input.
+///
+/// ```rust,compile_fail
+/// use activitystreams::namespace;
+///
+/// namespace!("toot" => "http://joinmastodon.org/ns#");
+/// ```
+#[macro_export]
+macro_rules! namespace {
+    ( $prefix:ident = $iri:expr ) => {
+        #[allow(dead_code)]
+        mod $prefix {
+            /// The namespace type for use with the functions in `activitystreams::namespace`
+            #[derive(Clone, Copy, Debug, Default)]
+            pub struct Namespace;
+
+            impl $crate::namespace::Namespace for Namespace {
+                const PREFIX: &'static str = ::core::stringify!($prefix);
+                const IRI: &'static str = $iri;
+            }
+
+            /// Produce a `@context` entry binding this namespace's prefix to its IRI
+            pub fn context() -> $crate::base::AnyBase {
+                $crate::namespace::context::<Namespace>()
+            }
+
+            /// Produce a `@context` entry binding this namespace's prefix, and each term, to it
+            pub fn context_with_terms(terms: &[&str]) -> $crate::base::AnyBase {
+                $crate::namespace::context_with_terms::<Namespace>(terms)
+            }
+
+            /// Fetch a property in this namespace from an object
+            pub fn get<T, B>(object: &B, term: &str) -> ::core::result::Result<::core::option::Option<T>, $crate::__private::serde_json::Error>
+            where
+                T: $crate::__private::serde::de::DeserializeOwned,
+                B: $crate::base::AsBase + ?Sized,
+            {
+                $crate::namespace::get::<Namespace, T, B>(object, term)
+            }
+
+            /// Remove a property in this namespace from an object, returning it
+            pub fn take<T, B>(object: &mut B, term: &str) -> ::core::result::Result<::core::option::Option<T>, $crate::__private::serde_json::Error>
+            where
+                T: $crate::__private::serde::de::DeserializeOwned,
+                B: $crate::base::AsBase + ?Sized,
+            {
+                $crate::namespace::take::<Namespace, T, B>(object, term)
+            }
+
+            /// Set a property in this namespace on an object
+            pub fn set<T, B>(object: &mut B, term: &str, value: T) -> ::core::result::Result<(), $crate::__private::serde_json::Error>
+            where
+                T: $crate::__private::serde::Serialize,
+                B: $crate::base::AsBase + ?Sized,
+            {
+                $crate::namespace::set::<Namespace, T, B>(object, term, value)
+            }
+        }
+    };
+    ($($tt:tt)*) => {
+        compile_error!(
+            "namespace! expects a prefix and an IRI, like `namespace!(toot = \"http://joinmastodon.org/ns#\")`"
+        );
+    };
+}
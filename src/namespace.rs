@@ -0,0 +1,378 @@
+//! Types and functions for reading and writing properties from vendor namespaces
+//!
+//! Implementations like Misskey, Pleroma, and Mastodon add their own properties to objects, scoped
+//! to a JSON-LD namespace. Depending on the `@context` a document was written with, the same
+//! property can appear as a bare term (`_misskey_reaction`), a compact IRI
+//! (`misskey:_misskey_reaction`), or a full IRI (`https://misskey-hub.net/ns#_misskey_reaction`).
+//! The `get` and `take` functions look for all three, and `set` writes the bare term, which is
+//! what the context produced by `context_with_terms` maps to the namespace.
+//!
+//! The `namespace!` macro generates a module with these functions specialized to one namespace.
+//!
+//! ```rust
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{namespace, object::Note, prelude::*};
+//!
+//! namespace!(misskey = "https://misskey-hub.net/ns#");
+//!
+//! let mut note: Note = serde_json::from_str(r#"{
+//!     "type": "Note",
+//!     "misskey:_misskey_reaction": "🦀"
+//! }"#)?;
+//!
+//! let reaction: Option<String> = misskey::get(&note, "_misskey_reaction")?;
+//! assert_eq!(reaction.as_deref(), Some("🦀"));
+//!
+//! misskey::set(&mut note, "_misskey_quote", "https://example.com/notes/1")?;
+//! note.add_context(misskey::context_with_terms(&["_misskey_reaction", "_misskey_quote"]));
+//!
+//! let json = serde_json::to_value(&note)?;
+//! assert_eq!(json["_misskey_quote"], "https://example.com/notes/1");
+//! assert_eq!(json["@context"]["misskey"], "https://misskey-hub.net/ns#");
+//! assert_eq!(json["@context"]["_misskey_quote"], "misskey:_misskey_quote");
+//! # Ok(())
+//! # }
+//! ```
+use crate::base::{AnyBase, AsBase};
+use iri_string::types::IriString;
+use serde_json::{Map, Value};
+
+/// A JSON-LD namespace used for vendor properties
+///
+/// This is usually implemented with the `namespace!` macro
+pub trait Namespace {
+    /// The prefix the namespace is bound to in `@context`, e.g. `misskey`
+    const PREFIX: &'static str;
+
+    /// The IRI of the namespace, e.g. `https://misskey-hub.net/ns#`
+    const IRI: &'static str;
+}
+
+/// Contexts and namespaces that show up in `@context` across the fediverse
+///
+/// Documents contexts like ActivityStreams are referenced by their IRI directly, while vendor
+/// namespaces like Mastodon's are bound to a prefix inside an object. Converting a KnownContext
+/// into an AnyBase produces whichever form is conventional, and `matches` recognizes both.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{namespace::KnownContext, object::Note, prelude::*};
+///
+/// let note: Note = serde_json::from_str(r#"{
+///     "@context": [
+///         "https://www.w3.org/ns/activitystreams",
+///         { "toot": "http://joinmastodon.org/ns#", "sensitive": "as:sensitive" }
+///     ],
+///     "type": "Note"
+/// }"#)?;
+///
+/// let known: Vec<_> = note
+///     .context()
+///     .into_iter()
+///     .flat_map(|context| context.iter())
+///     .flat_map(KnownContext::all_in)
+///     .collect();
+///
+/// assert_eq!(known, [KnownContext::ActivityStreams, KnownContext::Toot]);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[non_exhaustive]
+pub enum KnownContext {
+    /// `https://www.w3.org/ns/activitystreams`
+    ActivityStreams,
+
+    /// `https://w3id.org/security/v1`
+    SecurityV1,
+
+    /// `https://w3id.org/security/v2`
+    SecurityV2,
+
+    /// Mastodon's `http://joinmastodon.org/ns#`, bound to `toot`
+    Toot,
+
+    /// Pleroma's `http://litepub.social/ns#`, bound to `litepub`
+    Litepub,
+
+    /// Misskey's `https://misskey-hub.net/ns#`, bound to `misskey`
+    Misskey,
+}
+
+impl KnownContext {
+    /// Every known context, in declaration order
+    pub const ALL: [KnownContext; 6] = [
+        KnownContext::ActivityStreams,
+        KnownContext::SecurityV1,
+        KnownContext::SecurityV2,
+        KnownContext::Toot,
+        KnownContext::Litepub,
+        KnownContext::Misskey,
+    ];
+
+    /// The IRI of the context
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KnownContext::ActivityStreams => "https://www.w3.org/ns/activitystreams",
+            KnownContext::SecurityV1 => "https://w3id.org/security/v1",
+            KnownContext::SecurityV2 => "https://w3id.org/security/v2",
+            KnownContext::Toot => "http://joinmastodon.org/ns#",
+            KnownContext::Litepub => "http://litepub.social/ns#",
+            KnownContext::Misskey => "https://misskey-hub.net/ns#",
+        }
+    }
+
+    /// The prefix a vendor namespace is conventionally bound to, or None for document contexts
+    pub fn prefix(&self) -> Option<&'static str> {
+        match self {
+            KnownContext::Toot => Some("toot"),
+            KnownContext::Litepub => Some("litepub"),
+            KnownContext::Misskey => Some("misskey"),
+            _ => None,
+        }
+    }
+
+    /// The IRI of the context, as an IriString
+    ///
+    /// ```rust
+    /// use activitystreams::namespace::KnownContext;
+    ///
+    /// assert_eq!(KnownContext::SecurityV1.iri(), activitystreams::security());
+    /// ```
+    pub fn iri(&self) -> IriString {
+        self.as_str()
+            .parse()
+            .expect("Known contexts are valid IRIs")
+    }
+
+    /// Look up the known context for an IRI
+    ///
+    /// ActivityStreams is also recognized by its `http` IRI, which the spec requires consumers
+    /// to accept.
+    ///
+    /// ```rust
+    /// use activitystreams::namespace::KnownContext;
+    ///
+    /// assert_eq!(
+    ///     KnownContext::from_iri("http://www.w3.org/ns/activitystreams"),
+    ///     Some(KnownContext::ActivityStreams)
+    /// );
+    /// assert_eq!(KnownContext::from_iri("https://example.com/ns#"), None);
+    /// ```
+    pub fn from_iri(iri: &str) -> Option<Self> {
+        if iri == "http://www.w3.org/ns/activitystreams" {
+            return Some(KnownContext::ActivityStreams);
+        }
+
+        KnownContext::ALL
+            .into_iter()
+            .find(|known| known.as_str() == iri)
+    }
+
+    /// Check whether a single `@context` entry references this context
+    ///
+    /// This matches the IRI itself, or an object binding any term to the IRI.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::AnyBase, namespace::KnownContext};
+    ///
+    /// let entry = AnyBase::from_arbitrary_json(serde_json::json!({
+    ///     "litepub": "http://litepub.social/ns#"
+    /// }))?;
+    ///
+    /// assert!(KnownContext::Litepub.matches(&entry));
+    /// assert!(!KnownContext::Toot.matches(&entry));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn matches(&self, context: &AnyBase) -> bool {
+        KnownContext::all_in(context).any(|known| known == *self)
+    }
+
+    /// Iterate over the known contexts referenced by a single `@context` entry
+    pub fn all_in(context: &AnyBase) -> impl Iterator<Item = KnownContext> + '_ {
+        let iri = context
+            .as_xsd_any_uri()
+            .map(|iri| iri.as_str())
+            .or_else(|| context.as_xsd_string());
+
+        let bound = context
+            .as_base()
+            .into_iter()
+            .flat_map(|base| base.unparsed().values())
+            .filter_map(Value::as_str);
+
+        iri.into_iter()
+            .chain(bound)
+            .filter_map(KnownContext::from_iri)
+    }
+}
+
+impl std::fmt::Display for KnownContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl From<KnownContext> for IriString {
+    fn from(known: KnownContext) -> Self {
+        known.iri()
+    }
+}
+
+impl From<KnownContext> for AnyBase {
+    fn from(known: KnownContext) -> Self {
+        match known.prefix() {
+            Some(prefix) => {
+                let mut map = Map::new();
+                map.insert(prefix.to_owned(), Value::String(known.as_str().to_owned()));
+
+                AnyBase::from_arbitrary_json(Value::Object(map))
+                    .expect("A json object is always a valid AnyBase")
+            }
+            None => AnyBase::from_xsd_any_uri(known.iri()),
+        }
+    }
+}
+
+/// Produce a `@context` entry binding the namespace's prefix to its IRI
+///
+/// ```rust
+/// use activitystreams::namespace;
+///
+/// namespace!(toot = "http://joinmastodon.org/ns#");
+///
+/// let context = toot::context();
+///
+/// assert!(context.is_base());
+/// ```
+pub fn context<N>() -> AnyBase
+where
+    N: Namespace + ?Sized,
+{
+    context_with_terms::<N>(&[])
+}
+
+/// Produce a `@context` entry binding the namespace's prefix, and each term, to the namespace
+///
+/// With this entry in a document's context, the bare terms are understood to be in the namespace.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::namespace;
+///
+/// namespace!(toot = "http://joinmastodon.org/ns#");
+///
+/// let context = toot::context_with_terms(&["discoverable"]);
+///
+/// assert_eq!(
+///     serde_json::to_value(&context)?,
+///     serde_json::json!({
+///         "toot": "http://joinmastodon.org/ns#",
+///         "discoverable": "toot:discoverable"
+///     })
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn context_with_terms<N>(terms: &[&str]) -> AnyBase
+where
+    N: Namespace + ?Sized,
+{
+    let mut map = Map::new();
+
+    map.insert(N::PREFIX.to_owned(), Value::String(N::IRI.to_owned()));
+    for term in terms {
+        map.insert(
+            (*term).to_owned(),
+            Value::String(format!("{}:{}", N::PREFIX, term)),
+        );
+    }
+
+    AnyBase::from_arbitrary_json(Value::Object(map))
+        .expect("A json object is always a valid AnyBase")
+}
+
+/// Fetch a namespaced property from an object
+///
+/// The property is looked up as a bare term, a compact IRI, and a full IRI, in that order. This
+/// returns `Ok(None)` if the property isn't present, and an error if it is present but can't be
+/// deserialized as `T`.
+pub fn get<N, T, B>(object: &B, term: &str) -> Result<Option<T>, serde_json::Error>
+where
+    N: Namespace + ?Sized,
+    T: serde::de::DeserializeOwned,
+    B: AsBase + ?Sized,
+{
+    let unparsed = object.base_ref().unparsed();
+
+    let value = unparsed
+        .get(term)
+        .or_else(|| unparsed.get(&format!("{}:{}", N::PREFIX, term)))
+        .or_else(|| unparsed.get(&format!("{}{}", N::IRI, term)));
+
+    value.map(T::deserialize).transpose()
+}
+
+/// Remove a namespaced property from an object, returning it
+///
+/// Like `get`, the bare term is preferred over the compact IRI, and the compact IRI over the full
+/// IRI, but all three are removed. The value is moved out of the object rather than cloned.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{namespace, object::Note};
+///
+/// namespace!(misskey = "https://misskey-hub.net/ns#");
+///
+/// let mut note: Note = serde_json::from_str(r#"{
+///     "type": "Note",
+///     "misskey:_misskey_reaction": "🦀"
+/// }"#)?;
+///
+/// let reaction: Option<String> = misskey::take(&mut note, "_misskey_reaction")?;
+/// assert_eq!(reaction.as_deref(), Some("🦀"));
+///
+/// assert!(misskey::get::<String, _>(&note, "_misskey_reaction")?.is_none());
+/// # Ok(())
+/// # }
+/// ```
+pub fn take<N, T, B>(object: &mut B, term: &str) -> Result<Option<T>, serde_json::Error>
+where
+    N: Namespace + ?Sized,
+    T: serde::de::DeserializeOwned,
+    B: AsBase + ?Sized,
+{
+    let unparsed = crate::unparsed::UnparsedMut::unparsed_mut(object.base_mut());
+
+    let bare = unparsed.remove(term);
+    let compact = unparsed.remove(&format!("{}:{}", N::PREFIX, term));
+    let full = unparsed.remove(&format!("{}{}", N::IRI, term));
+
+    [bare, compact, full]
+        .into_iter()
+        .find(|value| !value.is_null())
+        .map(serde_json::from_value)
+        .transpose()
+}
+
+/// Set a namespaced property on an object, replacing any existing value
+///
+/// The property is written as a bare term, so the object's `@context` should include the entry
+/// from `context_with_terms`. Existing values under the compact or full IRI are removed.
+pub fn set<N, T, B>(object: &mut B, term: &str, value: T) -> Result<(), serde_json::Error>
+where
+    N: Namespace + ?Sized,
+    T: serde::Serialize,
+    B: AsBase + ?Sized,
+{
+    let value = serde_json::to_value(value)?;
+    let unparsed = crate::unparsed::UnparsedMut::unparsed_mut(object.base_mut());
+
+    unparsed.remove(&format!("{}:{}", N::PREFIX, term));
+    unparsed.remove(&format!("{}{}", N::IRI, term));
+    unparsed.insert(term.to_owned(), value);
+
+    Ok(())
+}
@@ -1,5 +1,9 @@
 //! Types and traits for dealing with Object attributes
 //!
+//! Every Object also has a `Full*` alias, such as `FullNote`, naming the `ApObject` that
+//! `ObjectExt::full` wraps it in to add the ActivityPub Object properties like `shares` and
+//! `likes`.
+//!
 //! ```rust
 //! # fn main() -> Result<(), anyhow::Error> {
 //! use activitystreams::{
@@ -20,16 +24,31 @@
 //! # }
 //! ```
 use crate::{
-    base::{AnyBase, AsBase, Base, Extends},
+    base::{AnyBase, AsBase, Base, BaseExt, Extends, ExtendsExt},
+    collection::UnorderedCollection,
     markers,
-    primitives::{AnyString, OneOrMany, Unit, XsdDateTime, XsdDuration},
+    primitives::{
+        deserialize_bounded, AnyString, Iter, OneOrMany, RdfLangStringMap, Unit, XsdDateTime,
+        XsdDuration, XsdFloat, XsdFloatError, XsdNonNegativeInteger,
+    },
+    registry::{KindRegistry, MaterializeError, Materialized},
     unparsed::{Unparsed, UnparsedMut, UnparsedMutExt},
+    validate::{Validate, Violations},
 };
 use iri_string::types::IriString;
 use std::convert::TryFrom;
 use time::{Duration, OffsetDateTime};
 
-pub use activitystreams_kinds::object as kind;
+pub mod kind {
+    //! Kinds of objects defined by the spec
+    //!
+    //! These types exist only to be statically-typed versions of the associated string. e.g.
+    //! `PlaceType` -> `"Place"`
+
+    pub use activitystreams_kinds::object::*;
+
+    crate::kind!(EmojiType, Emoji);
+}
 
 use self::kind::*;
 
@@ -129,6 +148,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().attachment.as_ref()
     }
 
+    /// Iterate over the attachment for the current object
+    ///
+    /// This yields nothing if attachment isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for attachment in video.iter_attachment() {
+    ///     println!("{:?}", attachment);
+    /// }
+    /// ```
+    fn iter_attachment<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.attachment().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the attachment for the current object
     ///
     /// This overwrites the contents of attachment
@@ -151,6 +191,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the attachment for the current object, returning it
+    ///
+    /// This is the consuming version of `set_attachment`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_attachment(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_attachment<T>(mut self, attachment: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_attachment(attachment);
+        self
+    }
+
     /// Set many attachments for the current object
     ///
     /// This overwrites the contents of attachment
@@ -178,6 +241,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many attachments for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_attachments`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_attachments(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_attachments<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_attachments(items);
+        self
+    }
+
     /// Add a attachment to the current object
     ///
     /// This does not overwrite the contents of attachment, only appends an item
@@ -265,6 +355,29 @@ pub trait ObjectExt: AsObject {
         self.object_ref().attributed_to.as_ref()
     }
 
+    /// Iterate over the attributed_to for the current object
+    ///
+    /// This yields nothing if attributed_to isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for attributed_to in video.iter_attributed_to() {
+    ///     println!("{:?}", attributed_to);
+    /// }
+    /// ```
+    fn iter_attributed_to<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.attributed_to()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
     /// Set the attributed_to for the current object
     ///
     /// This overwrites the contents of attributed_to
@@ -287,6 +400,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the attributed_to for the current object, returning it
+    ///
+    /// This is the consuming version of `set_attributed_to`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_attributed_to(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_attributed_to<T>(mut self, attributed_to: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_attributed_to(attributed_to);
+        self
+    }
+
     /// Set many attributed_tos for the current object
     ///
     /// This overwrites the contents of attributed_to
@@ -314,6 +450,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many attributed_tos for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_attributed_tos`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_attributed_tos(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_attributed_tos<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_attributed_tos(items);
+        self
+    }
+
     /// Add a attributed_to to the current object
     ///
     /// This does not overwrite the contents of attributed_to, only appends an item
@@ -401,6 +564,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().audience.as_ref()
     }
 
+    /// Iterate over the audience for the current object
+    ///
+    /// This yields nothing if audience isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for audience in video.iter_audience() {
+    ///     println!("{:?}", audience);
+    /// }
+    /// ```
+    fn iter_audience<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.audience().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the audience for the current object
     ///
     /// This overwrites the contents of audience
@@ -423,6 +607,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the audience for the current object, returning it
+    ///
+    /// This is the consuming version of `set_audience`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_audience(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_audience<T>(mut self, audience: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_audience(audience);
+        self
+    }
+
     /// This overwrites the contents of audience
     ///
     /// ```rust
@@ -448,6 +655,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// This overwrites the contents of audience, returning it
+    ///
+    /// This is the consuming version of `set_many_audiences`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_audiences(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_audiences<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_audiences(items);
+        self
+    }
+
     /// Add a audience to the current object
     ///
     /// This does not overwrite the contents of audience, only appends an item
@@ -535,6 +769,52 @@ pub trait ObjectExt: AsObject {
         self.object_ref().content.as_ref().map(|o| o.as_ref())
     }
 
+    /// Iterate over the content for the current object
+    ///
+    /// This yields nothing if content isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for content in video.iter_content() {
+    ///     println!("{:?}", content);
+    /// }
+    /// ```
+    fn iter_content<'a>(&'a self) -> Iter<'a, AnyString>
+    where
+        Self::Kind: 'a,
+    {
+        self.object_ref()
+            .content
+            .as_ref()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
+    /// Fetch the content for the current object as plain text
+    ///
+    /// The HTML in the first content value is converted with `AnyString::to_plain_text`
+    ///
+    /// ```rust
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let mut note = Note::new();
+    /// note.set_content("<p>Hello <a href=\"https://example.com/@asonix\">@asonix</a></p>");
+    ///
+    /// assert_eq!(note.content_text().as_deref(), Some("Hello @asonix"));
+    /// ```
+    #[cfg(feature = "html-text")]
+    fn content_text(&self) -> Option<String> {
+        self.object_ref()
+            .content
+            .as_ref()
+            .and_then(|content| content.iter().next())
+            .map(AnyString::to_plain_text)
+    }
+
     /// Set the content for the current object
     ///
     /// This overwrites the contents of content
@@ -554,6 +834,26 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the content for the current object, returning it
+    ///
+    /// This is the consuming version of `set_content`
+    ///
+    /// ```rust
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note = Note::new().with_content("hi").with_summary("greetings");
+    ///
+    /// assert!(note.content().is_some());
+    /// ```
+    fn with_content<T>(mut self, content: T) -> Self
+    where
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_content(content);
+        self
+    }
+
     /// Set many contents for the current object
     ///
     /// This overwrites the contents of content
@@ -575,6 +875,27 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many contents for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_contents`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_contents(vec!["hi", "hello"]);
+    /// ```
+    fn with_many_contents<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_many_contents(items);
+        self
+    }
+
     /// Add a content to the current object
     ///
     /// This does not overwrite the contents of content, only appends an item
@@ -637,50 +958,230 @@ pub trait ObjectExt: AsObject {
         self
     }
 
-    /// Fetch the summary for the current object
+    /// Fetch the contentMap for the current object
     ///
     /// ```rust
-    /// # use activitystreams::object::Video;
-    /// # let mut video = Video::new();
-    /// #
-    /// use activitystreams::prelude::*;
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note: Note = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "contentMap": {
+    ///         "fr": "<p>Bonjour</p>"
+    ///     }
+    /// }"#)?;
+    ///
+    /// assert_eq!(
+    ///     note.content_map().and_then(|map| map.get("fr")),
+    ///     Some("<p>Bonjour</p>")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn content_map(&self) -> Option<&RdfLangStringMap> {
+        self.object_ref().content_map.as_ref()
+    }
+
+    /// Set the contentMap for the current object
     ///
-    /// if let Some(summary) = video.summary() {
-    ///     println!("{:?}", summary);
-    /// }
+    /// This overwrites the contents of contentMap
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "<p>Hello</p>");
+    ///
+    /// let mut note = Note::new();
+    /// note.set_content_map(map);
+    /// # Ok(())
+    /// # }
     /// ```
-    fn summary<'a>(&'a self) -> Option<OneOrMany<&'a AnyString>>
+    fn set_content_map<T>(&mut self, content_map: T) -> &mut Self
     where
-        Self::Kind: 'a,
+        T: Into<RdfLangStringMap>,
     {
-        self.object_ref().summary.as_ref().map(|o| o.as_ref())
+        self.object_mut().content_map = Some(content_map.into());
+        self
     }
 
-    /// Set the summary for the current object
+    /// Set the contentMap for the current object, returning it
     ///
-    /// This overwrites the contents of summary
+    /// This is the consuming version of `set_content_map`
     ///
     /// ```rust
-    /// use activitystreams::prelude::*;
-    /// # use activitystreams::object::Video;
-    /// # let mut video = Video::new();
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
     ///
-    /// video.set_summary("hi");
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "<p>Hello</p>");
+    ///
+    /// let note = Note::new();
+    /// let note = note.with_content_map(map);
+    /// # Ok(())
+    /// # }
     /// ```
-    fn set_summary<T>(&mut self, summary: T) -> &mut Self
+    fn with_content_map<T>(mut self, content_map: T) -> Self
     where
-        T: Into<AnyString>,
+        T: Into<RdfLangStringMap>,
+        Self: Sized,
     {
-        self.object_mut().summary = Some(summary.into().into());
+        self.set_content_map(content_map);
         self
     }
 
-    /// Set many summaries for the current object
-    ///
-    /// This overwrites the contents of summary
+    /// Take the contentMap from the current object, leaving nothing
     ///
     /// ```rust
-    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// if let Some(content_map) = video.take_content_map() {
+    ///     println!("{:?}", content_map);
+    /// }
+    /// ```
+    fn take_content_map(&mut self) -> Option<RdfLangStringMap> {
+        self.object_mut().content_map.take()
+    }
+
+    /// Delete the contentMap from the current object
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::Video, primitives::RdfLangString};
+    /// # let mut video = Video::new();
+    /// # video.set_content_map(RdfLangString {
+    /// #     value: "<p>Hello</p>".to_owned(),
+    /// #     language: "en".parse()?,
+    /// #     direction: None,
+    /// # });
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// assert!(video.content_map().is_some());
+    /// video.delete_content_map();
+    /// assert!(video.content_map().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn delete_content_map(&mut self) -> &mut Self {
+        self.object_mut().content_map = None;
+        self
+    }
+
+    /// Fetch the summary for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// if let Some(summary) = video.summary() {
+    ///     println!("{:?}", summary);
+    /// }
+    /// ```
+    fn summary<'a>(&'a self) -> Option<OneOrMany<&'a AnyString>>
+    where
+        Self::Kind: 'a,
+    {
+        self.object_ref().summary.as_ref().map(|o| o.as_ref())
+    }
+
+    /// Iterate over the summary for the current object
+    ///
+    /// This yields nothing if summary isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for summary in video.iter_summary() {
+    ///     println!("{:?}", summary);
+    /// }
+    /// ```
+    fn iter_summary<'a>(&'a self) -> Iter<'a, AnyString>
+    where
+        Self::Kind: 'a,
+    {
+        self.object_ref()
+            .summary
+            .as_ref()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
+    /// Fetch the summary for the current object as plain text
+    ///
+    /// The HTML in the first summary value is converted with `AnyString::to_plain_text`
+    ///
+    /// ```rust
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let mut note = Note::new();
+    /// note.set_summary("<p>Spoilers &amp; such</p>");
+    ///
+    /// assert_eq!(note.summary_text().as_deref(), Some("Spoilers & such"));
+    /// ```
+    #[cfg(feature = "html-text")]
+    fn summary_text(&self) -> Option<String> {
+        self.object_ref()
+            .summary
+            .as_ref()
+            .and_then(|summary| summary.iter().next())
+            .map(AnyString::to_plain_text)
+    }
+
+    /// Set the summary for the current object
+    ///
+    /// This overwrites the contents of summary
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    ///
+    /// video.set_summary("hi");
+    /// ```
+    fn set_summary<T>(&mut self, summary: T) -> &mut Self
+    where
+        T: Into<AnyString>,
+    {
+        self.object_mut().summary = Some(summary.into().into());
+        self
+    }
+
+    /// Set the summary for the current object, returning it
+    ///
+    /// This is the consuming version of `set_summary`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_summary("hi");
+    /// ```
+    fn with_summary<T>(mut self, summary: T) -> Self
+    where
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_summary(summary);
+        self
+    }
+
+    /// Set many summaries for the current object
+    ///
+    /// This overwrites the contents of summary
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
     /// # use activitystreams::object::Video;
     /// # let mut video = Video::new();
     ///
@@ -696,6 +1197,27 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many summaries for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_summaries`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_summaries(vec![ "hi", "hello"]);
+    /// ```
+    fn with_many_summaries<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyString>,
+        Self: Sized,
+    {
+        self.set_many_summaries(items);
+        self
+    }
+
     /// Add a summary to the current object
     ///
     /// This does not overwrite the contents of summary, only appends an item
@@ -758,6 +1280,120 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Fetch the summaryMap for the current object
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note: Note = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "summaryMap": {
+    ///         "fr": "Divulgâcheurs"
+    ///     }
+    /// }"#)?;
+    ///
+    /// assert_eq!(
+    ///     note.summary_map().and_then(|map| map.get("fr")),
+    ///     Some("Divulgâcheurs")
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn summary_map(&self) -> Option<&RdfLangStringMap> {
+        self.object_ref().summary_map.as_ref()
+    }
+
+    /// Set the summaryMap for the current object
+    ///
+    /// This overwrites the contents of summaryMap
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "Spoilers");
+    ///
+    /// let mut note = Note::new();
+    /// note.set_summary_map(map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_summary_map<T>(&mut self, summary_map: T) -> &mut Self
+    where
+        T: Into<RdfLangStringMap>,
+    {
+        self.object_mut().summary_map = Some(summary_map.into());
+        self
+    }
+
+    /// Set the summaryMap for the current object, returning it
+    ///
+    /// This is the consuming version of `set_summary_map`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, primitives::RdfLangStringMap, prelude::*};
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "Spoilers");
+    ///
+    /// let note = Note::new();
+    /// let note = note.with_summary_map(map);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_summary_map<T>(mut self, summary_map: T) -> Self
+    where
+        T: Into<RdfLangStringMap>,
+        Self: Sized,
+    {
+        self.set_summary_map(summary_map);
+        self
+    }
+
+    /// Take the summaryMap from the current object, leaving nothing
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// if let Some(summary_map) = video.take_summary_map() {
+    ///     println!("{:?}", summary_map);
+    /// }
+    /// ```
+    fn take_summary_map(&mut self) -> Option<RdfLangStringMap> {
+        self.object_mut().summary_map.take()
+    }
+
+    /// Delete the summaryMap from the current object
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::Video, primitives::RdfLangString};
+    /// # let mut video = Video::new();
+    /// # video.set_summary_map(RdfLangString {
+    /// #     value: "Spoilers".to_owned(),
+    /// #     language: "en".parse()?,
+    /// #     direction: None,
+    /// # });
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// assert!(video.summary_map().is_some());
+    /// video.delete_summary_map();
+    /// assert!(video.summary_map().is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn delete_summary_map(&mut self) -> &mut Self {
+        self.object_mut().summary_map = None;
+        self
+    }
+
     /// Fetch the url for the current object
     ///
     /// ```rust
@@ -777,6 +1413,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().url.as_ref()
     }
 
+    /// Iterate over the url for the current object
+    ///
+    /// This yields nothing if url isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for url in video.iter_url() {
+    ///     println!("{:?}", url);
+    /// }
+    /// ```
+    fn iter_url<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.url().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the url for the current object
     ///
     /// This overwrites the contents of url
@@ -799,6 +1456,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the url for the current object, returning it
+    ///
+    /// This is the consuming version of `set_url`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_url(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_url<T>(mut self, url: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_url(url);
+        self
+    }
+
     /// Set many urls for the current object
     ///
     /// This overwrites the contents of url
@@ -826,6 +1506,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many urls for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_urls`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_urls(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_urls<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_urls(items);
+        self
+    }
+
     /// Add a url to the current object
     ///
     /// This does not overwrite the contents of url, only appends an item
@@ -913,6 +1620,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().generator.as_ref()
     }
 
+    /// Iterate over the generator for the current object
+    ///
+    /// This yields nothing if generator isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for generator in video.iter_generator() {
+    ///     println!("{:?}", generator);
+    /// }
+    /// ```
+    fn iter_generator<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.generator().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the generator for the current object
     ///
     /// This overwrites the contents of generator
@@ -935,6 +1663,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the generator for the current object, returning it
+    ///
+    /// This is the consuming version of `set_generator`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_generator(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_generator<T>(mut self, generator: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_generator(generator);
+        self
+    }
+
     /// Set many generators for the current object
     ///
     /// This overwrites the contents of generator
@@ -962,6 +1713,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many generators for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_generators`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_generators(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_generators<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_generators(items);
+        self
+    }
+
     /// Add a generator to the current object
     ///
     /// This does not overwrite the contents of generator, only appends an item
@@ -1049,6 +1827,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().icon.as_ref()
     }
 
+    /// Iterate over the icon for the current object
+    ///
+    /// This yields nothing if icon isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for icon in video.iter_icon() {
+    ///     println!("{:?}", icon);
+    /// }
+    /// ```
+    fn iter_icon<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.icon().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the icon for the current object
     ///
     /// This overwrites the contents of icon
@@ -1071,6 +1870,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the icon for the current object, returning it
+    ///
+    /// This is the consuming version of `set_icon`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_icon(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_icon<T>(mut self, icon: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_icon(icon);
+        self
+    }
+
     /// Set many icons for the current object
     ///
     /// This overwrites the contents of icon
@@ -1098,6 +1920,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many icons for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_icons`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_icons(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_icons<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_icons(items);
+        self
+    }
+
     /// Add a icon to the current object
     ///
     /// This does not overwrite the contents of icon, only appends an item
@@ -1166,7 +2015,28 @@ pub trait ObjectExt: AsObject {
         self
     }
 
-    /// Fetch the image for the current object
+    /// Fetch the image for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// if let Some(image) = video.image() {
+    ///     println!("{:?}", image);
+    /// }
+    /// ```
+    fn image<'a>(&'a self) -> Option<&'a OneOrMany<AnyBase>>
+    where
+        Self::Kind: 'a,
+    {
+        self.object_ref().image.as_ref()
+    }
+
+    /// Iterate over the image for the current object
+    ///
+    /// This yields nothing if image isn't set, so there's no need to match on one or many.
     ///
     /// ```rust
     /// # use activitystreams::object::Video;
@@ -1174,15 +2044,15 @@ pub trait ObjectExt: AsObject {
     /// #
     /// use activitystreams::prelude::*;
     ///
-    /// if let Some(image) = video.image() {
+    /// for image in video.iter_image() {
     ///     println!("{:?}", image);
     /// }
     /// ```
-    fn image<'a>(&'a self) -> Option<&'a OneOrMany<AnyBase>>
+    fn iter_image<'a>(&'a self) -> Iter<'a, AnyBase>
     where
         Self::Kind: 'a,
     {
-        self.object_ref().image.as_ref()
+        self.image().map(OneOrMany::iter).unwrap_or_default()
     }
 
     /// Set the image for the current object
@@ -1207,6 +2077,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the image for the current object, returning it
+    ///
+    /// This is the consuming version of `set_image`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_image(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_image<T>(mut self, image: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_image(image);
+        self
+    }
+
     /// Set many images for the current object
     ///
     /// This overwrites the contents of image
@@ -1234,6 +2127,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many images for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_images`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_images(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_images<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_images(items);
+        self
+    }
+
     /// Add a image to the current object
     ///
     /// This does not overwrite the contents of image, only appends an item
@@ -1321,6 +2241,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().location.as_ref()
     }
 
+    /// Iterate over the location for the current object
+    ///
+    /// This yields nothing if location isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for location in video.iter_location() {
+    ///     println!("{:?}", location);
+    /// }
+    /// ```
+    fn iter_location<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.location().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the location for the current object
     ///
     /// This overwrites the contents of location
@@ -1343,6 +2284,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the location for the current object, returning it
+    ///
+    /// This is the consuming version of `set_location`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_location(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_location<T>(mut self, location: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_location(location);
+        self
+    }
+
     /// Set many locations for the current object
     ///
     /// This overwrites the contents of location
@@ -1370,6 +2334,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many locations for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_locations`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_locations(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_locations<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_locations(items);
+        self
+    }
+
     /// Add a location to the current object
     ///
     /// This does not overwrite the contents of location, only appends an item
@@ -1457,6 +2448,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().tag.as_ref()
     }
 
+    /// Iterate over the tag for the current object
+    ///
+    /// This yields nothing if tag isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for tag in video.iter_tag() {
+    ///     println!("{:?}", tag);
+    /// }
+    /// ```
+    fn iter_tag<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.tag().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the tag for the current object
     ///
     /// This overwrites the contents of tag
@@ -1479,6 +2491,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the tag for the current object, returning it
+    ///
+    /// This is the consuming version of `set_tag`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_tag(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_tag<T>(mut self, tag: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_tag(tag);
+        self
+    }
+
     /// Set many tags for the current object
     ///
     /// This overwrites the contents of tag
@@ -1506,6 +2541,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many tags for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_tags`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_tags(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_tags<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_tags(items);
+        self
+    }
+
     /// Add a tag to the current object
     ///
     /// This does not overwrite the contents of tag, only appends an item
@@ -1574,6 +2636,112 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Fetch the custom emojis tagged on the current object
+    ///
+    /// This returns a `(shortcode, icon_url)` pair, with the surrounding colons removed from the
+    /// shortcode, for each `Emoji` in `tag` that has both a name and an icon.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note: Note = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "content": "hi :blobcat:",
+    ///     "tag": [{
+    ///         "type": "Emoji",
+    ///         "name": ":blobcat:",
+    ///         "icon": {
+    ///             "type": "Image",
+    ///             "url": "https://example.com/emoji/blobcat.png"
+    ///         }
+    ///     }]
+    /// }"#)?;
+    ///
+    /// let emojis = note.custom_emojis();
+    ///
+    /// assert_eq!(emojis.len(), 1);
+    /// assert_eq!(emojis[0].0, "blobcat");
+    /// assert_eq!(emojis[0].1.as_str(), "https://example.com/emoji/blobcat.png");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn custom_emojis(&self) -> Vec<(String, IriString)> {
+        self.object_ref()
+            .tag
+            .iter()
+            .flatten()
+            .filter(|tag| tag.kind_str() == Some("Emoji"))
+            .filter_map(|tag| {
+                let base = tag.as_base()?;
+                let name = base.name()?.as_one()?.as_str().trim_matches(':').to_owned();
+                let icon = base.unparsed().get("icon").and_then(icon_url)?;
+
+                Some((name, icon))
+            })
+            .collect()
+    }
+
+    /// Tag the current object with a custom emoji
+    ///
+    /// An existing `Emoji` tag with the same shortcode is replaced. The shortcode is written to
+    /// the emoji's name surrounded by colons, which is how it appears in content. Since `Emoji`
+    /// isn't part of the ActivityStreams vocabulary, documents should include
+    /// `"Emoji": "toot:Emoji"` in their context.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, object::Note, prelude::*};
+    ///
+    /// let mut note = Note::new();
+    /// note.set_content("hi :blobcat:")
+    ///     .add_custom_emoji("blobcat", iri!("https://example.com/emoji/old.png"))?
+    ///     .add_custom_emoji("blobcat", iri!("https://example.com/emoji/blobcat.png"))?;
+    ///
+    /// assert_eq!(note.tag().map(|tag| tag.iter().count()), Some(1));
+    /// assert_eq!(
+    ///     note.custom_emojis(),
+    ///     vec![("blobcat".to_owned(), iri!("https://example.com/emoji/blobcat.png"))]
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn add_custom_emoji(
+        &mut self,
+        shortcode: &str,
+        icon_url: IriString,
+    ) -> Result<&mut Self, serde_json::Error> {
+        let name = format!(":{}:", shortcode.trim_matches(':'));
+
+        let mut icon = Image::new();
+        icon.set_url(icon_url);
+
+        let mut emoji = Emoji::new();
+        emoji
+            .set_name(name.as_str())
+            .set_icon(icon.into_any_base()?);
+
+        let tags = self
+            .object_mut()
+            .tag
+            .take()
+            .into_iter()
+            .flatten()
+            .filter(|tag| {
+                tag.kind_str() != Some("Emoji")
+                    || tag
+                        .as_base()
+                        .and_then(|base| base.name())
+                        .and_then(|names| names.as_one().map(|existing| existing.as_str() != name))
+                        .unwrap_or(true)
+            })
+            .chain(std::iter::once(emoji.into_any_base()?))
+            .collect();
+
+        self.object_mut().tag = Some(OneOrMany::from_many(tags));
+        Ok(self)
+    }
+
     /// Fetch the start_time for the current object
     ///
     /// ```rust
@@ -1615,6 +2783,28 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the start_time for the current object, returning it
+    ///
+    /// This is the consuming version of `set_start_time`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, datetime};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_start_time(datetime!("2020-04-20T04:20:00Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_start_time(mut self, start_time: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_start_time(start_time);
+        self
+    }
+
     /// Take the start_time from the current object, leaving nothing
     ///
     /// ```rust
@@ -1693,6 +2883,28 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the end_time for the current object, returning it
+    ///
+    /// This is the consuming version of `set_end_time`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, datetime};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_end_time(datetime!("2020-04-20T04:20:00-05:00"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_end_time(mut self, end_time: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_end_time(end_time);
+        self
+    }
+
     /// Take the end_time from the current object, leaving nothing
     ///
     /// ```rust
@@ -1772,6 +2984,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the duration for the current object, returning it
+    ///
+    /// This is the consuming version of `set_duration`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// use time::Duration;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_duration(Duration::minutes(4) + Duration::seconds(20));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_duration(mut self, duration: Duration) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_duration(duration);
+        self
+    }
+
     /// Take the duration from the current object, leaving nothing
     ///
     /// ```rust
@@ -1832,22 +3067,82 @@ pub trait ObjectExt: AsObject {
             .map(|d| d.clone().into_inner())
     }
 
-    /// Set the published for the current object
+    /// Set the published for the current object
+    ///
+    /// This overwrites the contents of published
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, datetime};
+    /// # let mut video = Video::new();
+    ///
+    /// video.set_published(datetime!("2020-04-20T04:20:00Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_published(&mut self, published: OffsetDateTime) -> &mut Self {
+        self.object_mut().published = Some(published.into());
+        self
+    }
+
+    /// Set the published for the current object, returning it
+    ///
+    /// This is the consuming version of `set_published`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, datetime};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_published(datetime!("2020-04-20T04:20:00Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_published(mut self, published: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_published(published);
+        self
+    }
+
+    /// Set the published for the current object to the current time
+    ///
+    /// The timestamp is recorded in UTC, and serializes with a `Z` offset
+    ///
+    /// This overwrites the contents of published
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    ///
+    /// video.set_published_now();
+    /// assert!(video.published().is_some());
+    /// ```
+    fn set_published_now(&mut self) -> &mut Self {
+        self.set_published(OffsetDateTime::now_utc())
+    }
+
+    /// Set the published for the current object to the current time, returning it
     ///
-    /// This overwrites the contents of published
+    /// This is the consuming version of `set_published_now`
     ///
     /// ```rust
-    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::prelude::*;
-    /// # use activitystreams::{object::Video, datetime};
-    /// # let mut video = Video::new();
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
     ///
-    /// video.set_published(datetime!("2020-04-20T04:20:00Z"));
-    /// # Ok(())
-    /// # }
+    /// let video = video.with_published_now();
+    /// assert!(video.published().is_some());
     /// ```
-    fn set_published(&mut self, published: OffsetDateTime) -> &mut Self {
-        self.object_mut().published = Some(published.into());
+    fn with_published_now(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_published_now();
         self
     }
 
@@ -1929,6 +3224,66 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the updated for the current object, returning it
+    ///
+    /// This is the consuming version of `set_updated`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, datetime};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_updated(datetime!("2020-04-20T04:20:00Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_updated(mut self, updated: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_updated(updated);
+        self
+    }
+
+    /// Set the updated for the current object to the current time
+    ///
+    /// The timestamp is recorded in UTC, and serializes with a `Z` offset
+    ///
+    /// This overwrites the contents of updated
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    ///
+    /// video.set_updated_now();
+    /// assert!(video.updated().is_some());
+    /// ```
+    fn set_updated_now(&mut self) -> &mut Self {
+        self.set_updated(OffsetDateTime::now_utc())
+    }
+
+    /// Set the updated for the current object to the current time, returning it
+    ///
+    /// This is the consuming version of `set_updated_now`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Video;
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_updated_now();
+    /// assert!(video.updated().is_some());
+    /// ```
+    fn with_updated_now(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_updated_now();
+        self
+    }
+
     /// Take the updated from the current object, leaving nothing
     ///
     /// ```rust
@@ -1985,6 +3340,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().in_reply_to.as_ref()
     }
 
+    /// Iterate over the in_reply_to for the current object
+    ///
+    /// This yields nothing if in_reply_to isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for in_reply_to in video.iter_in_reply_to() {
+    ///     println!("{:?}", in_reply_to);
+    /// }
+    /// ```
+    fn iter_in_reply_to<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.in_reply_to().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the in_reply_to for the current object
     ///
     /// This overwrites the contents of in_reply_to
@@ -2007,6 +3383,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the in_reply_to for the current object, returning it
+    ///
+    /// This is the consuming version of `set_in_reply_to`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_in_reply_to(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_in_reply_to<T>(mut self, in_reply_to: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_in_reply_to(in_reply_to);
+        self
+    }
+
     /// Set many in_reply_tos for the current object
     ///
     /// This overwrites the contents of in_reply_to
@@ -2034,6 +3433,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many in_reply_tos for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_in_reply_tos`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_in_reply_tos(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_in_reply_tos<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_in_reply_tos(items);
+        self
+    }
+
     /// Add a in_reply_to to the current object
     ///
     /// This does not overwrite the contents of in_reply_to, only appends an item
@@ -2121,6 +3547,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().replies.as_ref()
     }
 
+    /// Iterate over the replies for the current object
+    ///
+    /// This yields nothing if replies isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for replies in video.iter_replies() {
+    ///     println!("{:?}", replies);
+    /// }
+    /// ```
+    fn iter_replies<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.replies().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the replies for the current object
     ///
     /// This overwrites the contents of replies
@@ -2143,6 +3590,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the replies for the current object, returning it
+    ///
+    /// This is the consuming version of `set_reply`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_reply(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_reply<T>(mut self, replies: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_reply(replies);
+        self
+    }
+
     /// Set many replies for the current object
     ///
     /// This overwrites the contents of replies
@@ -2170,6 +3640,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many replies for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_replies`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_replies(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_replies<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_replies(items);
+        self
+    }
+
     /// Add a replies to the current object
     ///
     /// This does not overwrite the contents of replies, only appends an item
@@ -2238,6 +3735,94 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Fetch the replies for the current object as an embedded Collection
+    ///
+    /// This returns `None` if replies is missing, holds more than one value, is only referenced by
+    /// id, or is some kind other than `Collection`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::Note, prelude::*};
+    ///
+    /// let note: Note = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "replies": {
+    ///         "id": "https://example.com/notes/1/replies",
+    ///         "type": "Collection",
+    ///         "totalItems": 4,
+    ///         "first": "https://example.com/notes/1/replies?page=true"
+    ///     }
+    /// }"#)?;
+    ///
+    /// let replies = note.replies_collection()?.unwrap();
+    ///
+    /// assert_eq!(replies.total_items(), Some(4));
+    /// assert!(replies.first().is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn replies_collection(&self) -> Result<Option<UnorderedCollection>, serde_json::Error> {
+        match self.replies().and_then(|replies| replies.as_one()) {
+            Some(replies) => replies.downcast_kind(),
+            None => Ok(None),
+        }
+    }
+
+    /// Set the replies for the current object to an embedded Collection
+    ///
+    /// This overwrites the contents of replies
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::UnorderedCollection, object::Video, prelude::*};
+    /// # let mut video = Video::new();
+    ///
+    /// let mut replies = UnorderedCollection::new();
+    /// replies.set_total_items(0u64);
+    ///
+    /// video.set_replies_collection(replies)?;
+    ///
+    /// assert!(video.replies_collection()?.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_replies_collection(
+        &mut self,
+        replies: UnorderedCollection,
+    ) -> Result<&mut Self, serde_json::Error> {
+        self.object_mut().replies = Some(replies.into_any_base()?.into());
+        Ok(self)
+    }
+
+    /// Set the replies for the current object to an embedded Collection, returning it
+    ///
+    /// This is the consuming version of `set_replies_collection`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{collection::UnorderedCollection, object::Video, prelude::*};
+    /// # let video = Video::new();
+    ///
+    /// let mut replies = UnorderedCollection::new();
+    /// replies.set_total_items(0u64);
+    ///
+    /// let video = video.with_replies_collection(replies)?;
+    ///
+    /// assert!(video.replies_collection()?.is_some());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_replies_collection(
+        mut self,
+        replies: UnorderedCollection,
+    ) -> Result<Self, serde_json::Error>
+    where
+        Self: Sized,
+    {
+        self.set_replies_collection(replies)?;
+        Ok(self)
+    }
+
     /// Fetch the to for the current object
     ///
     /// ```rust
@@ -2254,10 +3839,76 @@ pub trait ObjectExt: AsObject {
     where
         Self::Kind: 'a,
     {
-        self.object_ref().to.as_ref()
+        self.object_ref().to.as_ref()
+    }
+
+    /// Iterate over the to for the current object
+    ///
+    /// This yields nothing if to isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for to in video.iter_to() {
+    ///     println!("{:?}", to);
+    /// }
+    /// ```
+    fn iter_to<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.to().map(OneOrMany::iter).unwrap_or_default()
+    }
+
+    /// Set the to for the current object
+    ///
+    /// This overwrites the contents of to
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let mut video = Video::new();
+    ///
+    /// video.set_to(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_to<T>(&mut self, to: T) -> &mut Self
+    where
+        T: Into<AnyBase>,
+    {
+        self.object_mut().to = Some(to.into().into());
+        self
+    }
+
+    /// Set the to for the current object, returning it
+    ///
+    /// This is the consuming version of `set_to`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_to(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_to<T>(mut self, to: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_to(to);
+        self
     }
 
-    /// Set the to for the current object
+    /// Set many tos for the current object
     ///
     /// This overwrites the contents of to
     ///
@@ -2267,42 +3918,47 @@ pub trait ObjectExt: AsObject {
     /// # use activitystreams::{object::Video, iri};
     /// # let mut video = Video::new();
     ///
-    /// video.set_to(iri!("https://example.com"));
+    /// video.set_many_tos(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
     /// # Ok(())
     /// # }
     /// ```
-    fn set_to<T>(&mut self, to: T) -> &mut Self
+    fn set_many_tos<I, T>(&mut self, items: I) -> &mut Self
     where
+        I: IntoIterator<Item = T>,
         T: Into<AnyBase>,
     {
-        self.object_mut().to = Some(to.into().into());
+        let v: Vec<_> = items.into_iter().map(Into::into).collect();
+        self.object_mut().to = Some(v.into());
         self
     }
 
-    /// Set many tos for the current object
+    /// Set many tos for the current object, returning it
     ///
-    /// This overwrites the contents of to
+    /// This is the consuming version of `set_many_tos`
     ///
     /// ```rust
     /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::prelude::*;
     /// # use activitystreams::{object::Video, iri};
-    /// # let mut video = Video::new();
+    /// # let video = Video::new();
     ///
-    /// video.set_many_tos(vec![
+    /// let video = video.with_many_tos(vec![
     ///     iri!("https://example.com/one"),
     ///     iri!("https://example.com/two"),
     /// ]);
     /// # Ok(())
     /// # }
     /// ```
-    fn set_many_tos<I, T>(&mut self, items: I) -> &mut Self
+    fn with_many_tos<I, T>(mut self, items: I) -> Self
     where
         I: IntoIterator<Item = T>,
         T: Into<AnyBase>,
+        Self: Sized,
     {
-        let v: Vec<_> = items.into_iter().map(Into::into).collect();
-        self.object_mut().to = Some(v.into());
+        self.set_many_tos(items);
         self
     }
 
@@ -2393,6 +4049,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().bto.as_ref()
     }
 
+    /// Iterate over the bto for the current object
+    ///
+    /// This yields nothing if bto isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for bto in video.iter_bto() {
+    ///     println!("{:?}", bto);
+    /// }
+    /// ```
+    fn iter_bto<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.bto().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the bto for the current object
     ///
     /// This overwrites the contents of bto
@@ -2415,6 +4092,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the bto for the current object, returning it
+    ///
+    /// This is the consuming version of `set_bto`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_bto(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_bto<T>(mut self, bto: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_bto(bto);
+        self
+    }
+
     /// Set many btos for the current object
     ///
     /// This overwrites the contents of bto
@@ -2442,6 +4142,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many btos for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_btos`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_btos(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_btos<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_btos(items);
+        self
+    }
+
     /// Add a bto to the current object
     ///
     /// This does not overwrite the contents of bto, only appends an item
@@ -2529,6 +4256,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().cc.as_ref()
     }
 
+    /// Iterate over the cc for the current object
+    ///
+    /// This yields nothing if cc isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for cc in video.iter_cc() {
+    ///     println!("{:?}", cc);
+    /// }
+    /// ```
+    fn iter_cc<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.cc().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the cc for the current object
     ///
     /// This overwrites the contents of cc
@@ -2551,6 +4299,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the cc for the current object, returning it
+    ///
+    /// This is the consuming version of `set_cc`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_cc(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_cc<T>(mut self, cc: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_cc(cc);
+        self
+    }
+
     /// Set many ccs for the current object
     ///
     /// This overwrites the contents of cc
@@ -2578,6 +4349,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many ccs for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_ccs`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_ccs(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_ccs<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_ccs(items);
+        self
+    }
+
     /// Add a cc to the current object
     ///
     /// This does not overwrite the contents of cc, only appends an item
@@ -2665,6 +4463,27 @@ pub trait ObjectExt: AsObject {
         self.object_ref().bcc.as_ref()
     }
 
+    /// Iterate over the bcc for the current object
+    ///
+    /// This yields nothing if bcc isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Video;
+    /// # let mut video = Video::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for bcc in video.iter_bcc() {
+    ///     println!("{:?}", bcc);
+    /// }
+    /// ```
+    fn iter_bcc<'a>(&'a self) -> Iter<'a, AnyBase>
+    where
+        Self::Kind: 'a,
+    {
+        self.bcc().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the bcc for the current object
     ///
     /// This overwrites the contents of bcc
@@ -2687,6 +4506,29 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set the bcc for the current object, returning it
+    ///
+    /// This is the consuming version of `set_bcc`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_bcc(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_bcc<T>(mut self, bcc: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_bcc(bcc);
+        self
+    }
+
     /// Set many bcc for the current object
     ///
     /// This overwrites the contents of bcc
@@ -2714,6 +4556,33 @@ pub trait ObjectExt: AsObject {
         self
     }
 
+    /// Set many bcc for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_bcc`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Video, iri};
+    /// # let video = Video::new();
+    ///
+    /// let video = video.with_many_bcc(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_bcc<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_bcc(items);
+        self
+    }
+
     /// Add a bcc to the current object
     ///
     /// This does not overwrite the contents of bcc, only appends an item
@@ -2781,6 +4650,73 @@ pub trait ObjectExt: AsObject {
         self.object_mut().bcc = None;
         self
     }
+
+    /// Shorten each content and summary of the current object to at most `max_bytes` bytes
+    ///
+    /// Strings are never cut in the middle of a character, an HTML tag, or an HTML entity. See
+    /// `AnyString::truncate` for details.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Note;
+    /// # let mut note = Note::new();
+    /// use activitystreams::prelude::*;
+    ///
+    /// note.set_content("<p>hello, world</p>").set_summary("cw: greetings");
+    ///
+    /// note.truncate_content(8);
+    ///
+    /// assert_eq!(note.content().unwrap().as_single_xsd_string(), Some("<p>hello"));
+    /// assert_eq!(note.summary().unwrap().as_single_xsd_string(), Some("cw: gree"));
+    /// ```
+    fn truncate_content(&mut self, max_bytes: usize) -> &mut Self {
+        let object = self.object_mut();
+
+        for string in object
+            .content
+            .iter_mut()
+            .chain(object.summary.iter_mut())
+            .flat_map(|strings| strings.iter_mut())
+        {
+            string.truncate(max_bytes);
+        }
+
+        self
+    }
+
+    /// Wrap the current object in an ApObject, adding the ActivityPub-specific properties
+    ///
+    /// This works the same way for every kind of Object, including Activities and Collections.
+    /// Actors are extended with `Actor::full` instead, since ActivityPub requires them to have an
+    /// inbox. Links have no ActivityPub properties, so there's nothing to extend them with.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     activity::Create,
+    ///     collection::OrderedCollection,
+    ///     iri,
+    ///     object::{ApObject, Video},
+    ///     prelude::*,
+    /// };
+    ///
+    /// let video: ApObject<Video> = Video::new().full();
+    /// let outbox: ApObject<OrderedCollection> = OrderedCollection::new().full();
+    /// let create: ApObject<Create> = Create::new(
+    ///     iri!("https://example.com/users/asonix"),
+    ///     iri!("https://example.com/videos/1"),
+    /// )
+    /// .full();
+    ///
+    /// let create = create.with_shares(iri!("https://example.com/activities/1/shares"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn full(self) -> ApObject<Self>
+    where
+        Self: Sized,
+    {
+        ApObject::new(self)
+    }
 }
 
 /// Helper methods for interacting with ActivityPub Object types
@@ -2801,7 +4737,7 @@ pub trait ApObjectExt: AsApObject {
     ///     println!("{:?}", shares);
     /// }
     /// ```
-    fn shares<'a>(&'a self) -> Option<&'a IriString>
+    fn shares<'a>(&'a self) -> Option<&'a AnyBase>
     where
         Self::Inner: 'a,
     {
@@ -2823,8 +4759,35 @@ pub trait ApObjectExt: AsApObject {
     /// # Ok(())
     /// # }
     /// ```
-    fn set_shares(&mut self, shares: IriString) -> &mut Self {
-        self.ap_object_mut().shares = Some(shares);
+    fn set_shares<T>(&mut self, shares: T) -> &mut Self
+    where
+        T: Into<AnyBase>,
+    {
+        self.ap_object_mut().shares = Some(shares.into());
+        self
+    }
+
+    /// Set the shares for the current object, returning it
+    ///
+    /// This is the consuming version of `set_shares`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::{ApObject, Video};
+    /// # let video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let video = video.with_shares(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_shares<T>(mut self, shares: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_shares(shares);
         self
     }
 
@@ -2840,7 +4803,7 @@ pub trait ApObjectExt: AsApObject {
     ///     println!("{:?}", shares);
     /// }
     /// ```
-    fn take_shares(&mut self) -> Option<IriString> {
+    fn take_shares(&mut self) -> Option<AnyBase> {
         self.ap_object_mut().shares.take()
     }
 
@@ -2874,7 +4837,7 @@ pub trait ApObjectExt: AsApObject {
     ///     println!("{:?}", likes);
     /// }
     /// ```
-    fn likes<'a>(&'a self) -> Option<&'a IriString>
+    fn likes<'a>(&'a self) -> Option<&'a AnyBase>
     where
         Self::Inner: 'a,
     {
@@ -2896,8 +4859,35 @@ pub trait ApObjectExt: AsApObject {
     /// # Ok(())
     /// # }
     /// ```
-    fn set_likes(&mut self, likes: IriString) -> &mut Self {
-        self.ap_object_mut().likes = Some(likes);
+    fn set_likes<T>(&mut self, likes: T) -> &mut Self
+    where
+        T: Into<AnyBase>,
+    {
+        self.ap_object_mut().likes = Some(likes.into());
+        self
+    }
+
+    /// Set the likes for the current object, returning it
+    ///
+    /// This is the consuming version of `set_likes`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::{ApObject, Video};
+    /// # let video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let video = video.with_likes(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_likes<T>(mut self, likes: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_likes(likes);
         self
     }
 
@@ -2913,7 +4903,7 @@ pub trait ApObjectExt: AsApObject {
     ///     println!("{:?}", likes);
     /// }
     /// ```
-    fn take_likes(&mut self) -> Option<IriString> {
+    fn take_likes(&mut self) -> Option<AnyBase> {
         self.ap_object_mut().likes.take()
     }
 
@@ -2935,6 +4925,56 @@ pub trait ApObjectExt: AsApObject {
         self
     }
 
+    /// Fetch the like, share, and reply counts for the current object
+    ///
+    /// These are read from the `totalItems` of the embedded likes, shares, and replies
+    /// collections, as published by Mastodon and other servers.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::{ApObject, InteractionCounts, Note}, prelude::*};
+    ///
+    /// let note: ApObject<Note> = serde_json::from_str(r#"{
+    ///     "type": "Note",
+    ///     "id": "https://example.com/notes/1",
+    ///     "likes": {
+    ///         "id": "https://example.com/notes/1/likes",
+    ///         "type": "Collection",
+    ///         "totalItems": 12
+    ///     },
+    ///     "shares": {
+    ///         "id": "https://example.com/notes/1/shares",
+    ///         "type": "Collection",
+    ///         "totalItems": "3"
+    ///     },
+    ///     "replies": "https://example.com/notes/1/replies"
+    /// }"#)?;
+    ///
+    /// assert_eq!(
+    ///     note.interaction_counts(),
+    ///     InteractionCounts {
+    ///         likes: Some(12),
+    ///         shares: Some(3),
+    ///         replies: None,
+    ///     }
+    /// );
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn interaction_counts(&self) -> InteractionCounts
+    where
+        Self: AsObject + Sized,
+    {
+        InteractionCounts {
+            likes: self.likes().and_then(total_items),
+            shares: self.shares().and_then(total_items),
+            replies: self
+                .replies()
+                .and_then(|replies| replies.as_one())
+                .and_then(total_items),
+        }
+    }
+
     /// Fetch the source for the current object
     ///
     /// ```rust
@@ -2977,6 +5017,30 @@ pub trait ApObjectExt: AsApObject {
         self
     }
 
+    /// Set the source for the current object, returning it
+    ///
+    /// This is the consuming version of `set_source`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::{ApObject, Video}, iri};
+    /// # let video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let video = video.with_source(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_source<T>(mut self, source: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_source(source);
+        self
+    }
+
     /// Take the source from the current object, leaving nothing
     ///
     /// ```rust
@@ -3033,6 +5097,31 @@ pub trait ApObjectExt: AsApObject {
             .map(|o| o.as_ref())
     }
 
+    /// Iterate over the upload_media for the current object
+    ///
+    /// This yields nothing if upload_media isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{ApObject, Video};
+    /// # let mut video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for upload_media in video.iter_upload_media() {
+    ///     println!("{:?}", upload_media);
+    /// }
+    /// ```
+    fn iter_upload_media<'a>(&'a self) -> Iter<'a, IriString>
+    where
+        Self::Inner: 'a,
+    {
+        self.ap_object_ref()
+            .upload_media
+            .as_ref()
+            .map(OneOrMany::iter)
+            .unwrap_or_default()
+    }
+
     /// Set the upload_media for the current object
     ///
     /// This overwrites the contents of upload_media
@@ -3053,6 +5142,29 @@ pub trait ApObjectExt: AsApObject {
         self
     }
 
+    /// Set the upload_media for the current object, returning it
+    ///
+    /// This is the consuming version of `set_upload_media`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::{ApObject, Video};
+    /// # let video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let video = video.with_upload_media(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_upload_media(mut self, upload_media: IriString) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_upload_media(upload_media);
+        self
+    }
+
     /// Set many upload_medias for the current object
     ///
     /// This overwrites the contents of upload_media
@@ -3081,6 +5193,34 @@ pub trait ApObjectExt: AsApObject {
         self
     }
 
+    /// Set many upload_medias for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_upload_medias`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::{ApObject, Video};
+    /// # let video = ApObject::new(Video::new());
+    /// #
+    /// use activitystreams::{prelude::*, iri};
+    ///
+    /// let video = video.with_many_upload_medias(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_upload_medias<I, U>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = U>,
+        U: Into<IriString>,
+        Self: Sized,
+    {
+        self.set_many_upload_medias(items);
+        self
+    }
+
     /// Add a upload_media to the current object
     ///
     /// This does not overwrite the contents of upload_media, only appends an item
@@ -3164,12 +5304,14 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn accuracy(&self) -> Option<f64> {
-        self.place_ref().accuracy
+        self.place_ref().accuracy.map(XsdFloat::into_inner)
     }
 
     /// Set the accuracy for the current object
     ///
-    /// This overwrites the contents of accuracy
+    /// This overwrites the contents of accuracy. The accuracy should be between 0 and 100, inclusive; values
+    /// outside that range are kept, but are reported by `validate` and can't be read back in.
+    /// Use `try_set_accuracy` to reject them up front.
     ///
     /// ```rust
     /// # use activitystreams::object::Place;
@@ -3183,10 +5325,54 @@ pub trait PlaceExt: AsPlace {
     where
         T: Into<f64>,
     {
-        self.place_mut().accuracy = Some(float.into());
+        self.place_mut().accuracy = Some(XsdFloat::new(float.into()));
+        self
+    }
+
+    /// Set the accuracy for the current object, returning it
+    ///
+    /// This is the consuming version of `set_accuracy`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let place = place.with_accuracy(5f64);
+    /// ```
+    fn with_accuracy<T>(mut self, float: T) -> Self
+    where
+        T: Into<f64>,
+        Self: Sized,
+    {
+        self.set_accuracy(float);
         self
     }
 
+    /// Set the accuracy for the current object, checking that it's between 0 and 100, inclusive
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::Place;
+    /// # let mut place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// place.try_set_accuracy(5f64)?;
+    /// assert!(place.try_set_accuracy(150f64).is_err());
+    /// assert_eq!(place.accuracy(), Some(5f64));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_set_accuracy<T>(&mut self, float: T) -> Result<&mut Self, XsdFloatError>
+    where
+        T: Into<f64>,
+    {
+        self.place_mut().accuracy = Some(XsdFloat::bounded(float.into(), 0.0, 100.0)?);
+        Ok(self)
+    }
+
     /// Take the accuracy of the current object, leaving nothing
     ///
     /// ```rust
@@ -3200,7 +5386,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn take_accuracy(&mut self) -> Option<f64> {
-        self.place_mut().accuracy.take()
+        self.place_mut().accuracy.take().map(XsdFloat::into_inner)
     }
 
     /// Delete the accuracy from the current object
@@ -3234,7 +5420,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn altitude(&self) -> Option<f64> {
-        self.place_ref().altitude
+        self.place_ref().altitude.map(XsdFloat::into_inner)
     }
 
     /// Set the altitude for the current object
@@ -3253,7 +5439,28 @@ pub trait PlaceExt: AsPlace {
     where
         T: Into<f64>,
     {
-        self.place_mut().altitude = Some(float.into());
+        self.place_mut().altitude = Some(XsdFloat::new(float.into()));
+        self
+    }
+
+    /// Set the altitude for the current object, returning it
+    ///
+    /// This is the consuming version of `set_altitude`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let place = place.with_altitude(5f64);
+    /// ```
+    fn with_altitude<T>(mut self, float: T) -> Self
+    where
+        T: Into<f64>,
+        Self: Sized,
+    {
+        self.set_altitude(float);
         self
     }
 
@@ -3270,7 +5477,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn take_altitude(&mut self) -> Option<f64> {
-        self.place_mut().altitude.take()
+        self.place_mut().altitude.take().map(XsdFloat::into_inner)
     }
 
     /// Delete the altitude from the current object
@@ -3304,12 +5511,14 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn latitude(&self) -> Option<f64> {
-        self.place_ref().latitude
+        self.place_ref().latitude.map(XsdFloat::into_inner)
     }
 
     /// Set the latitude for the current object
     ///
-    /// This overwrites the contents of latitude
+    /// This overwrites the contents of latitude. The latitude should be between -90 and 90, inclusive; values
+    /// outside that range are kept, but are reported by `validate` and can't be read back in.
+    /// Use `try_set_latitude` to reject them up front.
     ///
     /// ```rust
     /// # use activitystreams::object::Place;
@@ -3323,10 +5532,54 @@ pub trait PlaceExt: AsPlace {
     where
         T: Into<f64>,
     {
-        self.place_mut().latitude = Some(float.into());
+        self.place_mut().latitude = Some(XsdFloat::new(float.into()));
+        self
+    }
+
+    /// Set the latitude for the current object, returning it
+    ///
+    /// This is the consuming version of `set_latitude`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let place = place.with_latitude(5f64);
+    /// ```
+    fn with_latitude<T>(mut self, float: T) -> Self
+    where
+        T: Into<f64>,
+        Self: Sized,
+    {
+        self.set_latitude(float);
         self
     }
 
+    /// Set the latitude for the current object, checking that it's between -90 and 90, inclusive
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::Place;
+    /// # let mut place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// place.try_set_latitude(5f64)?;
+    /// assert!(place.try_set_latitude(91f64).is_err());
+    /// assert_eq!(place.latitude(), Some(5f64));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_set_latitude<T>(&mut self, float: T) -> Result<&mut Self, XsdFloatError>
+    where
+        T: Into<f64>,
+    {
+        self.place_mut().latitude = Some(XsdFloat::bounded(float.into(), -90.0, 90.0)?);
+        Ok(self)
+    }
+
     /// Take the latitude of the current object, leaving nothing
     ///
     /// ```rust
@@ -3340,7 +5593,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn take_latitude(&mut self) -> Option<f64> {
-        self.place_mut().latitude.take()
+        self.place_mut().latitude.take().map(XsdFloat::into_inner)
     }
 
     /// Delete the latitude from the current object
@@ -3374,12 +5627,14 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn longitude(&self) -> Option<f64> {
-        self.place_ref().longitude
+        self.place_ref().longitude.map(XsdFloat::into_inner)
     }
 
     /// Set the longitude for the current object
     ///
-    /// This overwrites the contents of longitude
+    /// This overwrites the contents of longitude. The longitude should be between -180 and 180, inclusive; values
+    /// outside that range are kept, but are reported by `validate` and can't be read back in.
+    /// Use `try_set_longitude` to reject them up front.
     ///
     /// ```rust
     /// # use activitystreams::object::Place;
@@ -3393,10 +5648,54 @@ pub trait PlaceExt: AsPlace {
     where
         T: Into<f64>,
     {
-        self.place_mut().longitude = Some(float.into());
+        self.place_mut().longitude = Some(XsdFloat::new(float.into()));
+        self
+    }
+
+    /// Set the longitude for the current object, returning it
+    ///
+    /// This is the consuming version of `set_longitude`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let place = place.with_longitude(5f64);
+    /// ```
+    fn with_longitude<T>(mut self, float: T) -> Self
+    where
+        T: Into<f64>,
+        Self: Sized,
+    {
+        self.set_longitude(float);
         self
     }
 
+    /// Set the longitude for the current object, checking that it's between -180 and 180, inclusive
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::object::Place;
+    /// # let mut place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// place.try_set_longitude(5f64)?;
+    /// assert!(place.try_set_longitude(181f64).is_err());
+    /// assert_eq!(place.longitude(), Some(5f64));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn try_set_longitude<T>(&mut self, float: T) -> Result<&mut Self, XsdFloatError>
+    where
+        T: Into<f64>,
+    {
+        self.place_mut().longitude = Some(XsdFloat::bounded(float.into(), -180.0, 180.0)?);
+        Ok(self)
+    }
+
     /// Take the longitude of the current object, leaving nothing
     ///
     /// ```rust
@@ -3410,7 +5709,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn take_longitude(&mut self) -> Option<f64> {
-        self.place_mut().longitude.take()
+        self.place_mut().longitude.take().map(XsdFloat::into_inner)
     }
 
     /// Delete the longitude from the current object
@@ -3444,7 +5743,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn radius(&self) -> Option<f64> {
-        self.place_ref().radius
+        self.place_ref().radius.map(XsdFloat::into_inner)
     }
 
     /// Set the radius for the current object
@@ -3463,7 +5762,28 @@ pub trait PlaceExt: AsPlace {
     where
         T: Into<f64>,
     {
-        self.place_mut().radius = Some(float.into());
+        self.place_mut().radius = Some(XsdFloat::new(float.into()));
+        self
+    }
+
+    /// Set the radius for the current object, returning it
+    ///
+    /// This is the consuming version of `set_radius`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let place = place.with_radius(5f64);
+    /// ```
+    fn with_radius<T>(mut self, float: T) -> Self
+    where
+        T: Into<f64>,
+        Self: Sized,
+    {
+        self.set_radius(float);
         self
     }
 
@@ -3480,7 +5800,7 @@ pub trait PlaceExt: AsPlace {
     /// }
     /// ```
     fn take_radius(&mut self) -> Option<f64> {
-        self.place_mut().radius.take()
+        self.place_mut().radius.take().map(XsdFloat::into_inner)
     }
 
     /// Delete the radius from the current object
@@ -3537,6 +5857,27 @@ pub trait PlaceExt: AsPlace {
         self
     }
 
+    /// Set the units for the current object, returning it
+    ///
+    /// This is the consuming version of `set_units`
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Place;
+    /// # let place = Place::new();
+    /// #
+    /// use activitystreams::{prelude::*, primitives::Unit};
+    ///
+    /// let place = place.with_units(Unit::centimeters());
+    /// ```
+    fn with_units<T>(mut self, units: T) -> Self
+    where
+        T: Into<Unit>,
+        Self: Sized,
+    {
+        self.set_units(units);
+        self
+    }
+
     /// Take the units of the current object, leaving nothing
     ///
     /// ```rust
@@ -3617,6 +5958,30 @@ pub trait ProfileExt: AsProfile {
         self
     }
 
+    /// Set the described object for the current object, returning it
+    ///
+    /// This is the consuming version of `set_describes`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::Profile, iri};
+    /// # let profile = Profile::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let profile = profile.with_describes(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_describes<T>(mut self, describes: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_describes(describes);
+        self
+    }
+
     /// Take the described object from the current object, leaving nothing
     ///
     /// ```rust
@@ -3697,6 +6062,30 @@ pub trait RelationshipExt: AsRelationship {
         self
     }
 
+    /// Set the subject for the current object, returning it
+    ///
+    /// This is the consuming version of `set_subject`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// # use activitystreams::{object::Relationship, iri};
+    /// # let relationship = Relationship::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// let relationship = relationship.with_subject(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_subject<T>(mut self, subject: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_subject(subject);
+        self
+    }
+
     /// Take the subject from the current object, leaving nothing
     ///
     /// ```rust
@@ -3726,12 +6115,30 @@ pub trait RelationshipExt: AsRelationship {
     /// relationship.delete_subject();
     /// assert!(relationship.subject().is_none());
     /// ```
-    fn delete_subject(&mut self) -> &mut Self {
-        self.relationship_mut().subject = None;
-        self
+    fn delete_subject(&mut self) -> &mut Self {
+        self.relationship_mut().subject = None;
+        self
+    }
+
+    /// Fetch the object for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Relationship;
+    /// # let mut relationship = Relationship::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// if let Some(object) = relationship.object() {
+    ///     println!("{:?}", object);
+    /// }
+    /// ```
+    fn object(&self) -> Option<&OneOrMany<AnyBase>> {
+        self.relationship_ref().object.as_ref()
     }
 
-    /// Fetch the object for the current object
+    /// Iterate over the object for the current object
+    ///
+    /// This yields nothing if object isn't set, so there's no need to match on one or many.
     ///
     /// ```rust
     /// # use activitystreams::object::Relationship;
@@ -3739,12 +6146,12 @@ pub trait RelationshipExt: AsRelationship {
     /// #
     /// use activitystreams::prelude::*;
     ///
-    /// if let Some(object) = relationship.object() {
+    /// for object in relationship.iter_object() {
     ///     println!("{:?}", object);
     /// }
     /// ```
-    fn object(&self) -> Option<&OneOrMany<AnyBase>> {
-        self.relationship_ref().object.as_ref()
+    fn iter_object(&self) -> Iter<'_, AnyBase> {
+        self.object().map(OneOrMany::iter).unwrap_or_default()
     }
 
     /// Set the object for the current object
@@ -3769,6 +6176,29 @@ pub trait RelationshipExt: AsRelationship {
         self
     }
 
+    /// Set the object for the current object, returning it
+    ///
+    /// This is the consuming version of `set_object`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Relationship, iri};
+    /// # let relationship = Relationship::new();
+    ///
+    /// let relationship = relationship.with_object(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_object<T>(mut self, object: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_object(object);
+        self
+    }
+
     /// Set many objects for the current object
     ///
     /// This overwrites the contents of object
@@ -3796,6 +6226,33 @@ pub trait RelationshipExt: AsRelationship {
         self
     }
 
+    /// Set many objects for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_objects`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Relationship, iri};
+    /// # let relationship = Relationship::new();
+    ///
+    /// let relationship = relationship.with_many_objects(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_objects<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_objects(items);
+        self
+    }
+
     /// Add a object to the current object
     ///
     /// This does not overwrite the contents of object, only appends an item
@@ -3880,6 +6337,24 @@ pub trait RelationshipExt: AsRelationship {
         self.relationship_ref().relationship.as_ref()
     }
 
+    /// Iterate over the relationship for the current object
+    ///
+    /// This yields nothing if relationship isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Relationship;
+    /// # let mut relationship = Relationship::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for relationship in relationship.iter_relationship() {
+    ///     println!("{:?}", relationship);
+    /// }
+    /// ```
+    fn iter_relationship(&self) -> Iter<'_, AnyBase> {
+        self.relationship().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the relationship for the current object
     ///
     /// This overwrites the contents of relationship
@@ -3902,6 +6377,29 @@ pub trait RelationshipExt: AsRelationship {
         self
     }
 
+    /// Set the relationship for the current object, returning it
+    ///
+    /// This is the consuming version of `set_relationship`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Relationship, iri};
+    /// # let relationship = Relationship::new();
+    ///
+    /// let relationship = relationship.with_relationship(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_relationship<T>(mut self, relationship: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_relationship(relationship);
+        self
+    }
+
     /// Set many relationships for the current object
     ///
     /// This overwrites the contents of relationship
@@ -3929,6 +6427,33 @@ pub trait RelationshipExt: AsRelationship {
         self
     }
 
+    /// Set many relationships for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_relationships`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Relationship, iri};
+    /// # let relationship = Relationship::new();
+    ///
+    /// let relationship = relationship.with_many_relationships(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_relationships<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_relationships(items);
+        self
+    }
+
     /// Add a relationship to the current object
     ///
     /// This does not overwrite the contents of relationship, only appends an item
@@ -4020,6 +6545,24 @@ pub trait TombstoneExt: AsTombstone {
         self.tombstone_ref().former_type.as_ref()
     }
 
+    /// Iterate over the former_type for the current object
+    ///
+    /// This yields nothing if former_type isn't set, so there's no need to match on one or many.
+    ///
+    /// ```rust
+    /// # use activitystreams::object::Tombstone;
+    /// # let mut tombstone = Tombstone::new();
+    /// #
+    /// use activitystreams::prelude::*;
+    ///
+    /// for former_type in tombstone.iter_former_type() {
+    ///     println!("{:?}", former_type);
+    /// }
+    /// ```
+    fn iter_former_type(&self) -> Iter<'_, AnyBase> {
+        self.former_type().map(OneOrMany::iter).unwrap_or_default()
+    }
+
     /// Set the former_type for the current object
     ///
     /// This overwrites the contents of former_type
@@ -4042,6 +6585,29 @@ pub trait TombstoneExt: AsTombstone {
         self
     }
 
+    /// Set the former_type for the current object, returning it
+    ///
+    /// This is the consuming version of `set_former_type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Tombstone, iri};
+    /// # let tombstone = Tombstone::new();
+    ///
+    /// let tombstone = tombstone.with_former_type(iri!("https://example.com"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_former_type<T>(mut self, former_type: T) -> Self
+    where
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_former_type(former_type);
+        self
+    }
+
     /// Set many former_types for the current object
     ///
     /// This overwrites the contents of former_type
@@ -4069,6 +6635,33 @@ pub trait TombstoneExt: AsTombstone {
         self
     }
 
+    /// Set many former_types for the current object, returning it
+    ///
+    /// This is the consuming version of `set_many_former_types`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Tombstone, iri};
+    /// # let tombstone = Tombstone::new();
+    ///
+    /// let tombstone = tombstone.with_many_former_types(vec![
+    ///     iri!("https://example.com/one"),
+    ///     iri!("https://example.com/two"),
+    /// ]);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_many_former_types<I, T>(mut self, items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<AnyBase>,
+        Self: Sized,
+    {
+        self.set_many_former_types(items);
+        self
+    }
+
     /// Add a former_type to the current object
     ///
     /// This does not overwrite the contents of former_type, only appends an item
@@ -4175,6 +6768,66 @@ pub trait TombstoneExt: AsTombstone {
         self
     }
 
+    /// Set the deleted for the current object, returning it
+    ///
+    /// This is the consuming version of `set_deleted`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::{object::Tombstone, datetime};
+    /// # let tombstone = Tombstone::new();
+    ///
+    /// let tombstone = tombstone.with_deleted(datetime!("2020-04-20T04:20:00Z"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_deleted(mut self, deleted: OffsetDateTime) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_deleted(deleted);
+        self
+    }
+
+    /// Set the deleted for the current object to the current time
+    ///
+    /// The timestamp is recorded in UTC, and serializes with a `Z` offset
+    ///
+    /// This overwrites the contents of deleted
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Tombstone;
+    /// # let mut tombstone = Tombstone::new();
+    ///
+    /// tombstone.set_deleted_now();
+    /// assert!(tombstone.deleted().is_some());
+    /// ```
+    fn set_deleted_now(&mut self) -> &mut Self {
+        self.set_deleted(OffsetDateTime::now_utc())
+    }
+
+    /// Set the deleted for the current object to the current time, returning it
+    ///
+    /// This is the consuming version of `set_deleted_now`
+    ///
+    /// ```rust
+    /// use activitystreams::prelude::*;
+    /// # use activitystreams::object::Tombstone;
+    /// # let tombstone = Tombstone::new();
+    ///
+    /// let tombstone = tombstone.with_deleted_now();
+    /// assert!(tombstone.deleted().is_some());
+    /// ```
+    fn with_deleted_now(mut self) -> Self
+    where
+        Self: Sized,
+    {
+        self.set_deleted_now();
+        self
+    }
+
     /// Take the deleted from the current object, leaving nothing
     ///
     /// ```rust
@@ -4261,12 +6914,177 @@ pub type Page = Object<PageType>;
 /// that aren't already present on an Object.
 pub type Video = Object<VideoType>;
 
+/// A custom emoji, rendered in place of its `:shortcode:` name in content.
+///
+/// Emoji isn't part of the ActivityStreams vocabulary, but is defined in Mastodon's
+/// `http://joinmastodon.org/ns#` namespace and is used by most implementations for tags on
+/// Objects and actors.
+///
+/// This is just an alias for `Object<EmojiType>` because there's no fields inherent to Emoji
+/// that aren't already present on an Object.
+pub type Emoji = Object<EmojiType>;
+
+/// An Article with the ActivityPub Object properties.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     iri,
+///     object::{Article, FullArticle},
+///     prelude::*,
+/// };
+///
+/// let article: FullArticle = Article::new()
+///     .full()
+///     .with_shares(iri!("https://example.com/articles/1/shares"));
+///
+/// assert!(article.shares().is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub type FullArticle = ApObject<Article>;
+
+/// An Audio with the ActivityPub Object properties.
+pub type FullAudio = ApObject<Audio>;
+
+/// A Document with the ActivityPub Object properties.
+pub type FullDocument = ApObject<Document>;
+
+/// An Event with the ActivityPub Object properties.
+pub type FullEvent = ApObject<Event>;
+
+/// An Image with the ActivityPub Object properties.
+pub type FullImage = ApObject<Image>;
+
+/// A Note with the ActivityPub Object properties.
+pub type FullNote = ApObject<Note>;
+
+/// A Page with the ActivityPub Object properties.
+pub type FullPage = ApObject<Page>;
+
+/// A Video with the ActivityPub Object properties.
+pub type FullVideo = ApObject<Video>;
+
+/// An Emoji with the ActivityPub Object properties.
+pub type FullEmoji = ApObject<Emoji>;
+
+/// A Place with the ActivityPub Object properties.
+pub type FullPlace = ApObject<Place>;
+
+/// A Profile with the ActivityPub Object properties.
+pub type FullProfile = ApObject<Profile>;
+
+/// A Relationship with the ActivityPub Object properties.
+pub type FullRelationship = ApObject<Relationship>;
+
+/// A Tombstone with the ActivityPub Object properties.
+pub type FullTombstone = ApObject<Tombstone>;
+
+/// An ActivityPub object whose `type` isn't one of the kinds defined by the spec.
+///
+/// This is just an alias for `ApObject<Object<String>>`, which keeps the `type` as a string while
+/// still providing structured access to all of the Object and ActivityPub Object properties. This
+/// is useful as a fallback when an unknown type (e.g. `CacheFile`) is encountered.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{object::GenericObject, prelude::*};
+///
+/// let json = r#"{
+///     "type": "CacheFile",
+///     "id": "https://example.com/cache/1",
+///     "summary": "A cached video",
+///     "likes": "https://example.com/cache/1/likes"
+/// }"#;
+///
+/// let object: GenericObject = serde_json::from_str(json)?;
+///
+/// assert_eq!(object.kind().map(String::as_str), Some("CacheFile"));
+/// assert!(object.summary().is_some());
+/// assert!(object.likes().is_some());
+/// # Ok(())
+/// # }
+/// ```
+pub type GenericObject = ApObject<Object<String>>;
+
+/// An ActivityPub Object of any kind
+///
+/// When deserializing, the `type` field is used to pick the variant, rather than trying each
+/// variant in turn. Objects with a `type` that isn't defined by the spec are kept in the `Other`
+/// variant.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::object::AnyObject;
+///
+/// let json = r#"{
+///     "type": "Note",
+///     "id": "https://example.com/notes/1",
+///     "content": "<p>hi</p>"
+/// }"#;
+///
+/// let object: AnyObject = serde_json::from_str(json)?;
+///
+/// match object {
+///     AnyObject::Note(ref note) => println!("Note {:?}", note),
+///     AnyObject::Other(ref other) => println!("Unknown object {:?}", other),
+///     _ => return Err(anyhow::Error::msg("Unsupported object")),
+/// }
+///
+/// assert_eq!(object.kind_str(), Some("Note"));
+/// assert!(object.content().is_some());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Serialize)]
+#[serde(untagged)]
+pub enum AnyObject {
+    /// An Article object
+    Article(ApObject<Article>),
+
+    /// An Audio object
+    Audio(ApObject<Audio>),
+
+    /// A Document object
+    Document(ApObject<Document>),
+
+    /// An Event object
+    Event(ApObject<Event>),
+
+    /// An Image object
+    Image(ApObject<Image>),
+
+    /// A Note object
+    Note(ApObject<Note>),
+
+    /// A Page object
+    Page(ApObject<Page>),
+
+    /// A Place object
+    Place(ApObject<Place>),
+
+    /// A Profile object
+    Profile(ApObject<Profile>),
+
+    /// A Relationship object
+    Relationship(ApObject<Relationship>),
+
+    /// A Tombstone object
+    Tombstone(ApObject<Tombstone>),
+
+    /// A Video object
+    Video(ApObject<Video>),
+
+    /// An object whose `type` isn't defined by the spec
+    Other(GenericObject),
+}
+
 /// Describes an object of any kind.
 ///
 /// The Object type serves as the base type for most of the other kinds of objects defined in the
 /// Activity Vocabulary, including other Core types such as Activity, IntransitiveActivity,
 /// Collection and OrderedCollection.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Object<Kind> {
     /// Identifies a resource attached or related to an object that potentially requires special
@@ -4277,7 +7095,7 @@ pub struct Object<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     attachment: Option<OneOrMany<AnyBase>>,
 
     /// Identifies one or more entities to which this object is attributed.
@@ -4287,7 +7105,7 @@ pub struct Object<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     attributed_to: Option<OneOrMany<AnyBase>>,
 
     /// Identifies one or more entities that represent the total population of entities for which
@@ -4295,7 +7113,7 @@ pub struct Object<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     audience: Option<OneOrMany<AnyBase>>,
 
     /// The content or textual representation of the Object encoded as a JSON string.
@@ -4307,30 +7125,47 @@ pub struct Object<Kind> {
     ///
     /// - Range: xsd:string | rdf:langString
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     content: Option<OneOrMany<AnyString>>,
 
+    /// The content of the Object, keyed by language tag.
+    ///
+    /// This is the JSON-LD language map form of content, emitted instead of or alongside a list of
+    /// language-tagged values.
+    ///
+    /// - Range: rdf:langString
+    /// - Functional: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_map: Option<RdfLangStringMap>,
+
     /// A natural language summarization of the object encoded as HTML.
     ///
     /// Multiple language tagged summaries MAY be provided.
     ///
     /// - Range: xsd:string | rdf:langString
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     summary: Option<OneOrMany<AnyString>>,
 
+    /// The summary of the Object, keyed by language tag.
+    ///
+    /// - Range: rdf:langString
+    /// - Functional: false
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary_map: Option<RdfLangStringMap>,
+
     /// Identifies one or more links to representations of the object.
     ///
     /// - Range: xsd:anyUri | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     url: Option<OneOrMany<AnyBase>>,
 
     /// Identifies the entity (e.g. an application) that generated the object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     generator: Option<OneOrMany<AnyBase>>,
 
     /// Indicates an entity that describes an icon for this object.
@@ -4340,7 +7175,7 @@ pub struct Object<Kind> {
     ///
     /// - Range: Image | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     icon: Option<OneOrMany<AnyBase>>,
 
     /// Indicates an entity that describes an image for this object.
@@ -4349,14 +7184,14 @@ pub struct Object<Kind> {
     ///
     /// - Range: Image | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     image: Option<OneOrMany<AnyBase>>,
 
     /// Indicates one or more physical or logical locations associated with the object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     location: Option<OneOrMany<AnyBase>>,
 
     /// One or more "tags" that have been associated with an objects. A tag can be any kind of Object.
@@ -4366,7 +7201,7 @@ pub struct Object<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     tag: Option<OneOrMany<AnyBase>>,
 
     /// The date and time describing the actual or expected starting time of the object.
@@ -4419,42 +7254,42 @@ pub struct Object<Kind> {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     in_reply_to: Option<OneOrMany<AnyBase>>,
 
     /// Identifies a Collection containing objects considered to be responses to this object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     replies: Option<OneOrMany<AnyBase>>,
 
     /// Identifies an entity considered to be part of the public primary audience of an Object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     to: Option<OneOrMany<AnyBase>>,
 
     /// Identifies an Object that is part of the private primary audience of this Object.
     ///
     /// Range: Object | Link
     /// Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     bto: Option<OneOrMany<AnyBase>>,
 
     /// Identifies an Object that is part of the public secondary audience of this Object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     cc: Option<OneOrMany<AnyBase>>,
 
     /// Identifies one or more Objects that are part of the private secondary audience of this Object.
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     bcc: Option<OneOrMany<AnyBase>>,
 
     /// Base fields and unparsed json ends up here
@@ -4463,7 +7298,7 @@ pub struct Object<Kind> {
 }
 
 /// Define activitypub properties for the Object type as described by the Activity Pub vocabulary.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(rename_all = "camelCase")]
 pub struct ApObject<Inner> {
     /// This is a list of all Announce activities with this object as the object property, added as
@@ -4473,10 +7308,10 @@ pub struct ApObject<Inner> {
     /// filtered on privileges of an authenticated user or as appropriate when no authentication is
     /// given.
     ///
-    /// - Range: anyUri
+    /// - Range: anyUri | Collection | OrderedCollection
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    shares: Option<IriString>,
+    shares: Option<AnyBase>,
 
     /// This is a list of all Like activities with this object as the object property, added as a
     /// side effect.
@@ -4485,10 +7320,10 @@ pub struct ApObject<Inner> {
     /// filtered on privileges of an authenticated user or as appropriate when no authentication is
     /// given.
     ///
-    /// - Range: anyUri
+    /// - Range: anyUri | Collection | OrderedCollection
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    likes: Option<IriString>,
+    likes: Option<AnyBase>,
 
     /// The source property is intended to convey some sort of source from which the content markup
     /// was derived, as a form of provenance, or to support future editing by clients.
@@ -4512,7 +7347,7 @@ pub struct ApObject<Inner> {
     ///
     /// - Range: anyUri
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     upload_media: Option<OneOrMany<IriString>>,
 
     /// The ActivityStreams object being extended
@@ -4520,6 +7355,22 @@ pub struct ApObject<Inner> {
     inner: Inner,
 }
 
+/// The interaction counts advertised by an object's likes, shares, and replies collections
+///
+/// Each count is `None` when the collection is missing, is only referenced by id, or doesn't
+/// include a `totalItems` value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct InteractionCounts {
+    /// The `totalItems` of the embedded likes collection
+    pub likes: Option<u64>,
+
+    /// The `totalItems` of the embedded shares collection
+    pub shares: Option<u64>,
+
+    /// The `totalItems` of the embedded replies collection
+    pub replies: Option<u64>,
+}
+
 /// Represents a logical or physical location.
 ///
 /// The Place object is used to represent both physical and logical locations. While numerous
@@ -4538,7 +7389,25 @@ pub struct ApObject<Inner> {
 /// While publishers are not required to use these specific properties and MAY make use of other
 /// mechanisms for describing locations, consuming implementations that support the Place object
 /// MUST support the use of these properties.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+///
+/// Values of accuracy, latitude, and longitude outside of their ranges are rejected when
+/// deserializing. With the `float-roundtrip` feature, coordinates are read back exactly as they
+/// were written.
+///
+/// ```rust
+/// use activitystreams::{object::Place, prelude::*};
+///
+/// let place: Place = serde_json::from_str(
+///     r#"{"type":"Place","latitude":36.75,"longitude":-119.7667,"accuracy":94.0}"#,
+/// )
+/// .unwrap();
+/// assert_eq!(place.latitude(), Some(36.75));
+///
+/// assert!(serde_json::from_str::<Place>(r#"{"type":"Place","latitude":136.75}"#).is_err());
+/// assert!(serde_json::from_str::<Place>(r#"{"type":"Place","longitude":"-200"}"#).is_err());
+/// assert!(serde_json::from_str::<Place>(r#"{"type":"Place","accuracy":101}"#).is_err());
+/// ```
+#[derive(Clone, Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 pub struct Place {
     /// Indicates the accuracy of position coordinates on a Place objects.
     ///
@@ -4546,8 +7415,12 @@ pub struct Place {
     ///
     /// - Range: xsd:float [>= 0.0f, <= 100.0f]
     /// - Functional: true
-    #[serde(skip_serializing_if = "Option::is_none")]
-    accuracy: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_accuracy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    accuracy: Option<XsdFloat>,
 
     /// Indicates the altitude of a place. The measurement units is indicated using the units
     /// property.
@@ -4557,21 +7430,29 @@ pub struct Place {
     /// - Range: xsd:float
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    altitude: Option<f64>,
+    altitude: Option<XsdFloat>,
 
     ///The latitude of a place.
     ///
-    /// - Range: xsd:float
+    /// - Range: xsd:float [>= -90.0f, <= 90.0f]
     /// - Functional: true
-    #[serde(skip_serializing_if = "Option::is_none")]
-    latitude: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_latitude",
+        skip_serializing_if = "Option::is_none"
+    )]
+    latitude: Option<XsdFloat>,
 
     /// The longitude of a place.
     ///
-    /// - Range: xsd:float
+    /// - Range: xsd:float [>= -180.0f, <= 180.0f]
     /// - Functional: true
-    #[serde(skip_serializing_if = "Option::is_none")]
-    longitude: Option<f64>,
+    #[serde(
+        default,
+        deserialize_with = "deserialize_longitude",
+        skip_serializing_if = "Option::is_none"
+    )]
+    longitude: Option<XsdFloat>,
 
     /// The radius from the given latitude and longitude for a Place.
     ///
@@ -4581,7 +7462,7 @@ pub struct Place {
     /// - Range: xsd:float
     /// - Functional: true
     #[serde(skip_serializing_if = "Option::is_none")]
-    radius: Option<f64>,
+    radius: Option<XsdFloat>,
 
     /// Specifies the measurement units for the radius and altitude properties on a Place object.
     ///
@@ -4601,7 +7482,7 @@ pub struct Place {
 /// Type objects.
 ///
 /// The describes property is used to reference the object being described by the profile.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Profile {
     /// On a Profile object, the describes property identifies the object described by the Profile.
     ///
@@ -4629,7 +7510,7 @@ pub struct Profile {
 /// of individuals that are directly connected within a person's social graph. Suppose we have a
 /// user, Sally, with direct relationships to users Joe and Jane. Sally follows Joe's updates while
 /// Sally and Jane have a mutual relationship.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Relationship {
     /// On a Relationship object, the subject property identifies one of the connected individuals.
     ///
@@ -4645,7 +7526,7 @@ pub struct Relationship {
     ///
     /// - Range: Object | Link
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     object: Option<OneOrMany<AnyBase>>,
 
     /// On a Relationship object, the relationship property identifies the kind of relationship
@@ -4653,7 +7534,7 @@ pub struct Relationship {
     ///
     /// - Range: Object
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     relationship: Option<OneOrMany<AnyBase>>,
 
     /// The object being extended
@@ -4665,14 +7546,14 @@ pub struct Relationship {
 ///
 /// It can be used in Collections to signify that there used to be an object at this position, but
 /// it has been deleted.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 pub struct Tombstone {
     /// On a Tombstone object, the formerType property identifies the type of the object that was
     /// deleted.
     ///
     /// - Range: Object
     /// - Functional: false
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "crate::primitives::is_none_or_empty")]
     former_type: Option<OneOrMany<AnyBase>>,
 
     /// On a Tombstone object, the deleted property is a timestamp for when the object was deleted.
@@ -4704,7 +7585,9 @@ impl<Kind> Object<Kind> {
             attributed_to: None,
             audience: None,
             content: None,
+            content_map: None,
             summary: None,
+            summary_map: None,
             url: None,
             generator: None,
             icon: None,
@@ -4748,7 +7631,9 @@ impl<Kind> Object<Kind> {
             attributed_to: None,
             audience: None,
             content: None,
+            content_map: None,
             summary: None,
+            summary_map: None,
             url: None,
             generator: None,
             icon: None,
@@ -4770,13 +7655,71 @@ impl<Kind> Object<Kind> {
         }
     }
 
+    /// An infallible conversion from `Object<T>` to `Object<U>` where there is a known path from T
+    /// to U
+    ///
+    /// All of the Object's properties are kept, only its kind changes
+    ///
+    /// ```rust
+    /// use activitystreams::object::{kind::ArticleType, Note, Object};
+    /// use activitystreams::prelude::*;
+    ///
+    /// let mut note = Note::new();
+    /// note.set_content("a very long post");
+    ///
+    /// let article: Object<ArticleType> = note.map_kind(|_| ArticleType::Article);
+    ///
+    /// assert!(article.content().is_some());
+    /// ```
+    ///
+    /// Object types that share the same properties can also be converted directly
+    ///
+    /// ```rust
+    /// use activitystreams::object::{ApObject, Article, Note};
+    ///
+    /// let note = ApObject::new(Note::new());
+    ///
+    /// let article: ApObject<Article> = note.into();
+    /// ```
+    pub fn map_kind<NewKind>(self, f: impl Fn(Kind) -> NewKind) -> Object<NewKind> {
+        Object {
+            attachment: self.attachment,
+            attributed_to: self.attributed_to,
+            audience: self.audience,
+            content: self.content,
+            content_map: self.content_map,
+            summary: self.summary,
+            summary_map: self.summary_map,
+            url: self.url,
+            generator: self.generator,
+            icon: self.icon,
+            image: self.image,
+            location: self.location,
+            tag: self.tag,
+            start_time: self.start_time,
+            end_time: self.end_time,
+            duration: self.duration,
+            published: self.published,
+            updated: self.updated,
+            in_reply_to: self.in_reply_to,
+            replies: self.replies,
+            to: self.to,
+            bto: self.bto,
+            cc: self.cc,
+            bcc: self.bcc,
+            inner: self.inner.map_kind(f),
+        }
+    }
+
     fn extending(mut base: Base<Kind>) -> Result<Self, serde_json::Error> {
         Ok(Object {
             attachment: base.remove("attachment")?,
             attributed_to: base.remove("attributedTo")?,
             audience: base.remove("audience")?,
             content: base.remove("content")?,
+            content_map: base.remove("contentMap")?,
             summary: base.remove("summary")?,
+            summary_map: base.remove("summaryMap")?,
             url: base.remove("url")?,
             generator: base.remove("generator")?,
             icon: base.remove("icon")?,
@@ -4804,7 +7747,9 @@ impl<Kind> Object<Kind> {
             attributed_to,
             audience,
             content,
+            content_map,
             summary,
+            summary_map,
             url,
             generator,
             icon,
@@ -4830,7 +7775,9 @@ impl<Kind> Object<Kind> {
             .insert("attributedTo", attributed_to)?
             .insert("audience", audience)?
             .insert("content", content)?
+            .insert("contentMap", content_map)?
             .insert("summary", summary)?
+            .insert("summaryMap", summary_map)?
             .insert("url", url)?
             .insert("generator", generator)?
             .insert("icon", icon)?
@@ -4887,8 +7834,8 @@ impl<Inner> ApObject<Inner> {
     pub fn into_parts(
         self,
     ) -> (
-        Option<IriString>,
-        Option<IriString>,
+        Option<AnyBase>,
+        Option<AnyBase>,
         Option<AnyBase>,
         Option<OneOrMany<IriString>>,
         Inner,
@@ -4952,6 +7899,27 @@ impl<Inner> ApObject<Inner> {
     }
 }
 
+fn deserialize_accuracy<'de, D>(deserializer: D) -> Result<Option<XsdFloat>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserialize_bounded(deserializer, 0.0, 100.0)
+}
+
+fn deserialize_latitude<'de, D>(deserializer: D) -> Result<Option<XsdFloat>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserialize_bounded(deserializer, -90.0, 90.0)
+}
+
+fn deserialize_longitude<'de, D>(deserializer: D) -> Result<Option<XsdFloat>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    deserialize_bounded(deserializer, -180.0, 180.0)
+}
+
 impl Place {
     /// Create a new ActivityPub Object
     ///
@@ -4973,10 +7941,10 @@ impl Place {
     }
 
     fn extending(mut inner: Object<PlaceType>) -> Result<Self, serde_json::Error> {
-        let accuracy = inner.remove("accuracy")?;
+        let accuracy = deserialize_accuracy(inner.remove::<serde_json::Value>("accuracy")?)?;
         let altitude = inner.remove("altitude")?;
-        let latitude = inner.remove("latitude")?;
-        let longitude = inner.remove("longitude")?;
+        let latitude = deserialize_latitude(inner.remove::<serde_json::Value>("latitude")?)?;
+        let longitude = deserialize_longitude(inner.remove::<serde_json::Value>("longitude")?)?;
         let radius = inner.remove("radius")?;
         let units = inner.remove("units")?;
 
@@ -5135,6 +8103,263 @@ impl Tombstone {
     }
 }
 
+macro_rules! any_object {
+    ($any_object:expr, $object:ident => $expr:expr) => {
+        match $any_object {
+            AnyObject::Article($object) => $expr,
+            AnyObject::Audio($object) => $expr,
+            AnyObject::Document($object) => $expr,
+            AnyObject::Event($object) => $expr,
+            AnyObject::Image($object) => $expr,
+            AnyObject::Note($object) => $expr,
+            AnyObject::Page($object) => $expr,
+            AnyObject::Place($object) => $expr,
+            AnyObject::Profile($object) => $expr,
+            AnyObject::Relationship($object) => $expr,
+            AnyObject::Tombstone($object) => $expr,
+            AnyObject::Video($object) => $expr,
+            AnyObject::Other($object) => $expr,
+        }
+    };
+}
+
+impl AnyObject {
+    /// Create an AnyObject from a generic Base, picking the variant based on its `type`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{base::Base, object::AnyObject};
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(
+    ///     r#"{"type":"CacheFile","id":"https://example.com/cache/1"}"#,
+    /// )?;
+    ///
+    /// let object = AnyObject::from_base(base)?;
+    ///
+    /// assert!(matches!(object, AnyObject::Other(_)));
+    /// assert_eq!(object.kind_str(), Some("CacheFile"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base(base: Base<serde_json::Value>) -> Result<Self, serde_json::Error> {
+        let any_object = match base.kind().and_then(|kind| kind.as_str()) {
+            Some("Article") => AnyObject::Article(base.solidify()?.extend()?),
+            Some("Audio") => AnyObject::Audio(base.solidify()?.extend()?),
+            Some("Document") => AnyObject::Document(base.solidify()?.extend()?),
+            Some("Event") => AnyObject::Event(base.solidify()?.extend()?),
+            Some("Image") => AnyObject::Image(base.solidify()?.extend()?),
+            Some("Note") => AnyObject::Note(base.solidify()?.extend()?),
+            Some("Page") => AnyObject::Page(base.solidify()?.extend()?),
+            Some("Place") => AnyObject::Place(base.solidify()?.extend()?),
+            Some("Profile") => AnyObject::Profile(base.solidify()?.extend()?),
+            Some("Relationship") => AnyObject::Relationship(base.solidify()?.extend()?),
+            Some("Tombstone") => AnyObject::Tombstone(base.solidify()?.extend()?),
+            Some("Video") => AnyObject::Video(base.solidify()?.extend()?),
+            _ => AnyObject::Other(base.solidify()?.extend()?),
+        };
+
+        Ok(any_object)
+    }
+
+    /// Create an AnyObject from an AnyBase
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{object::{AnyObject, Image}, prelude::*};
+    ///
+    /// let any_base = Image::new().into_any_base()?;
+    ///
+    /// let object = AnyObject::from_any_base(any_base)?;
+    ///
+    /// assert!(matches!(object, Some(AnyObject::Image(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_any_base(any_base: AnyBase) -> Result<Option<Self>, serde_json::Error> {
+        any_base.take_base().map(Self::from_base).transpose()
+    }
+
+    /// Create an AnyObject from a generic Base, or the type registered for its `type`
+    ///
+    /// Types registered with the `KindRegistry` take precedence over the variants of this enum.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{
+    ///     base::Base,
+    ///     kind,
+    ///     object::{AnyObject, Object},
+    ///     prelude::*,
+    ///     registry::KindRegistry,
+    /// };
+    ///
+    /// kind!(EmojiReactType, EmojiReact);
+    /// type EmojiReact = Object<EmojiReactType>;
+    ///
+    /// let mut registry = KindRegistry::new();
+    /// registry.register::<EmojiReact>("EmojiReact");
+    ///
+    /// let base: Base<serde_json::Value> =
+    ///     serde_json::from_str(r#"{"type":"EmojiReact","content":"🦀"}"#)?;
+    /// let react = AnyObject::from_base_with(base, &registry)?
+    ///     .downcast::<EmojiReact>()
+    ///     .expect("EmojiReact is registered");
+    ///
+    /// let content = react.content().expect("Content is present");
+    /// assert_eq!(content.as_one().map(|c| c.as_str()), Some("🦀"));
+    ///
+    /// let base: Base<serde_json::Value> = serde_json::from_str(r#"{"type":"Note"}"#)?;
+    /// let note = AnyObject::from_base_with(base, &registry)?.unregistered();
+    ///
+    /// assert!(matches!(note, Some(AnyObject::Note(_))));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_base_with(
+        base: Base<serde_json::Value>,
+        registry: &KindRegistry,
+    ) -> Result<Materialized<Self>, MaterializeError> {
+        registry.materialize_base(base, Self::from_base)
+    }
+
+    /// Create an AnyObject from an AnyBase, or the type registered for its `type`
+    ///
+    /// This returns `None` if the AnyBase is only an ID or a string
+    pub fn from_any_base_with(
+        any_base: AnyBase,
+        registry: &KindRegistry,
+    ) -> Result<Option<Materialized<Self>>, MaterializeError> {
+        any_base
+            .take_base()
+            .map(|base| Self::from_base_with(base, registry))
+            .transpose()
+    }
+
+    /// Convert this AnyObject into an AnyBase
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::object::{AnyObject, ApObject, Video};
+    ///
+    /// let object = AnyObject::from(ApObject::new(Video::new()));
+    ///
+    /// let any_base = object.into_any_base()?;
+    ///
+    /// assert_eq!(any_base.kind_str(), Some("Video"));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_any_base(self) -> Result<AnyBase, serde_json::Error> {
+        any_object!(self, object => object.into_any_base())
+    }
+
+    /// Fetch the `type` of the current object as a string
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Article};
+    /// let object = AnyObject::from(ApObject::new(Article::new()));
+    ///
+    /// assert_eq!(object.kind_str(), Some("Article"));
+    /// ```
+    pub fn kind_str(&self) -> Option<&str> {
+        match self {
+            AnyObject::Article(_) => Some("Article"),
+            AnyObject::Audio(_) => Some("Audio"),
+            AnyObject::Document(_) => Some("Document"),
+            AnyObject::Event(_) => Some("Event"),
+            AnyObject::Image(_) => Some("Image"),
+            AnyObject::Note(_) => Some("Note"),
+            AnyObject::Page(_) => Some("Page"),
+            AnyObject::Place(_) => Some("Place"),
+            AnyObject::Profile(_) => Some("Profile"),
+            AnyObject::Relationship(_) => Some("Relationship"),
+            AnyObject::Tombstone(_) => Some("Tombstone"),
+            AnyObject::Video(_) => Some("Video"),
+            AnyObject::Other(object) => object.kind().map(String::as_str),
+        }
+    }
+
+    /// Fetch the id_unchecked for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(id_unchecked) = object.id_unchecked() {
+    ///     println!("{:?}", id_unchecked);
+    /// }
+    /// ```
+    pub fn id_unchecked(&self) -> Option<&IriString> {
+        any_object!(self, object => object.id_unchecked())
+    }
+
+    /// Fetch the attributed_to for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(attributed_to) = object.attributed_to() {
+    ///     println!("{:?}", attributed_to);
+    /// }
+    /// ```
+    pub fn attributed_to(&self) -> Option<&OneOrMany<AnyBase>> {
+        any_object!(self, object => object.attributed_to())
+    }
+
+    /// Fetch the content for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(content) = object.content() {
+    ///     println!("{:?}", content);
+    /// }
+    /// ```
+    pub fn content(&self) -> Option<OneOrMany<&AnyString>> {
+        any_object!(self, object => object.content())
+    }
+
+    /// Fetch the summary for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(summary) = object.summary() {
+    ///     println!("{:?}", summary);
+    /// }
+    /// ```
+    pub fn summary(&self) -> Option<OneOrMany<&AnyString>> {
+        any_object!(self, object => object.summary())
+    }
+
+    /// Fetch the in_reply_to for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(in_reply_to) = object.in_reply_to() {
+    ///     println!("{:?}", in_reply_to);
+    /// }
+    /// ```
+    pub fn in_reply_to(&self) -> Option<&OneOrMany<AnyBase>> {
+        any_object!(self, object => object.in_reply_to())
+    }
+
+    /// Fetch the published for the current object
+    ///
+    /// ```rust
+    /// # use activitystreams::object::{AnyObject, ApObject, Note};
+    /// # let object = AnyObject::from(ApObject::new(Note::new()));
+    /// if let Some(published) = object.published() {
+    ///     println!("{:?}", published);
+    /// }
+    /// ```
+    pub fn published(&self) -> Option<OffsetDateTime> {
+        any_object!(self, object => object.published())
+    }
+}
+
 impl<Kind> Extends for Object<Kind> {
     type Kind = Kind;
 
@@ -5165,6 +8390,47 @@ impl<Kind> TryFrom<Object<Kind>> for Base<Kind> {
     }
 }
 
+/// Conversions between Object types that share the same properties, for republishing content as a
+/// different type, e.g. a long Note as an Article
+macro_rules! sibling_objects {
+    ($($from:ident => $to:ident,)*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(object: $from) -> Self {
+                    object.map_kind(|_| Default::default())
+                }
+            }
+
+            impl From<ApObject<$from>> for ApObject<$to> {
+                fn from(object: ApObject<$from>) -> Self {
+                    ApObject {
+                        shares: object.shares,
+                        likes: object.likes,
+                        source: object.source,
+                        upload_media: object.upload_media,
+                        inner: object.inner.into(),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+sibling_objects! {
+    Note => Article,
+    Article => Note,
+    Article => Page,
+    Page => Article,
+    Document => Audio,
+    Document => Image,
+    Document => Page,
+    Document => Video,
+    Audio => Document,
+    Image => Document,
+    Page => Document,
+    Video => Document,
+}
+
 impl<Inner> Extends for ApObject<Inner>
 where
     Inner: Extends<Error = serde_json::Error> + UnparsedMut + markers::Object,
@@ -5629,6 +8895,114 @@ impl AsTombstone for Tombstone {
     }
 }
 
+impl<'de> serde::de::Deserialize<'de> for AnyObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let base = Base::<serde_json::Value>::deserialize(deserializer)?;
+        AnyObject::from_base(base).map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<ApObject<Article>> for AnyObject {
+    fn from(object: ApObject<Article>) -> Self {
+        AnyObject::Article(object)
+    }
+}
+
+impl From<ApObject<Audio>> for AnyObject {
+    fn from(object: ApObject<Audio>) -> Self {
+        AnyObject::Audio(object)
+    }
+}
+
+impl From<ApObject<Document>> for AnyObject {
+    fn from(object: ApObject<Document>) -> Self {
+        AnyObject::Document(object)
+    }
+}
+
+impl From<ApObject<Event>> for AnyObject {
+    fn from(object: ApObject<Event>) -> Self {
+        AnyObject::Event(object)
+    }
+}
+
+impl From<ApObject<Image>> for AnyObject {
+    fn from(object: ApObject<Image>) -> Self {
+        AnyObject::Image(object)
+    }
+}
+
+impl From<ApObject<Note>> for AnyObject {
+    fn from(object: ApObject<Note>) -> Self {
+        AnyObject::Note(object)
+    }
+}
+
+impl From<ApObject<Page>> for AnyObject {
+    fn from(object: ApObject<Page>) -> Self {
+        AnyObject::Page(object)
+    }
+}
+
+impl From<ApObject<Place>> for AnyObject {
+    fn from(object: ApObject<Place>) -> Self {
+        AnyObject::Place(object)
+    }
+}
+
+impl From<ApObject<Profile>> for AnyObject {
+    fn from(object: ApObject<Profile>) -> Self {
+        AnyObject::Profile(object)
+    }
+}
+
+impl From<ApObject<Relationship>> for AnyObject {
+    fn from(object: ApObject<Relationship>) -> Self {
+        AnyObject::Relationship(object)
+    }
+}
+
+impl From<ApObject<Tombstone>> for AnyObject {
+    fn from(object: ApObject<Tombstone>) -> Self {
+        AnyObject::Tombstone(object)
+    }
+}
+
+impl From<ApObject<Video>> for AnyObject {
+    fn from(object: ApObject<Video>) -> Self {
+        AnyObject::Video(object)
+    }
+}
+
+impl From<GenericObject> for AnyObject {
+    fn from(object: GenericObject) -> Self {
+        AnyObject::Other(object)
+    }
+}
+
+fn total_items(collection: &AnyBase) -> Option<u64> {
+    let total_items = collection.as_base()?.unparsed().get("totalItems")?;
+
+    <XsdNonNegativeInteger as serde::Deserialize>::deserialize(total_items)
+        .ok()
+        .map(XsdNonNegativeInteger::into_inner)
+}
+
+fn icon_url(icon: &serde_json::Value) -> Option<IriString> {
+    match icon {
+        serde_json::Value::String(iri) => iri.parse().ok(),
+        serde_json::Value::Array(icons) => icons.first().and_then(icon_url),
+        serde_json::Value::Object(icon) => icon
+            .get("url")
+            .or_else(|| icon.get("href"))
+            .and_then(icon_url),
+        _ => None,
+    }
+}
+
 impl<Kind> Default for Object<Kind>
 where
     Kind: Default,
@@ -5661,3 +9035,137 @@ impl Default for Tombstone {
         Self::new()
     }
 }
+
+impl<Kind> Validate for Object<Kind> {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        if let (Some(start_time), Some(end_time)) = (&self.start_time, &self.end_time) {
+            if end_time < start_time {
+                violations.report_field("endTime", "endTime is before startTime");
+            }
+        }
+
+        if let (Some(published), Some(updated)) = (&self.published, &self.updated) {
+            if updated < published {
+                violations.report_field("updated", "updated is before published");
+            }
+        }
+
+        if self.duration.as_ref().is_some_and(XsdDuration::is_negative) {
+            violations.report_field("duration", "duration is negative");
+        }
+
+        // replies is the only functional property this crate reads as one or many values
+        if self
+            .replies
+            .as_ref()
+            .and_then(OneOrMany::as_many)
+            .is_some_and(|replies| replies.len() > 1)
+        {
+            violations.report_field("replies", "replies can only hold one value");
+        }
+
+        violations
+            .field("contentMap", &self.content_map)
+            .field("summaryMap", &self.summary_map)
+            .field("attachment", &self.attachment)
+            .field("attributedTo", &self.attributed_to)
+            .field("audience", &self.audience)
+            .field("url", &self.url)
+            .field("generator", &self.generator)
+            .field("icon", &self.icon)
+            .field("image", &self.image)
+            .field("location", &self.location)
+            .field("tag", &self.tag)
+            .field("inReplyTo", &self.in_reply_to)
+            .field("replies", &self.replies)
+            .field("to", &self.to)
+            .field("bto", &self.bto)
+            .field("cc", &self.cc)
+            .field("bcc", &self.bcc);
+    }
+}
+
+impl<Inner> Validate for ApObject<Inner>
+where
+    Inner: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        violations
+            .field("shares", &self.shares)
+            .field("likes", &self.likes)
+            .field("source", &self.source);
+    }
+}
+
+impl Validate for Place {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        let ranges = [
+            ("accuracy", &self.accuracy, Some(0.0), Some(100.0)),
+            ("altitude", &self.altitude, None, None),
+            ("latitude", &self.latitude, Some(-90.0), Some(90.0)),
+            ("longitude", &self.longitude, Some(-180.0), Some(180.0)),
+            ("radius", &self.radius, Some(0.0), None),
+        ];
+
+        for (name, value, min, max) in ranges {
+            let value = match value {
+                Some(value) if value.0.is_finite() => value.0,
+                Some(value) => {
+                    violations.field(name, value);
+                    continue;
+                }
+                None => continue,
+            };
+
+            match (min, max) {
+                (Some(min), Some(max)) if value < min || value > max => {
+                    violations.report_field(
+                        name,
+                        format!("{} must be between {} and {}", name, min, max),
+                    );
+                }
+                (Some(min), None) if value < min => {
+                    violations.report_field(name, format!("{} is negative", name));
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+impl Validate for Profile {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("describes", &self.describes);
+    }
+}
+
+impl Validate for Relationship {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+
+        violations
+            .field("subject", &self.subject)
+            .field("object", &self.object)
+            .field("relationship", &self.relationship);
+    }
+}
+
+impl Validate for Tombstone {
+    fn validate_into(&self, violations: &mut Violations) {
+        self.inner.validate_into(violations);
+        violations.field("formerType", &self.former_type);
+    }
+}
+
+impl Validate for AnyObject {
+    fn validate_into(&self, violations: &mut Violations) {
+        any_object!(self, object => object.validate_into(violations))
+    }
+}
@@ -0,0 +1,207 @@
+//! Types and functions for storing documents in binary formats
+//!
+//! The serde implementations in this crate lean on features only self-describing formats
+//! support: unknown properties are collected with `#[serde(flatten)]`, and fields like
+//! `OneOrMany` and `Either` are untagged. Formats like bincode and postcard can't deserialize
+//! these, so documents written to them directly can't be read back.
+//!
+//! `Persisted` wraps a document so it serializes as usual in human-readable formats, and as its
+//! JSON bytes in binary formats. This lets on-disk caches keep their own bookkeeping in a binary
+//! format while storing fetched documents alongside it.
+//!
+//! ```rust
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{
+//!     actor::{ApActor, Person},
+//!     iri,
+//!     persist::Persisted,
+//!     prelude::*,
+//! };
+//!
+//! #[derive(serde::Deserialize, serde::Serialize)]
+//! struct CachedActor {
+//!     fetched_at: i64,
+//!     actor: Persisted<ApActor<Person>>,
+//! }
+//!
+//! let mut person = ApActor::new(iri!("https://example.com/users/asonix/inbox"), Person::new());
+//! person.set_preferred_username("asonix");
+//!
+//! let cached = CachedActor {
+//!     fetched_at: 1_600_000_000,
+//!     actor: Persisted(person),
+//! };
+//!
+//! // serialize with any serde format, binary or not
+//! let json = serde_json::to_value(&cached)?;
+//! assert_eq!(json["actor"]["preferredUsername"], "asonix");
+//!
+//! let cached: CachedActor = serde_json::from_value(json)?;
+//! assert_eq!(cached.actor.preferred_username(), Some("asonix"));
+//! # Ok(())
+//! # }
+//! ```
+use serde::{
+    de::{self, DeserializeOwned, SeqAccess, Visitor},
+    ser, Deserialize, Deserializer, Serialize, Serializer,
+};
+use std::{
+    fmt,
+    ops::{Deref, DerefMut},
+};
+
+/// A document that can be stored in binary serde formats
+///
+/// In human-readable formats like JSON, this serializes exactly like the wrapped value. In
+/// binary formats, it serializes as the bytes of the value's JSON representation.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Persisted<T>(pub T);
+
+impl<T> Persisted<T> {
+    /// Take the wrapped document
+    ///
+    /// ```rust
+    /// use activitystreams::{object::Note, persist::Persisted};
+    ///
+    /// let note: Note = Persisted(Note::new()).into_inner();
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Persisted<T> {
+    fn from(t: T) -> Self {
+        Persisted(t)
+    }
+}
+
+impl<T> Deref for Persisted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<T> DerefMut for Persisted<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl<T> Serialize for Persisted<T>
+where
+    T: Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            return self.0.serialize(serializer);
+        }
+
+        let bytes = serde_json::to_vec(&self.0).map_err(ser::Error::custom)?;
+        serializer.serialize_bytes(&bytes)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Persisted<T>
+where
+    T: DeserializeOwned,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            return T::deserialize(deserializer).map(Persisted);
+        }
+
+        let bytes = deserializer.deserialize_byte_buf(BytesVisitor)?;
+        serde_json::from_slice(&bytes)
+            .map(Persisted)
+            .map_err(de::Error::custom)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the bytes of a json document")
+    }
+
+    fn visit_bytes<E>(self, v: &[u8]) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E>(self, v: Vec<u8>) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        Ok(v)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
+
+/// Serialize a document with bincode
+///
+/// This is a shorthand for serializing a `Persisted` document with `bincode::serialize`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     activity::Follow,
+///     iri,
+///     persist::{from_bincode, to_bincode},
+///     prelude::*,
+/// };
+///
+/// let follow = Follow::new(
+///     iri!("https://example.com/users/asonix"),
+///     iri!("https://example.com/users/relay"),
+/// );
+///
+/// let bytes = to_bincode(&follow)?;
+/// let follow: Follow = from_bincode(&bytes)?;
+///
+/// assert_eq!(
+///     follow.actor_unchecked().as_single_id().map(|id| id.as_str()),
+///     Some("https://example.com/users/asonix")
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "bincode")]
+pub fn to_bincode<T>(value: &T) -> Result<Vec<u8>, bincode::Error>
+where
+    T: Serialize,
+{
+    bincode::serialize(&Persisted(value))
+}
+
+/// Deserialize a document serialized with `to_bincode`
+#[cfg(feature = "bincode")]
+pub fn from_bincode<T>(bytes: &[u8]) -> Result<T, bincode::Error>
+where
+    T: DeserializeOwned,
+{
+    bincode::deserialize::<Persisted<T>>(bytes).map(Persisted::into_inner)
+}
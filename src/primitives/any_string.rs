@@ -4,7 +4,7 @@ use crate::primitives::{Either, OneOrMany, RdfLangString};
 ///
 /// In the ActivityStreams specification, string types are often defined as either an xsd:String or
 /// and rdf:langString. The AnyString type represents this union.
-#[derive(Clone, Debug, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
 pub struct AnyString(Either<String, RdfLangString>);
 
@@ -33,7 +33,8 @@ impl AnyString {
     /// # use activitystreams::primitives::{AnyString, RdfLangString};
     /// # let any_string = AnyString::from_rdf_lang_string(RdfLangString {
     /// #     value: "hi".into(),
-    /// #     language: "en".into(),
+    /// #     language: "en".parse()?,
+    /// #     direction: None,
     /// # });
     /// #
     /// let s_borrow = any_string
@@ -70,7 +71,8 @@ impl AnyString {
     /// # use activitystreams::primitives::{AnyString, RdfLangString};
     /// # let any_string = AnyString::from_rdf_lang_string(RdfLangString {
     /// #     value: "hi".into(),
-    /// #     language: "en".into(),
+    /// #     language: "en".parse()?,
+    /// #     direction: None,
     /// # });
     /// #
     /// let rdf_lang_string = any_string
@@ -100,12 +102,16 @@ impl AnyString {
     /// Create a new AnyString from an RdfLangString
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, RdfLangString};
     ///
     /// let any_string = AnyString::from_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn from_rdf_lang_string<T>(string: T) -> Self
     where
@@ -117,16 +123,20 @@ impl AnyString {
     /// Replace the contents of self with a String
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, RdfLangString};
     ///
     /// let mut any_string = AnyString::from_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
     ///
     /// any_string.set_xsd_string("hi");
     ///
     /// assert!(any_string.as_xsd_string().is_some());
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn set_xsd_string<T>(&mut self, string: T)
     where
@@ -138,16 +148,20 @@ impl AnyString {
     /// Replace the contents of self with an RdfLangString
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, RdfLangString};
     ///
     /// let mut any_string = AnyString::from_xsd_string("hi");
     ///
     /// any_string.set_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
     ///
     /// assert!(any_string.as_rdf_lang_string().is_some());
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn set_rdf_lang_string<T>(&mut self, string: T)
     where
@@ -156,9 +170,124 @@ impl AnyString {
         self.0 = Either::Right(string.into());
     }
 
+    /// Shorten the inner string to at most `max_bytes` bytes
+    ///
+    /// Since content and summaries are usually HTML, the string is never cut in the middle of a
+    /// character, a tag, or an entity. Tags left open by the truncation are not closed.
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::AnyString;
+    ///
+    /// let mut any_string = AnyString::from_xsd_string("<p>caf&eacute; <b>time</b></p>");
+    ///
+    /// any_string.truncate(10);
+    /// assert_eq!(any_string.as_str(), "<p>caf");
+    ///
+    /// any_string.truncate(2);
+    /// assert_eq!(any_string.as_str(), "");
+    /// ```
+    pub fn truncate(&mut self, max_bytes: usize) {
+        let s = match self.0 {
+            Either::Left(ref mut s) => s,
+            Either::Right(ref mut lang_str) => &mut lang_str.value,
+        };
+
+        if s.len() <= max_bytes {
+            return;
+        }
+
+        let mut end = max_bytes;
+        while !s.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        if let Some(index) = s[..end].rfind('<') {
+            if !s[index..end].contains('>') {
+                end = index;
+            }
+        }
+
+        if let Some(index) = s[..end].rfind('&') {
+            if !s[index..end].contains(|c: char| c == ';' || c.is_whitespace()) {
+                end = index;
+            }
+        }
+
+        s.truncate(end);
+    }
+
+    /// Convert the inner string from HTML to plain text
+    ///
+    /// Tags are removed, line breaks and paragraphs become newlines, and character references
+    /// are decoded. This is meant for notifications, logs, and search indexes, and makes no
+    /// attempt to preserve formatting beyond line structure.
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::AnyString;
+    ///
+    /// let any_string = AnyString::from_xsd_string("<p>Fish &amp; chips</p><p>to go<br>please</p>");
+    ///
+    /// assert_eq!(any_string.to_plain_text(), "Fish & chips\n\nto go\nplease");
+    /// ```
+    #[cfg(feature = "html-text")]
+    pub fn to_plain_text(&self) -> String {
+        crate::primitives::html_text::html_to_text(self.as_str())
+    }
+
+    /// Create a new AnyString from user-provided text, escaping any markup
+    ///
+    /// Use this when building `content` or `summary` from text that shouldn't be read as HTML.
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::AnyString;
+    ///
+    /// let any_string = AnyString::from_plain_text("<b>bold</b> & brash");
+    ///
+    /// assert_eq!(any_string.as_str(), "&lt;b&gt;bold&lt;/b&gt; &amp; brash");
+    /// assert_eq!(any_string.unescaped(), "<b>bold</b> & brash");
+    /// ```
+    #[cfg(feature = "html-text")]
+    pub fn from_plain_text<T>(text: T) -> Self
+    where
+        T: AsRef<str>,
+    {
+        Self::from_xsd_string(crate::primitives::html_text::escape_html(text.as_ref()))
+    }
+
+    /// Produce the inner string with `&`, `<`, `>`, and quotes escaped as character references
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::AnyString;
+    ///
+    /// let any_string = AnyString::from_xsd_string(r#"1 < 2 && "yes""#);
+    ///
+    /// assert_eq!(any_string.escaped(), "1 &lt; 2 &amp;&amp; &quot;yes&quot;");
+    /// ```
+    #[cfg(feature = "html-text")]
+    pub fn escaped(&self) -> String {
+        crate::primitives::html_text::escape_html(self.as_str())
+    }
+
+    /// Produce the inner string with its character references decoded
+    ///
+    /// Unlike `to_plain_text`, tags are left in place.
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::AnyString;
+    ///
+    /// let any_string = AnyString::from_xsd_string("<p>Fish &amp; chips</p>");
+    ///
+    /// assert_eq!(any_string.unescaped(), "<p>Fish & chips</p>");
+    /// ```
+    #[cfg(feature = "html-text")]
+    pub fn unescaped(&self) -> String {
+        crate::primitives::html_text::unescape_html(self.as_str())
+    }
+
     /// Borrow the inner str
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, RdfLangString};
     /// let any_string = AnyString::from_xsd_string("hi");
     ///
@@ -166,10 +295,13 @@ impl AnyString {
     ///
     /// let any_string = AnyString::from_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
     ///
     /// assert_eq!(any_string.as_str(), "hi");
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn as_str(&self) -> &str {
         match self.0 {
@@ -181,6 +313,7 @@ impl AnyString {
     /// Borrow the inner language
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, RdfLangString};
     /// let any_string = AnyString::from_xsd_string("hi");
     ///
@@ -188,15 +321,18 @@ impl AnyString {
     ///
     /// let any_string = AnyString::from_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
     ///
     /// assert_eq!(any_string.language(), Some("en"));
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn language(&self) -> Option<&str> {
         match self.0 {
             Either::Left(_) => None,
-            Either::Right(ref lang_str) => Some(&lang_str.language),
+            Either::Right(ref lang_str) => Some(lang_str.language.as_str()),
         }
     }
 }
@@ -232,7 +368,8 @@ impl OneOrMany<AnyString> {
     /// # use activitystreams::primitives::{OneOrMany, RdfLangString};
     /// # let string = OneOrMany::from_rdf_lang_string(RdfLangString {
     /// #   value: "hi".into(),
-    /// #   language: "en".into(),
+    /// #   language: "en".parse()?,
+    /// #   direction: None,
     /// # });
     /// string
     ///     .as_single_rdf_lang_string()
@@ -268,7 +405,8 @@ impl OneOrMany<AnyString> {
     /// # use activitystreams::primitives::{OneOrMany, RdfLangString};
     /// # let string = OneOrMany::from_rdf_lang_string(RdfLangString {
     /// #   value: "hi".into(),
-    /// #   language: "en".into(),
+    /// #   language: "en".parse()?,
+    /// #   direction: None,
     /// # });
     /// string
     ///     .single_rdf_lang_string()
@@ -298,12 +436,16 @@ impl OneOrMany<AnyString> {
     /// Create the object from a single RdfLangString
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{OneOrMany, RdfLangString};
     ///
     /// let string = OneOrMany::from_rdf_lang_string(RdfLangString {
     ///     value: "hi".into(),
-    ///     language: "en".into(),
+    ///     language: "en".parse()?,
+    ///     direction: None,
     /// });
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn from_rdf_lang_string<T>(string: T) -> Self
     where
@@ -333,6 +475,7 @@ impl OneOrMany<AnyString> {
     /// Add an RdfLangString to the object, appending to whatever is currently included
     ///
     /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
     /// use activitystreams::primitives::{AnyString, OneOrMany, RdfLangString};
     ///
     /// let mut string = OneOrMany::<AnyString>::from_xsd_string("Hello");
@@ -340,12 +483,16 @@ impl OneOrMany<AnyString> {
     /// string
     ///     .add_rdf_lang_string(RdfLangString {
     ///         value: "Hey".into(),
-    ///         language: "en".into(),
+    ///         language: "en".parse()?,
+    ///         direction: None,
     ///     })
     ///     .add_rdf_lang_string(RdfLangString {
     ///         value: "hi".into(),
-    ///         language: "en".into(),
+    ///         language: "en".parse()?,
+    ///         direction: None,
     ///     });
+    /// # Ok(())
+    /// # }
     /// ```
     pub fn add_rdf_lang_string<T>(&mut self, string: T) -> &mut Self
     where
@@ -380,7 +527,8 @@ impl OneOrMany<&AnyString> {
     /// # use activitystreams::primitives::{OneOrMany, RdfLangString};
     /// # let string = OneOrMany::from_rdf_lang_string(RdfLangString {
     /// #   value: "hi".into(),
-    /// #   language: "en".into(),
+    /// #   language: "en".parse()?,
+    /// #   direction: None,
     /// # });
     /// string
     ///     .as_single_rdf_lang_string()
@@ -413,6 +561,20 @@ impl OneOrMany<&AnyString> {
     }
 }
 
+impl std::str::FromStr for AnyString {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::from_xsd_string(s))
+    }
+}
+
+impl std::fmt::Display for AnyString {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
 impl From<&str> for AnyString {
     fn from(s: &str) -> Self {
         AnyString::from_xsd_string(s.to_owned())
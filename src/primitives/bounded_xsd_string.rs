@@ -0,0 +1,242 @@
+use crate::primitives::{AnyString, Error};
+use std::ops::Deref;
+
+/// An xsd:string that is at most `MAX` characters long
+///
+/// Remote servers can send a `name` or `summary` of any length, and a `preferredUsername` that
+/// doesn't fit in a database column is a problem for every consumer. Using this type for such
+/// fields in an extension rejects oversized values while deserializing.
+///
+/// Length is counted in characters, not bytes.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::BoundedXsdString;
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Profile {
+///     preferred_username: BoundedXsdString<8>,
+/// }
+///
+/// let profile: Profile = serde_json::from_str(r#"{"preferredUsername":"asonix"}"#)?;
+/// assert_eq!(profile.preferred_username, "asonix");
+///
+/// assert!(serde_json::from_str::<Profile>(r#"{"preferredUsername":"aaaaaaaaa"}"#).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct BoundedXsdString<const MAX: usize>(String);
+
+/// The error type produced when a BoundedXsdString is too long
+#[derive(Clone, Debug)]
+pub struct BoundedXsdStringError {
+    max: usize,
+    len: usize,
+}
+
+impl BoundedXsdStringError {
+    /// The largest number of characters that was allowed
+    pub fn max(&self) -> usize {
+        self.max
+    }
+
+    /// The number of characters in the rejected string
+    pub fn length(&self) -> usize {
+        self.len
+    }
+}
+
+impl std::fmt::Display for BoundedXsdStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "String is {} characters long, but at most {} are allowed",
+            self.len, self.max
+        )
+    }
+}
+
+impl std::error::Error for BoundedXsdStringError {}
+
+impl<const MAX: usize> BoundedXsdString<MAX> {
+    /// The largest number of characters this type holds
+    pub const MAX: usize = MAX;
+
+    /// Create a new BoundedXsdString, failing if the string is longer than `MAX` characters
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::BoundedXsdString;
+    ///
+    /// assert!(BoundedXsdString::<5>::new("hello").is_ok());
+    /// assert!(BoundedXsdString::<5>::new("héllö").is_ok());
+    /// assert!(BoundedXsdString::<5>::new("hello!").is_err());
+    /// ```
+    pub fn new<T>(s: T) -> Result<Self, BoundedXsdStringError>
+    where
+        T: Into<String>,
+    {
+        let s = s.into();
+        let len = s.chars().count();
+
+        if len > MAX {
+            return Err(BoundedXsdStringError { max: MAX, len });
+        }
+
+        Ok(BoundedXsdString(s))
+    }
+
+    /// Create a new BoundedXsdString, cutting the string down to `MAX` characters if needed
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::BoundedXsdString;
+    ///
+    /// assert_eq!(BoundedXsdString::<5>::new_truncated("hello, world"), "hello");
+    /// assert_eq!(BoundedXsdString::<5>::new_truncated("hi"), "hi");
+    /// ```
+    pub fn new_truncated<T>(s: T) -> Self
+    where
+        T: Into<String>,
+    {
+        let mut s = s.into();
+
+        if let Some((index, _)) = s.char_indices().nth(MAX) {
+            s.truncate(index);
+        }
+
+        BoundedXsdString(s)
+    }
+
+    /// Borrow the inner str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Retrieve the inner String
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl<const MAX: usize> Deref for BoundedXsdString<MAX> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> AsRef<str> for BoundedXsdString<MAX> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl<const MAX: usize> PartialEq<str> for BoundedXsdString<MAX> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl<const MAX: usize> PartialEq<&str> for BoundedXsdString<MAX> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const MAX: usize> From<BoundedXsdString<MAX>> for String {
+    fn from(s: BoundedXsdString<MAX>) -> Self {
+        s.0
+    }
+}
+
+impl<const MAX: usize> From<BoundedXsdString<MAX>> for AnyString {
+    fn from(s: BoundedXsdString<MAX>) -> Self {
+        AnyString::from_xsd_string(s.0)
+    }
+}
+
+impl<const MAX: usize> std::str::FromStr for BoundedXsdString<MAX> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s)?)
+    }
+}
+
+impl<const MAX: usize> std::convert::TryFrom<String> for BoundedXsdString<MAX> {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Ok(Self::new(s)?)
+    }
+}
+
+impl<const MAX: usize> std::convert::TryFrom<&str> for BoundedXsdString<MAX> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const MAX: usize> std::convert::TryFrom<&mut str> for BoundedXsdString<MAX> {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl<const MAX: usize> std::fmt::Display for BoundedXsdString<MAX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const MAX: usize> serde::ser::Serialize for BoundedXsdString<MAX> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de, const MAX: usize> serde::de::Deserialize<'de> for BoundedXsdString<MAX> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::new(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BoundedXsdString;
+
+    #[test]
+    fn count_characters() {
+        assert!(BoundedXsdString::<3>::new("日本語").is_ok());
+        assert!(BoundedXsdString::<3>::new("日本語!").is_err());
+        assert!(BoundedXsdString::<0>::new("").is_ok());
+    }
+
+    #[test]
+    fn truncate_on_character_boundary() {
+        assert_eq!(BoundedXsdString::<2>::new_truncated("日本語"), "日本");
+    }
+
+    #[test]
+    fn dont_deserialize_long() {
+        for case in [r#""abcd""#, "null", "5"] {
+            assert!(serde_json::from_str::<BoundedXsdString<3>>(case).is_err());
+        }
+
+        let s: BoundedXsdString<3> = serde_json::from_str(r#""abc""#).unwrap();
+        assert_eq!(s, "abc");
+    }
+}
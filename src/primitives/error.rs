@@ -0,0 +1,162 @@
+use crate::primitives::{
+    BoundedXsdStringError, LanguageTagError, NonEmptyXsdStringError, OutOfRangeError,
+    XsdBase64BinaryError, XsdBooleanError, XsdDateError, XsdDoubleError, XsdDurationError,
+    XsdFloatError, XsdHexBinaryError, XsdTimeError,
+};
+
+/// The error type produced when a primitive can't be parsed or converted
+///
+/// Each variant wraps the error for one kind of primitive, which is available through
+/// `std::error::Error::source`, so code parsing several kinds of primitives can use `?` without
+/// converting between error types.
+///
+/// ```rust
+/// use activitystreams::primitives::{Error, OutOfRangeError, XsdDateTime, XsdDuration};
+///
+/// fn expiry(published: &str, ttl: &str) -> Result<XsdDateTime, Error> {
+///     let expiry = published
+///         .parse::<XsdDateTime>()?
+///         .checked_add(ttl.parse::<XsdDuration>()?)
+///         .ok_or(OutOfRangeError)?;
+///
+///     Ok(expiry)
+/// }
+///
+/// assert!(expiry("2020-04-20T04:20:00Z", "PT1H").is_ok());
+/// assert!(matches!(
+///     expiry("2020-04-20T04:20:00Z", "1 hour"),
+///     Err(Error::Duration(_))
+/// ));
+/// assert!(matches!(
+///     expiry("2020-04-20T04:20:00Z", "P9999999DT1S"),
+///     Err(Error::OutOfRange(_))
+/// ));
+/// ```
+#[derive(Clone, Debug)]
+#[non_exhaustive]
+pub enum Error {
+    /// An xsd:base64Binary could not be parsed
+    Base64Binary(XsdBase64BinaryError),
+
+    /// An xsd:boolean could not be parsed
+    Boolean(XsdBooleanError),
+
+    /// A string was longer than its maximum length
+    BoundedString(BoundedXsdStringError),
+
+    /// An xsd:date could not be parsed
+    Date(XsdDateError),
+
+    /// An xsd:dateTime could not be parsed
+    DateTime(time::error::Parse),
+
+    /// An xsd:double could not be parsed
+    Double(XsdDoubleError),
+
+    /// An xsd:duration could not be parsed
+    Duration(XsdDurationError),
+
+    /// An xsd:float could not be parsed
+    Float(XsdFloatError),
+
+    /// An xsd:hexBinary could not be parsed
+    HexBinary(XsdHexBinaryError),
+
+    /// An integer type could not be parsed
+    Integer(std::num::ParseIntError),
+
+    /// An integer was outside the range of an integer type
+    IntegerRange(std::num::TryFromIntError),
+
+    /// A language tag could not be parsed
+    LanguageTag(LanguageTagError),
+
+    /// A string was empty or only whitespace
+    NonEmptyString(NonEmptyXsdStringError),
+
+    /// A date or duration could not be represented by the type it was converted to
+    OutOfRange(OutOfRangeError),
+
+    /// An xsd:time could not be parsed
+    Time(XsdTimeError),
+}
+
+impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::Base64Binary(_) => write!(f, "Invalid xsd:base64Binary"),
+            Error::Boolean(_) => write!(f, "Invalid xsd:boolean"),
+            Error::BoundedString(_) => write!(f, "String too long"),
+            Error::Date(_) => write!(f, "Invalid xsd:date"),
+            Error::DateTime(_) => write!(f, "Invalid xsd:dateTime"),
+            Error::Double(_) => write!(f, "Invalid xsd:double"),
+            Error::Duration(_) => write!(f, "Invalid xsd:duration"),
+            Error::Float(_) => write!(f, "Invalid xsd:float"),
+            Error::HexBinary(_) => write!(f, "Invalid xsd:hexBinary"),
+            Error::Integer(_) => write!(f, "Invalid integer"),
+            Error::IntegerRange(_) => write!(f, "Integer out of range"),
+            Error::LanguageTag(_) => write!(f, "Invalid language tag"),
+            Error::NonEmptyString(_) => write!(f, "Invalid non-empty string"),
+            Error::OutOfRange(_) => write!(f, "Value out of range"),
+            Error::Time(_) => write!(f, "Invalid xsd:time"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Base64Binary(e) => Some(e),
+            Error::Boolean(e) => Some(e),
+            Error::BoundedString(e) => Some(e),
+            Error::Date(e) => Some(e),
+            Error::DateTime(e) => Some(e),
+            Error::Double(e) => Some(e),
+            Error::Duration(e) => Some(e),
+            Error::Float(e) => Some(e),
+            Error::HexBinary(e) => Some(e),
+            Error::Integer(e) => Some(e),
+            Error::IntegerRange(e) => Some(e),
+            Error::LanguageTag(e) => Some(e),
+            Error::NonEmptyString(e) => Some(e),
+            Error::OutOfRange(e) => Some(e),
+            Error::Time(e) => Some(e),
+        }
+    }
+}
+
+macro_rules! from_error {
+    ($($variant:ident($error:ty)),* $(,)?) => {
+        $(
+            impl From<$error> for Error {
+                fn from(e: $error) -> Self {
+                    Error::$variant(e)
+                }
+            }
+        )*
+    };
+}
+
+from_error!(
+    Base64Binary(XsdBase64BinaryError),
+    Boolean(XsdBooleanError),
+    BoundedString(BoundedXsdStringError),
+    Date(XsdDateError),
+    DateTime(time::error::Parse),
+    Double(XsdDoubleError),
+    Duration(XsdDurationError),
+    Float(XsdFloatError),
+    HexBinary(XsdHexBinaryError),
+    Integer(std::num::ParseIntError),
+    IntegerRange(std::num::TryFromIntError),
+    LanguageTag(LanguageTagError),
+    NonEmptyString(NonEmptyXsdStringError),
+    OutOfRange(OutOfRangeError),
+    Time(XsdTimeError),
+);
+
+impl From<std::convert::Infallible> for Error {
+    fn from(e: std::convert::Infallible) -> Self {
+        match e {}
+    }
+}
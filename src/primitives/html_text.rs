@@ -0,0 +1,212 @@
+/// Convert an HTML fragment into plain text
+///
+/// This is not a full HTML parser. It handles the markup ActivityPub servers put in content and
+/// summaries: tags are dropped, `<br>` becomes a newline, the end of a block element becomes a
+/// blank line, `<script>` and `<style>` contents are removed, and the common character references
+/// are decoded. Unknown entities are left as they are.
+pub(crate) fn html_to_text(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut skipping = false;
+    let mut rest = html;
+
+    while let Some(index) = rest.find(['<', '&']) {
+        if !skipping {
+            text.push_str(&rest[..index]);
+        }
+        rest = &rest[index..];
+
+        if rest.starts_with('<') {
+            let end = match rest.find('>') {
+                Some(end) => end,
+                None => {
+                    rest = "";
+                    break;
+                }
+            };
+
+            let tag = &rest[1..end];
+            rest = &rest[end + 1..];
+
+            let closing = tag.starts_with('/');
+            let name = tag
+                .trim_start_matches('/')
+                .split(|c: char| c.is_whitespace() || c == '/')
+                .next()
+                .unwrap_or_default()
+                .to_ascii_lowercase();
+
+            match name.as_str() {
+                "script" | "style" => skipping = !closing,
+                _ if skipping => {}
+                "br" => text.push('\n'),
+                "p" | "div" | "blockquote" | "pre" | "ul" | "ol" | "li" | "h1" | "h2" | "h3"
+                | "h4" | "h5" | "h6"
+                    if closing =>
+                {
+                    text.push_str("\n\n")
+                }
+                _ => {}
+            }
+        } else {
+            let (decoded, len) = decode_entity(rest);
+
+            if !skipping {
+                text.push_str(&decoded);
+            }
+            rest = &rest[len..];
+        }
+    }
+
+    if !skipping {
+        text.push_str(rest);
+    }
+
+    normalize_whitespace(&text)
+}
+
+/// Escape text so it can be placed in HTML without being read as markup
+pub(crate) fn escape_html(text: &str) -> String {
+    let mut html = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => html.push_str("&amp;"),
+            '<' => html.push_str("&lt;"),
+            '>' => html.push_str("&gt;"),
+            '"' => html.push_str("&quot;"),
+            '\'' => html.push_str("&#39;"),
+            c => html.push(c),
+        }
+    }
+
+    html
+}
+
+/// Decode the character references in an HTML fragment, leaving any tags in place
+pub(crate) fn unescape_html(html: &str) -> String {
+    let mut text = String::with_capacity(html.len());
+    let mut rest = html;
+
+    while let Some(index) = rest.find('&') {
+        text.push_str(&rest[..index]);
+        rest = &rest[index..];
+
+        let (decoded, len) = decode_entity(rest);
+        text.push_str(&decoded);
+        rest = &rest[len..];
+    }
+
+    text.push_str(rest);
+    text
+}
+
+fn decode_entity(s: &str) -> (std::borrow::Cow<'static, str>, usize) {
+    let end = match s.char_indices().take(12).find(|(_, c)| *c == ';') {
+        Some((end, _)) => end,
+        None => return ("&".into(), 1),
+    };
+
+    let decoded = match &s[1..end] {
+        "amp" => Some('&'),
+        "lt" => Some('<'),
+        "gt" => Some('>'),
+        "quot" => Some('"'),
+        "apos" => Some('\''),
+        "nbsp" => Some(' '),
+        entity => entity.strip_prefix('#').and_then(|number| {
+            let code = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => number.parse(),
+            };
+
+            code.ok().and_then(char::from_u32)
+        }),
+    };
+
+    match decoded {
+        Some(c) => (c.to_string().into(), end + 1),
+        None => (s[..=end].to_owned().into(), end + 1),
+    }
+}
+
+fn normalize_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut newlines = 0;
+
+    for line in text.split('\n') {
+        let line = line.trim_end();
+
+        if line.trim_start().is_empty() {
+            newlines += 1;
+            continue;
+        }
+
+        if !out.is_empty() {
+            out.push_str(if newlines > 1 { "\n\n" } else { "\n" });
+        }
+        out.push_str(line);
+        newlines = 1;
+    }
+
+    out.trim().to_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{escape_html, html_to_text, unescape_html};
+
+    #[test]
+    fn escape_round_trip() {
+        let text = r#"<script>alert("hi & 'bye'")</script>"#;
+        let html = escape_html(text);
+
+        assert_eq!(
+            html,
+            "&lt;script&gt;alert(&quot;hi &amp; &#39;bye&#39;&quot;)&lt;/script&gt;"
+        );
+        assert_eq!(unescape_html(&html), text);
+        assert_eq!(html_to_text(&html), text);
+    }
+
+    #[test]
+    fn unescape_keeps_tags() {
+        assert_eq!(
+            unescape_html("<p>Fish &amp; chips &unknown; &</p>"),
+            "<p>Fish & chips &unknown; &</p>"
+        );
+    }
+
+    #[test]
+    fn strips_tags() {
+        let html = concat!(
+            r#"<p>Hello <a href="https://example.com/@asonix" class="u-url mention">"#,
+            "@<span>asonix</span></a></p>"
+        );
+
+        assert_eq!(html_to_text(html), "Hello @asonix");
+    }
+
+    #[test]
+    fn breaks_lines_and_paragraphs() {
+        let html = "<p>one<br>two<br />three</p><p>four</p>";
+
+        assert_eq!(html_to_text(html), "one\ntwo\nthree\n\nfour");
+    }
+
+    #[test]
+    fn decodes_entities() {
+        let html = "caf&eacute; &amp; &lt;tea&gt; &#39;hi&#x27; &nbsp;&unknown; & more";
+
+        assert_eq!(
+            html_to_text(html),
+            "caf&eacute; & <tea> 'hi'  &unknown; & more"
+        );
+    }
+
+    #[test]
+    fn drops_scripts_and_unclosed_tags() {
+        let html = "<p>safe</p><script>alert('<p>no</p>')</script><style>p {}</style><b";
+
+        assert_eq!(html_to_text(html), "safe");
+    }
+}
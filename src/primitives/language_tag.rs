@@ -0,0 +1,323 @@
+use crate::primitives::Error;
+
+/// A BCP47 language tag, like `en`, `pt-BR`, or `zh-Hant-TW`.
+///
+/// Tags are checked to be well-formed according to RFC 5646 when parsed, and are stored in their
+/// canonical case: the language and most other subtags are lowercase, scripts are titlecase, and
+/// regions are uppercase. The subtags themselves aren't checked against the IANA registry.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::LanguageTag;
+///
+/// let tag: LanguageTag = "ZH-hant-tw".parse()?;
+///
+/// assert_eq!(tag.as_str(), "zh-Hant-TW");
+/// assert_eq!(tag.primary_language(), "zh");
+/// assert!("en_US".parse::<LanguageTag>().is_err());
+/// assert!(serde_json::from_str::<LanguageTag>(r#""not a tag""#).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct LanguageTag(String);
+
+/// The error type produced when a LanguageTag cannot be parsed
+#[derive(Clone, Debug)]
+pub struct LanguageTagError(String);
+
+impl std::fmt::Display for LanguageTagError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Invalid language tag {:?}", self.0)
+    }
+}
+
+impl std::error::Error for LanguageTagError {}
+
+/// Tags from before RFC 4646 that don't follow the current syntax, but are still valid
+const IRREGULAR: [&str; 17] = [
+    "en-GB-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-BE-FR",
+    "sgn-BE-NL",
+    "sgn-CH-DE",
+];
+
+impl LanguageTag {
+    /// Parse and canonicalize a language tag
+    pub fn parse(s: &str) -> Result<Self, LanguageTagError> {
+        canonicalize(s)
+            .map(LanguageTag)
+            .ok_or_else(|| LanguageTagError(s.to_owned()))
+    }
+
+    /// The tag for content whose language is undetermined, `und`
+    pub fn undetermined() -> Self {
+        LanguageTag(String::from("und"))
+    }
+
+    /// Borrow the tag as a str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Take the tag as a String
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+
+    /// Fetch the primary language subtag, e.g. `pt` for `pt-BR`
+    pub fn primary_language(&self) -> &str {
+        self.0.split('-').next().unwrap_or(&self.0)
+    }
+}
+
+fn canonicalize(s: &str) -> Option<String> {
+    if let Some(irregular) = IRREGULAR.iter().find(|tag| tag.eq_ignore_ascii_case(s)) {
+        return Some((*irregular).to_owned());
+    }
+
+    let subtags: Vec<&str> = s.split('-').collect();
+    if subtags
+        .iter()
+        .any(|subtag| subtag.is_empty() || subtag.len() > 8 || !is_alphanumeric(subtag))
+    {
+        return None;
+    }
+
+    let mut out: Vec<String> = Vec::with_capacity(subtags.len());
+    let mut rest = &subtags[..];
+
+    if !rest[0].eq_ignore_ascii_case("x") {
+        let language = rest[0];
+        if language.len() < 2 || !is_alpha(language) {
+            return None;
+        }
+        out.push(language.to_ascii_lowercase());
+        rest = &rest[1..];
+
+        if language.len() <= 3 {
+            let mut extlangs = 0;
+            while extlangs < 3 && rest.first().is_some_and(|s| s.len() == 3 && is_alpha(s)) {
+                out.push(rest[0].to_ascii_lowercase());
+                rest = &rest[1..];
+                extlangs += 1;
+            }
+        }
+
+        if let Some(script) = rest.first().filter(|s| s.len() == 4 && is_alpha(s)) {
+            let (first, others) = script.split_at(1);
+            out.push(first.to_ascii_uppercase() + &others.to_ascii_lowercase());
+            rest = &rest[1..];
+        }
+
+        if let Some(region) = rest.first().filter(|s| {
+            (s.len() == 2 && is_alpha(s)) || (s.len() == 3 && s.bytes().all(|b| b.is_ascii_digit()))
+        }) {
+            out.push(region.to_ascii_uppercase());
+            rest = &rest[1..];
+        }
+
+        while let Some(variant) = rest
+            .first()
+            .filter(|s| s.len() >= 5 || (s.len() == 4 && s.as_bytes()[0].is_ascii_digit()))
+        {
+            out.push(variant.to_ascii_lowercase());
+            rest = &rest[1..];
+        }
+
+        while let Some(singleton) = rest
+            .first()
+            .filter(|s| s.len() == 1 && !s.eq_ignore_ascii_case("x"))
+        {
+            out.push(singleton.to_ascii_lowercase());
+            rest = &rest[1..];
+
+            let len = rest.iter().take_while(|s| s.len() >= 2).count();
+            if len == 0 {
+                return None;
+            }
+            out.extend(rest[..len].iter().map(|s| s.to_ascii_lowercase()));
+            rest = &rest[len..];
+        }
+    }
+
+    if let Some(x) = rest.first().filter(|s| s.eq_ignore_ascii_case("x")) {
+        if rest.len() == 1 {
+            return None;
+        }
+        out.push(x.to_ascii_lowercase());
+        out.extend(rest[1..].iter().map(|s| s.to_ascii_lowercase()));
+        rest = &[];
+    }
+
+    if !rest.is_empty() {
+        return None;
+    }
+
+    Some(out.join("-"))
+}
+
+fn is_alpha(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_alphabetic())
+}
+
+fn is_alphanumeric(s: &str) -> bool {
+    s.bytes().all(|b| b.is_ascii_alphanumeric())
+}
+
+impl Default for LanguageTag {
+    fn default() -> Self {
+        Self::undetermined()
+    }
+}
+
+impl std::str::FromStr for LanguageTag {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s)?)
+    }
+}
+
+impl std::convert::TryFrom<String> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for LanguageTag {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<LanguageTag> for String {
+    fn from(tag: LanguageTag) -> Self {
+        tag.0
+    }
+}
+
+impl AsRef<str> for LanguageTag {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for LanguageTag {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for LanguageTag {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl std::fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl serde::ser::Serialize for LanguageTag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for LanguageTag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LanguageTag;
+
+    #[test]
+    fn canonicalize_case() {
+        let cases = [
+            ("EN", "en"),
+            ("en-us", "en-US"),
+            ("sr-latn-rs", "sr-Latn-RS"),
+            ("es-419", "es-419"),
+            ("de-CH-1996", "de-CH-1996"),
+            ("zh-YUE-hk", "zh-yue-HK"),
+            ("en-A-Bbb-X-Ccc", "en-a-bbb-x-ccc"),
+            ("X-Private", "x-private"),
+            ("I-KLINGON", "i-klingon"),
+        ];
+
+        for (input, expected) in cases {
+            assert_eq!(LanguageTag::parse(input).unwrap().as_str(), expected);
+        }
+    }
+
+    #[test]
+    fn reject_malformed() {
+        let cases = [
+            "",
+            "e",
+            "en-",
+            "en--us",
+            "en_US",
+            "en-a",
+            "en-x",
+            "en-US-a-bb-a",
+            "123",
+            "toolonglang",
+            "en-a-b-cc",
+            "en US",
+        ];
+
+        for case in cases {
+            assert!(LanguageTag::parse(case).is_err(), "{:?}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["en", "pt-BR", "zh-Hant-TW", "x-private"] {
+            let parsed: LanguageTag = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<LanguageTag>(&json).unwrap(), parsed);
+        }
+    }
+}
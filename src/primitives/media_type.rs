@@ -0,0 +1,110 @@
+//! Media types that come up constantly when working with ActivityStreams
+//!
+//! Each function parses its media type once and hands out clones afterwards, so building a
+//! document or an HTTP response doesn't re-parse the same string on every call.
+//!
+//! ```rust
+//! use activitystreams::{object::Video, prelude::*, primitives::media_type};
+//!
+//! let mut video = Video::new();
+//! video.set_media_type(media_type::html());
+//!
+//! assert_eq!(video.media_type(), Some(&media_type::html()));
+//! ```
+use mime::Mime;
+use std::sync::OnceLock;
+
+macro_rules! media_type {
+    ($(#[$meta:meta])* $name:ident, $media_type:expr) => {
+        $(#[$meta])*
+        pub fn $name() -> Mime {
+            static MEDIA_TYPE: OnceLock<Mime> = OnceLock::new();
+
+            MEDIA_TYPE
+                .get_or_init(|| $media_type.parse().expect("Known media types are valid"))
+                .clone()
+        }
+    };
+}
+
+media_type!(
+    /// `text/html`, the usual media type for `content` and `summary`
+    html,
+    "text/html"
+);
+
+media_type!(
+    /// `text/markdown`, used for the `source` of posts written in Markdown
+    markdown,
+    "text/markdown"
+);
+
+media_type!(
+    /// `text/plain`
+    plain_text,
+    "text/plain"
+);
+
+media_type!(
+    /// `application/activity+json`, the media type of ActivityPub documents
+    activity_json,
+    "application/activity+json"
+);
+
+media_type!(
+    /// `application/ld+json`, without a profile
+    ld_json,
+    "application/ld+json"
+);
+
+media_type!(
+    /// `application/ld+json; profile="https://www.w3.org/ns/activitystreams"`
+    ///
+    /// ActivityPub treats this as equivalent to `application/activity+json`.
+    ld_json_profile,
+    r#"application/ld+json; profile="https://www.w3.org/ns/activitystreams""#
+);
+
+media_type!(
+    /// `application/jrd+json`, the media type of WebFinger responses
+    jrd_json,
+    "application/jrd+json"
+);
+
+/// Check whether a media type identifies an ActivityStreams document
+///
+/// This accepts `application/activity+json`, and `application/ld+json` with the ActivityStreams
+/// profile, ignoring other parameters like `charset`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::media_type;
+///
+/// assert!(media_type::is_activitystreams(&media_type::activity_json()));
+/// assert!(media_type::is_activitystreams(&media_type::ld_json_profile()));
+/// assert!(media_type::is_activitystreams(
+///     &"application/activity+json; charset=utf-8".parse()?
+/// ));
+/// assert!(!media_type::is_activitystreams(&media_type::ld_json()));
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_activitystreams(media_type: &Mime) -> bool {
+    if media_type.type_() != mime::APPLICATION {
+        return false;
+    }
+
+    match (media_type.subtype().as_str(), media_type.suffix()) {
+        ("activity", Some(mime::JSON)) => true,
+        ("ld", Some(mime::JSON)) => media_type
+            .get_param("profile")
+            .map(|profile| {
+                profile
+                    .as_str()
+                    .split_whitespace()
+                    .any(|profile| profile == "https://www.w3.org/ns/activitystreams")
+            })
+            .unwrap_or(false),
+        _ => false,
+    }
+}
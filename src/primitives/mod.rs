@@ -14,26 +14,70 @@
 //! ```
 
 mod any_string;
+mod bounded_xsd_string;
 mod either;
+mod error;
+#[cfg(feature = "html-text")]
+mod html_text;
+mod language_tag;
+pub mod media_type;
+mod non_empty_xsd_string;
 mod one_or_many;
 mod rdf_lang_string;
+mod rdf_lang_string_map;
 mod serde_parse;
 mod unit;
+mod xsd_any_uri;
+mod xsd_base64_binary;
 mod xsd_boolean;
+mod xsd_date;
 mod xsd_datetime;
+mod xsd_double;
 mod xsd_duration;
+mod xsd_float;
+mod xsd_hex_binary;
+mod xsd_integer;
+mod xsd_non_negative_integer;
+mod xsd_positive_integer;
+mod xsd_str;
+mod xsd_time;
+mod xsd_unsigned_long;
 
 pub use self::{
     any_string::AnyString,
+    bounded_xsd_string::{BoundedXsdString, BoundedXsdStringError},
     either::Either,
-    one_or_many::OneOrMany,
-    rdf_lang_string::RdfLangString,
+    error::Error,
+    language_tag::{LanguageTag, LanguageTagError},
+    non_empty_xsd_string::{NonEmptyXsdString, NonEmptyXsdStringError},
+    one_or_many::{is_none_or_empty, IntoIter, Iter, IterMut, OneOrMany},
+    rdf_lang_string::{Direction, RdfLangString},
+    rdf_lang_string_map::RdfLangStringMap,
     unit::Unit,
-    xsd_boolean::XsdBoolean,
-    xsd_datetime::XsdDateTime,
+    xsd_any_uri::{parse_iri_lenient, resolve_against, IriBuilder, Origin, XsdAnyUriExt},
+    xsd_base64_binary::{XsdBase64Binary, XsdBase64BinaryError},
+    xsd_boolean::{XsdBoolean, XsdBooleanError},
+    xsd_date::{XsdDate, XsdDateError},
+    xsd_datetime::{OutOfRangeError, XsdDateTime},
+    xsd_double::{XsdDouble, XsdDoubleError},
     xsd_duration::{XsdDuration, XsdDurationError},
+    xsd_float::{XsdFloat, XsdFloatError},
+    xsd_hex_binary::{XsdHexBinary, XsdHexBinaryError},
+    xsd_integer::XsdInteger,
+    xsd_non_negative_integer::XsdNonNegativeInteger,
+    xsd_positive_integer::XsdPositiveInteger,
+    xsd_str::XsdStr,
+    xsd_time::{XsdTime, XsdTimeError},
+    xsd_unsigned_long::XsdUnsignedLong,
 };
 
+#[cfg(feature = "uuid")]
+pub use self::xsd_any_uri::generate_id;
+#[cfg(feature = "http")]
+pub use self::xsd_any_uri::iri_from_http_uri;
+
+pub(crate) use self::{xsd_any_uri::resolve_json_references, xsd_float::deserialize_bounded};
+
 use self::serde_parse::SerdeParse;
 
 /// An alias for the mime::Mime struct with serde compatibility
@@ -0,0 +1,192 @@
+use crate::primitives::{AnyString, Error};
+use std::ops::Deref;
+
+/// An xsd:string that isn't empty or made only of whitespace
+///
+/// Fields like `preferredUsername` and `name` are meaningless when blank, but nothing in the spec
+/// stops a server from sending `""`. Using this type for such fields in an extension rejects those
+/// values while deserializing instead of leaving every consumer to check for them.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::NonEmptyXsdString;
+///
+/// #[derive(serde::Deserialize)]
+/// #[serde(rename_all = "camelCase")]
+/// struct Profile {
+///     preferred_username: NonEmptyXsdString,
+/// }
+///
+/// let profile: Profile = serde_json::from_str(r#"{"preferredUsername":"asonix"}"#)?;
+/// assert_eq!(profile.preferred_username, "asonix");
+///
+/// assert!(serde_json::from_str::<Profile>(r#"{"preferredUsername":""}"#).is_err());
+/// assert!(serde_json::from_str::<Profile>(r#"{"preferredUsername":" \n"}"#).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct NonEmptyXsdString(String);
+
+/// The error type produced when a NonEmptyXsdString is empty or only whitespace
+#[derive(Clone, Debug)]
+pub struct NonEmptyXsdStringError;
+
+impl std::fmt::Display for NonEmptyXsdStringError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "String is empty or only whitespace")
+    }
+}
+
+impl std::error::Error for NonEmptyXsdStringError {}
+
+impl NonEmptyXsdString {
+    /// Create a new NonEmptyXsdString, failing if the string is empty or only whitespace
+    ///
+    /// The string is kept as it is, surrounding whitespace included.
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::NonEmptyXsdString;
+    ///
+    /// assert!(NonEmptyXsdString::new(" hi ").is_ok());
+    /// assert!(NonEmptyXsdString::new("\t").is_err());
+    /// ```
+    pub fn new<T>(s: T) -> Result<Self, NonEmptyXsdStringError>
+    where
+        T: Into<String>,
+    {
+        let s = s.into();
+
+        if s.trim().is_empty() {
+            return Err(NonEmptyXsdStringError);
+        }
+
+        Ok(NonEmptyXsdString(s))
+    }
+
+    /// Borrow the inner str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Retrieve the inner String
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl Deref for NonEmptyXsdString {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for NonEmptyXsdString {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for NonEmptyXsdString {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for NonEmptyXsdString {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl From<NonEmptyXsdString> for String {
+    fn from(s: NonEmptyXsdString) -> Self {
+        s.0
+    }
+}
+
+impl From<NonEmptyXsdString> for AnyString {
+    fn from(s: NonEmptyXsdString) -> Self {
+        AnyString::from_xsd_string(s.0)
+    }
+}
+
+impl std::str::FromStr for NonEmptyXsdString {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s)?)
+    }
+}
+
+impl std::convert::TryFrom<String> for NonEmptyXsdString {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Ok(Self::new(s)?)
+    }
+}
+
+impl std::convert::TryFrom<&str> for NonEmptyXsdString {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for NonEmptyXsdString {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for NonEmptyXsdString {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl serde::ser::Serialize for NonEmptyXsdString {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for NonEmptyXsdString {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::new(s).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::NonEmptyXsdString;
+
+    #[test]
+    fn dont_deserialize_blank() {
+        for case in [r#""""#, r#"" ""#, r#""\t\r\n""#, "null", "5"] {
+            assert!(serde_json::from_str::<NonEmptyXsdString>(case).is_err());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let s: NonEmptyXsdString = serde_json::from_str(r#"" Aode ""#).unwrap();
+        let json = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(s, " Aode ");
+        assert_eq!(json, r#"" Aode ""#);
+    }
+}
@@ -237,6 +237,20 @@ impl<T> OneOrMany<T> {
         }
     }
 
+    /// Check whether the OneOrMany contains no values
+    ///
+    /// This can only happen when it was produced from an empty Vec
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::OneOrMany;
+    ///
+    /// assert!(OneOrMany::<u32>::from_many(vec![]).is_empty());
+    /// assert!(!OneOrMany::from_one(1).is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.as_slice().is_empty()
+    }
+
     /// Produce a new object from one value
     ///
     /// ```
@@ -380,6 +394,24 @@ impl<'a, T> IntoIterator for &'a mut OneOrMany<T> {
     }
 }
 
+impl<'a, T> Default for Iter<'a, T> {
+    fn default() -> Self {
+        Iter(Either::Left(None))
+    }
+}
+
+impl<'a, T> Default for IterMut<'a, T> {
+    fn default() -> Self {
+        IterMut(Either::Left(None))
+    }
+}
+
+impl<T> Default for IntoIter<T> {
+    fn default() -> Self {
+        IntoIter(Either::Left(None))
+    }
+}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
@@ -560,6 +592,33 @@ impl<T> DoubleEndedIterator for IntoIter<T> {
 
 impl<T> std::iter::FusedIterator for IntoIter<T> {}
 
+/// Used to skip serializing properties that are missing or have no values
+///
+/// Some servers reject a document containing `"tag": []`, so the types in this crate leave such
+/// properties out. Extensions can do the same by passing this to serde's `skip_serializing_if`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::OneOrMany;
+///
+/// #[derive(serde::Serialize)]
+/// struct Emojis {
+///     #[serde(skip_serializing_if = "activitystreams::primitives::is_none_or_empty")]
+///     emoji: Option<OneOrMany<String>>,
+/// }
+///
+/// let emojis = Emojis {
+///     emoji: Some(OneOrMany::from_many(vec![])),
+/// };
+///
+/// assert_eq!(serde_json::to_string(&emojis)?, "{}");
+/// # Ok(())
+/// # }
+/// ```
+pub fn is_none_or_empty<T>(value: &Option<OneOrMany<T>>) -> bool {
+    value.as_ref().is_none_or(OneOrMany::is_empty)
+}
+
 impl<T> From<T> for OneOrMany<T> {
     fn from(t: T) -> Self {
         OneOrMany::from_one(t)
@@ -602,6 +661,7 @@ where
         struct OneOrManyInner<'a, T>(Either<&'a T, &'a [T]>);
         let to_ser = match self.0 {
             Either::Left([ref one]) => OneOrManyInner(Either::Left(one)),
+            Either::Right(ref v) if v.len() == 1 => OneOrManyInner(Either::Left(&v[0])),
             Either::Right(ref v) => OneOrManyInner(Either::Right(v)),
         };
 
@@ -639,6 +699,30 @@ mod tests {
         assert_eq!(h2, h1);
     }
 
+    #[test]
+    fn collapse_single_item_vec() {
+        let s = serde_json::to_string(&OneOrMany::from_many(vec![String::from("hello")])).unwrap();
+        assert_eq!(s, r#""hello""#);
+    }
+
+    #[test]
+    fn skip_empty() {
+        #[derive(serde::Serialize)]
+        struct Hi {
+            #[serde(skip_serializing_if = "super::is_none_or_empty")]
+            inner: Option<OneOrMany<String>>,
+        }
+
+        let s = serde_json::to_string(&Hi {
+            inner: Some(OneOrMany::from_many(vec![])),
+        })
+        .unwrap();
+        assert_eq!(s, "{}");
+
+        let s = serde_json::to_string(&Hi { inner: None }).unwrap();
+        assert_eq!(s, "{}");
+    }
+
     #[test]
     fn iter_works() {
         let single = OneOrMany::from_one(1);
@@ -17,7 +17,30 @@
  * along with ActivityStreams.  If not, see <http://www.gnu.org/licenses/>.
  */
 
+use crate::primitives::LanguageTag;
+
 /// The rdf.langString type extends xs.string, and represents a language tagged string in RDF.
+///
+/// Deserializing fails if `@language` isn't a well-formed BCP47 language tag. The JSON-LD 1.1
+/// `@direction` is kept when present, and only serialized when set.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::{Direction, RdfLangString};
+///
+/// let s: RdfLangString = serde_json::from_str(r#"{"@value":"hi","@language":"en-us"}"#)?;
+/// assert_eq!(s.language, "en-US");
+///
+/// let err = serde_json::from_str::<RdfLangString>(r#"{"@value":"hi","@language":"en_US"}"#)
+///     .unwrap_err();
+/// assert!(err.to_string().contains("Invalid language tag"));
+///
+/// let s: RdfLangString =
+///     serde_json::from_str(r#"{"@value":"مرحبا","@language":"ar","@direction":"rtl"}"#)?;
+/// assert_eq!(s.direction, Some(Direction::Rtl));
+/// # Ok(())
+/// # }
+/// ```
 #[derive(
     Clone, Debug, Default, Eq, Ord, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize,
 )]
@@ -32,7 +55,39 @@ pub struct RdfLangString {
     ///
     /// Represented in json as "@language"
     #[serde(rename = "@language")]
-    pub language: String,
+    pub language: LanguageTag,
+
+    /// The base direction of the text, if it was given
+    ///
+    /// Represented in json as "@direction"
+    #[serde(
+        rename = "@direction",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub direction: Option<Direction>,
+}
+
+/// The base direction of a language tagged string, from JSON-LD 1.1
+#[derive(
+    Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, serde::Deserialize, serde::Serialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// Left to right
+    Ltr,
+
+    /// Right to left
+    Rtl,
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Direction::Ltr => write!(f, "ltr"),
+            Direction::Rtl => write!(f, "rtl"),
+        }
+    }
 }
 
 impl std::fmt::Display for RdfLangString {
@@ -40,3 +95,35 @@ impl std::fmt::Display for RdfLangString {
         write!(f, "{}:{}", self.language, self.value)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, RdfLangString};
+
+    #[test]
+    fn skip_missing_direction() {
+        let s: RdfLangString = serde_json::from_str(r#"{"@value":"hi","@language":"en"}"#).unwrap();
+
+        assert_eq!(s.direction, None);
+        assert_eq!(
+            serde_json::to_string(&s).unwrap(),
+            r#"{"@value":"hi","@language":"en"}"#
+        );
+    }
+
+    #[test]
+    fn round_trip_direction() {
+        let json = r#"{"@value":"hi","@language":"en","@direction":"ltr"}"#;
+        let s: RdfLangString = serde_json::from_str(json).unwrap();
+
+        assert_eq!(s.direction, Some(Direction::Ltr));
+        assert_eq!(serde_json::to_string(&s).unwrap(), json);
+    }
+
+    #[test]
+    fn dont_deserialize_unknown_direction() {
+        let json = r#"{"@value":"hi","@language":"en","@direction":"up"}"#;
+
+        assert!(serde_json::from_str::<RdfLangString>(json).is_err());
+    }
+}
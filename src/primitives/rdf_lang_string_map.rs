@@ -0,0 +1,350 @@
+use crate::primitives::{LanguageTag, RdfLangString};
+use std::collections::BTreeMap;
+
+/// A map from language tags to strings, as used by `contentMap`, `nameMap`, and `summaryMap`
+///
+/// JSON-LD compaction, and servers like Mastodon, emit natural language values in this form
+/// rather than as arrays of `rdf:langString`. Keys are kept exactly as they were received, in the
+/// order they were received, so documents round-trip unchanged and a key that isn't a well-formed
+/// BCP47 language tag doesn't stop the rest of the document from parsing. `iter_tagged` gives the
+/// typed view, and lookups compare keys by their canonical language tag.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::RdfLangStringMap;
+///
+/// let map: RdfLangStringMap =
+///     serde_json::from_str(r#"{"fr":"Bonjour","en-us":"Hello","en_GB":"Hiya"}"#)?;
+///
+/// assert_eq!(map.get("en-US"), Some("Hello"));
+/// assert_eq!(map.get("fr"), Some("Bonjour"));
+/// assert_eq!(map.get("en_GB"), Some("Hiya"));
+///
+/// let tags: Vec<_> = map.iter_tagged().map(|(tag, _)| tag.to_string()).collect();
+/// assert_eq!(tags, ["fr", "en-US"]);
+///
+/// assert_eq!(
+///     serde_json::to_string(&map)?,
+///     r#"{"fr":"Bonjour","en-us":"Hello","en_GB":"Hiya"}"#
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RdfLangStringMap(Vec<(String, String)>);
+
+impl RdfLangStringMap {
+    /// Create a new, empty RdfLangStringMap
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Retrieve the keys and strings in the map, in order
+    pub fn into_inner(self) -> Vec<(String, String)> {
+        self.0
+    }
+
+    fn position(&self, language: &str) -> Option<usize> {
+        self.0
+            .iter()
+            .position(|(key, _)| key == language)
+            .or_else(|| {
+                let language = LanguageTag::parse(language).ok()?;
+
+                self.0
+                    .iter()
+                    .position(|(key, _)| LanguageTag::parse(key).ok().as_ref() == Some(&language))
+            })
+    }
+
+    /// Fetch the string for a language tag
+    ///
+    /// A key that matches exactly is preferred. Otherwise the tag is canonicalized before lookup,
+    /// so `en-us` finds a value stored under `en-US`.
+    pub fn get(&self, language: &str) -> Option<&str> {
+        self.position(language)
+            .map(|index| self.0[index].1.as_str())
+    }
+
+    /// Insert a string for a language tag, returning the string it replaced
+    ///
+    /// An existing entry for the same language keeps its place and key, and new languages are
+    /// added at the end.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::RdfLangStringMap;
+    ///
+    /// let mut map = RdfLangStringMap::new();
+    /// map.insert("en".parse()?, "Hello");
+    ///
+    /// assert_eq!(map.insert("en".parse()?, "Hi").as_deref(), Some("Hello"));
+    /// assert_eq!(map.len(), 1);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn insert<T>(&mut self, language: LanguageTag, value: T) -> Option<String>
+    where
+        T: Into<String>,
+    {
+        let value = value.into();
+
+        match self.position(language.as_str()) {
+            Some(index) => Some(std::mem::replace(&mut self.0[index].1, value)),
+            None => {
+                self.0.push((language.into_inner(), value));
+                None
+            }
+        }
+    }
+
+    /// Remove the string for a language tag
+    pub fn remove(&mut self, language: &str) -> Option<String> {
+        self.position(language).map(|index| self.0.remove(index).1)
+    }
+
+    /// The number of languages in the map
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Check whether the map is empty
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Iterate over the keys and strings in the map, as they were received
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0
+            .iter()
+            .map(|(language, value)| (language.as_str(), value.as_str()))
+    }
+
+    /// Iterate over the entries whose keys are well-formed language tags
+    ///
+    /// Entries with malformed keys are skipped.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::RdfLangStringMap;
+    ///
+    /// let map: RdfLangStringMap = serde_json::from_str(r#"{"en_US":"Hello","FR":"Bonjour"}"#)?;
+    /// let tagged: Vec<_> = map.iter_tagged().collect();
+    ///
+    /// assert_eq!(tagged.len(), 1);
+    /// assert_eq!(tagged[0].0, "fr");
+    /// assert_eq!(tagged[0].1, "Bonjour");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn iter_tagged(&self) -> impl Iterator<Item = (LanguageTag, &str)> {
+        self.0.iter().filter_map(|(language, value)| {
+            Some((LanguageTag::parse(language).ok()?, value.as_str()))
+        })
+    }
+
+    /// Convert the map into a list of `rdf:langString` values
+    ///
+    /// Entries whose keys aren't well-formed language tags are dropped.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::RdfLangStringMap;
+    ///
+    /// let map: RdfLangStringMap = serde_json::from_str(r#"{"en":"Hello"}"#)?;
+    /// let strings = map.into_lang_strings();
+    ///
+    /// assert_eq!(strings[0].language, "en");
+    /// assert_eq!(strings[0].value, "Hello");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn into_lang_strings(self) -> Vec<RdfLangString> {
+        self.0
+            .into_iter()
+            .filter_map(|(language, value)| {
+                Some(RdfLangString {
+                    value,
+                    language: LanguageTag::parse(&language).ok()?,
+                    direction: None,
+                })
+            })
+            .collect()
+    }
+}
+
+impl From<BTreeMap<LanguageTag, String>> for RdfLangStringMap {
+    fn from(map: BTreeMap<LanguageTag, String>) -> Self {
+        map.into_iter().collect()
+    }
+}
+
+impl From<RdfLangString> for RdfLangStringMap {
+    fn from(s: RdfLangString) -> Self {
+        std::iter::once(s).collect()
+    }
+}
+
+impl FromIterator<(LanguageTag, String)> for RdfLangStringMap {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (LanguageTag, String)>,
+    {
+        let mut map = RdfLangStringMap::new();
+        map.extend(iter);
+        map
+    }
+}
+
+impl FromIterator<RdfLangString> for RdfLangStringMap {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = RdfLangString>,
+    {
+        iter.into_iter().map(|s| (s.language, s.value)).collect()
+    }
+}
+
+impl Extend<(LanguageTag, String)> for RdfLangStringMap {
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = (LanguageTag, String)>,
+    {
+        for (language, value) in iter {
+            self.insert(language, value);
+        }
+    }
+}
+
+impl IntoIterator for RdfLangStringMap {
+    type Item = (String, String);
+    type IntoIter = std::vec::IntoIter<(String, String)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl serde::ser::Serialize for RdfLangStringMap {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        use serde::ser::SerializeMap;
+
+        let mut map = serializer.serialize_map(Some(self.0.len()))?;
+        for (language, value) in &self.0 {
+            map.serialize_entry(language, value)?;
+        }
+        map.end()
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for RdfLangStringMap {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        struct RdfLangStringMapVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for RdfLangStringMapVisitor {
+            type Value = RdfLangStringMap;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                write!(f, "a map from language tags to strings")
+            }
+
+            fn visit_map<A>(self, mut access: A) -> Result<Self::Value, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                let mut entries: Vec<(String, String)> =
+                    Vec::with_capacity(access.size_hint().unwrap_or(0).min(16));
+
+                while let Some((language, value)) = access.next_entry::<String, String>()? {
+                    match entries.iter_mut().find(|(key, _)| *key == language) {
+                        Some(entry) => entry.1 = value,
+                        None => entries.push((language, value)),
+                    }
+                }
+
+                Ok(RdfLangStringMap(entries))
+            }
+        }
+
+        deserializer.deserialize_map(RdfLangStringMapVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RdfLangStringMap;
+    use crate::{activity::Create, base::AnyBase, object::Note, prelude::*, validate::Validate};
+
+    #[test]
+    fn keep_keys_as_received() {
+        let map: RdfLangStringMap =
+            serde_json::from_str(r#"{"ZH-hant-tw":"你好","EN":"Hello","en_US":"Howdy"}"#).unwrap();
+        let keys: Vec<_> = map.iter().map(|(language, _)| language).collect();
+
+        assert_eq!(keys, ["ZH-hant-tw", "EN", "en_US"]);
+        assert_eq!(map.get("zh-Hant-TW"), Some("你好"));
+        assert_eq!(map.get("en"), Some("Hello"));
+        assert_eq!(map.get("en_US"), Some("Howdy"));
+        assert_eq!(map.get("not a tag"), None);
+    }
+
+    #[test]
+    fn insert_replaces_matching_language_in_place() {
+        let mut map: RdfLangStringMap =
+            serde_json::from_str(r#"{"EN":"Hello","fr":"Bonjour"}"#).unwrap();
+
+        assert_eq!(
+            map.insert("en".parse().unwrap(), "Hi").as_deref(),
+            Some("Hello")
+        );
+        map.insert("de".parse().unwrap(), "Hallo");
+
+        assert_eq!(
+            serde_json::to_string(&map).unwrap(),
+            r#"{"EN":"Hi","fr":"Bonjour","de":"Hallo"}"#
+        );
+    }
+
+    #[test]
+    fn round_trip() {
+        let map: RdfLangStringMap =
+            serde_json::from_str(r#"{"fr":"Bonjour","en":"Hello"}"#).unwrap();
+        let json = serde_json::to_string(&map).unwrap();
+
+        assert_eq!(json, r#"{"fr":"Bonjour","en":"Hello"}"#);
+        assert_eq!(
+            serde_json::from_str::<RdfLangStringMap>(&json).unwrap(),
+            map
+        );
+    }
+
+    #[test]
+    fn accept_documents_with_malformed_keys() {
+        let note: Note = serde_json::from_str(
+            r#"{"type":"Note","contentMap":{"en_US":"hi"},"summaryMap":{"en":"hey"}}"#,
+        )
+        .unwrap();
+
+        assert_eq!(note.content_map().unwrap().get("en_US"), Some("hi"));
+        assert_eq!(note.summary_map().unwrap().get("en"), Some("hey"));
+
+        let violations = note.validate();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].path, "/contentMap/en_US");
+
+        let json = r#"{"actor":"https://example.com/users/asonix","object":{"type":"Note","nameMap":{"en_US":"hi"}},"type":"Create"}"#;
+        let expected: serde_json::Value = serde_json::from_str(json).unwrap();
+
+        let create: Create = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&create).unwrap(), expected);
+
+        let any_base: AnyBase = serde_json::from_str(json).unwrap();
+        assert_eq!(serde_json::to_value(&any_base).unwrap(), expected);
+    }
+}
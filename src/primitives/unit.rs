@@ -202,6 +202,24 @@ impl std::str::FromStr for Unit {
     }
 }
 
+impl std::fmt::Display for Unit {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self.0 {
+            Either::Left(ref length) => std::fmt::Display::fmt(length, f),
+            Either::Right(ref custom) => std::fmt::Display::fmt(custom, f),
+        }
+    }
+}
+
+impl From<Unit> for String {
+    fn from(unit: Unit) -> Self {
+        match unit.0 {
+            Either::Left(length) => length.to_string(),
+            Either::Right(custom) => custom,
+        }
+    }
+}
+
 impl From<String> for Unit {
     fn from(s: String) -> Self {
         match s.parse() {
@@ -233,7 +251,6 @@ impl From<&str> for Unit {
     serde::Deserialize,
     serde::Serialize,
 )]
-#[serde(untagged)]
 enum Length {
     #[serde(rename = "cm")]
     Centimeters,
@@ -332,7 +349,32 @@ impl std::fmt::Display for Length {
             Length::Feet => write!(f, "feet"),
             Length::Inches => write!(f, "inches"),
             Length::Kilometers => write!(f, "km"),
-            Length::Meters => write!(f, "meters"),
+            Length::Meters => write!(f, "m"),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Unit;
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["cm", "feet", "inches", "km", "m", "yards"] {
+            let parsed: Unit = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(json, format!("{:?}", s));
+            assert_eq!(serde_json::from_str::<Unit>(&json).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn deserialize_lengths() {
+        let unit: Unit = serde_json::from_str(r#""km""#).unwrap();
+
+        assert!(unit.is_kilometers());
+        assert!(!unit.is_custom());
+    }
+}
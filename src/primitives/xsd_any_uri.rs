@@ -0,0 +1,1082 @@
+use crate::walk::URI_PROPERTIES;
+use iri_string::{
+    percent_encode::PercentEncodedForIri,
+    types::{IriReferenceStr, IriStr, IriString},
+};
+
+/// Helper methods for manipulating the `IriString`s used for `xsd:anyUri` fields
+///
+/// The components of an IRI can already be read with `IriStr::scheme_str`,
+/// `IriStr::authority_str`, `IriStr::path_str`, `IriStr::query_str`, and `IriStr::fragment_str`.
+/// These methods cover the host and port, and producing new IRIs from existing ones.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, prelude::*};
+///
+/// let outbox = iri!("https://example.com/users/asonix/outbox");
+/// let page = outbox.with_query_param("page", "2");
+///
+/// assert_eq!(page.as_str(), "https://example.com/users/asonix/outbox?page=2");
+/// # Ok(())
+/// # }
+/// ```
+pub trait XsdAnyUriExt {
+    /// Produce a new IRI with the given query parameter appended
+    ///
+    /// The key and value are percent-encoded, and any existing query parameters and fragment are
+    /// preserved
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("https://example.com/outbox?page=2#items");
+    /// let id = id.with_query_param("max_id", "a b&c");
+    ///
+    /// assert_eq!(id.as_str(), "https://example.com/outbox?page=2&max_id=a%20b%26c#items");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_query_param(&self, key: &str, value: &str) -> IriString;
+
+    /// Produce a new IRI with the fragment removed
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let key_id = iri!("https://example.com/users/asonix#main-key");
+    /// let owner = key_id.strip_fragment();
+    ///
+    /// assert_eq!(key_id.fragment_str(), Some("main-key"));
+    /// assert_eq!(owner.as_str(), "https://example.com/users/asonix");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn strip_fragment(&self) -> IriString;
+
+    /// Fetch the host of the IRI, if it has an authority
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("https://asonix@example.com:8443/users/asonix");
+    ///
+    /// assert_eq!(id.host(), Some("example.com"));
+    /// assert_eq!(id.port(), Some(8443));
+    /// assert_eq!(iri!("urn:uuid:1234").host(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn host(&self) -> Option<&str>;
+
+    /// Fetch the port of the IRI, if one is given explicitly
+    fn port(&self) -> Option<u16>;
+
+    /// Produce a new IRI with the path replaced
+    ///
+    /// The path must already be percent-encoded where needed. When the IRI has an authority, a
+    /// leading `/` is added to the path if it's missing. When it doesn't, a path starting with
+    /// `//` is prefixed with `/.` so it can't be read back as an authority, as described in
+    /// RFC 3986 §5.3. The query and fragment are preserved.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("https://example.com/users/asonix?page=2");
+    /// let outbox = id.with_path("users/asonix/outbox")?;
+    ///
+    /// assert_eq!(outbox.as_str(), "https://example.com/users/asonix/outbox?page=2");
+    /// assert!(id.with_path("/users/a sonix").is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn with_path(&self, path: &str) -> Result<IriString, iri_string::validate::Error>;
+
+    /// Replace the path of the IRI
+    ///
+    /// This behaves like `with_path`, but updates the IRI in place
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let mut id = iri!("https://example.com/users/asonix#main-key");
+    /// id.set_path("/actor")?;
+    ///
+    /// assert_eq!(id.as_str(), "https://example.com/actor#main-key");
+    /// assert_eq!(id.path_str(), "/actor");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn set_path(&mut self, path: &str) -> Result<&mut Self, iri_string::validate::Error>
+    where
+        Self: From<IriString> + Sized,
+    {
+        *self = self.with_path(path)?.into();
+        Ok(self)
+    }
+
+    /// Produce the RFC 3986 normal form of the IRI
+    ///
+    /// The scheme and host are lowercased, percent-encodings are uppercased or decoded when they
+    /// don't need to be encoded, dot segments are removed from the path, the port is dropped when
+    /// it's the default for `http`, `https`, `ws`, or `wss`, and an empty path becomes `/`
+    ///
+    /// With the `idna` feature, internationalized hosts are also converted to their ASCII
+    /// (punycode) form, so `bücher.example` and `xn--bcher-kva.example` normalize the same way.
+    ///
+    /// This is named `normalized` so it isn't shadowed by iri-string's own `IriStr::normalize`,
+    /// which doesn't handle ports or empty paths.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("HTTPS://Example.COM:443/users/./%7Easonix/../asonix");
+    ///
+    /// assert_eq!(id.normalized().as_str(), "https://example.com/users/asonix");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn normalized(&self) -> IriString;
+
+    /// Check whether two IRIs are the same after normalization
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("https://example.com/users/asonix");
+    ///
+    /// assert!(id.is_equivalent(&iri!("https://EXAMPLE.com:443/users/%61sonix")));
+    /// assert!(!id.is_equivalent(&iri!("https://example.com/users/Asonix")));
+    /// assert!(iri!("http://example.com").is_equivalent(&iri!("http://example.com/")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn is_equivalent(&self, other: &IriStr) -> bool {
+        self.normalized() == other.normalized()
+    }
+
+    /// Fetch the origin of the IRI, its scheme, host, and port
+    ///
+    /// The scheme and host are normalized, and the port is filled in with the scheme's default
+    /// when one isn't given. IRIs without a host, like `urn:` or `data:` IRIs, have an opaque
+    /// origin.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*, primitives::Origin};
+    ///
+    /// let origin = iri!("HTTPS://Example.com/users/asonix").origin();
+    ///
+    /// assert_eq!(origin.host(), Some("example.com"));
+    /// assert_eq!(origin.port(), Some(443));
+    /// assert_eq!(origin.to_string(), "https://example.com");
+    ///
+    /// assert!(matches!(iri!("urn:uuid:1234").origin(), Origin::Opaque));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn origin(&self) -> Origin;
+
+    /// Check whether two IRIs share an origin
+    ///
+    /// This is useful for checking that an object's id belongs to the server that sent it. Opaque
+    /// origins are never the same as any other origin, including themselves.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let actor = iri!("https://example.com/users/asonix");
+    ///
+    /// assert!(actor.same_origin(&iri!("https://EXAMPLE.com:443/activities/1")));
+    /// assert!(!actor.same_origin(&iri!("http://example.com/activities/1")));
+    /// assert!(!actor.same_origin(&iri!("https://example.com.evil.net/activities/1")));
+    /// assert!(!iri!("urn:uuid:1234").same_origin(&iri!("urn:uuid:1234")));
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn same_origin(&self, other: &IriStr) -> bool {
+        self.origin() == other.origin()
+    }
+
+    /// Convert the IRI into an `http::Uri`
+    ///
+    /// Non-ASCII characters are percent-encoded, and the fragment is dropped since `http::Uri`
+    /// can't represent one
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{iri, prelude::*};
+    ///
+    /// let id = iri!("https://example.com/users/ásonix#main-key");
+    /// let uri = id.to_http_uri()?;
+    ///
+    /// assert_eq!(uri.to_string(), "https://example.com/users/%C3%A1sonix");
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "http")]
+    fn to_http_uri(&self) -> Result<http::Uri, http::uri::InvalidUri>;
+}
+
+/// Convert an `http::Uri` into an IRI
+///
+/// This fails for URIs without a scheme, like the `/users/asonix/inbox` request targets servers
+/// receive, since `xsd:anyUri` fields hold absolute IRIs
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::iri_from_http_uri;
+///
+/// let uri: http::Uri = "https://example.com/users/asonix/inbox".parse()?;
+/// let iri = iri_from_http_uri(&uri)?;
+///
+/// assert_eq!(iri.as_str(), "https://example.com/users/asonix/inbox");
+///
+/// let uri: http::Uri = "/users/asonix/inbox".parse()?;
+/// assert!(iri_from_http_uri(&uri).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "http")]
+pub fn iri_from_http_uri(
+    uri: &http::Uri,
+) -> Result<IriString, iri_string::types::CreationError<String>> {
+    IriString::try_from(uri.to_string())
+}
+
+impl XsdAnyUriExt for IriStr {
+    fn with_query_param(&self, key: &str, value: &str) -> IriString {
+        let (absolute, fragment) = self.to_absolute_and_fragment();
+
+        let separator = if absolute.query_str().is_some() {
+            '&'
+        } else {
+            '?'
+        };
+
+        let mut s = format!(
+            "{}{}{}={}",
+            absolute,
+            separator,
+            PercentEncodedForIri::unreserve(key),
+            PercentEncodedForIri::unreserve(value)
+        );
+
+        if let Some(fragment) = fragment {
+            s.push('#');
+            s.push_str(fragment.as_str());
+        }
+
+        IriString::try_from(s).expect("Percent-encoded query parameters are valid IRI components")
+    }
+
+    fn strip_fragment(&self) -> IriString {
+        self.to_absolute().to_owned().into()
+    }
+
+    fn host(&self) -> Option<&str> {
+        self.authority_components()
+            .map(|authority| authority.host())
+    }
+
+    fn port(&self) -> Option<u16> {
+        self.authority_components()?.port()?.parse().ok()
+    }
+
+    fn with_path(&self, path: &str) -> Result<IriString, iri_string::validate::Error> {
+        let mut s = format!("{}:", self.scheme_str());
+
+        if let Some(authority) = self.authority_str() {
+            s.push_str("//");
+            s.push_str(authority);
+
+            if !path.is_empty() && !path.starts_with('/') {
+                s.push('/');
+            }
+        } else if path.starts_with("//") {
+            s.push_str("/.");
+        }
+
+        s.push_str(path);
+
+        if let Some(query) = self.query_str() {
+            s.push('?');
+            s.push_str(query);
+        }
+
+        if let Some(fragment) = self.fragment_str() {
+            s.push('#');
+            s.push_str(fragment);
+        }
+
+        IriString::try_from(s).map_err(|e| e.validation_error())
+    }
+
+    fn normalized(&self) -> IriString {
+        let normalized = IriString::try_from(IriStr::normalize(self).to_string())
+            .expect("Normalized IRIs are valid IRIs");
+
+        let authority = match normalized.authority_components() {
+            Some(authority) => authority,
+            None => return normalized,
+        };
+
+        let default_port = default_port(normalized.scheme_str());
+        let keep_port = authority
+            .port()
+            .filter(|port| !port.is_empty() && port.parse().ok() != default_port);
+
+        let mut s = format!("{}://", normalized.scheme_str());
+
+        if let Some(userinfo) = authority.userinfo() {
+            s.push_str(userinfo);
+            s.push('@');
+        }
+        s.push_str(&ascii_host(authority.host()));
+        if let Some(port) = keep_port {
+            s.push(':');
+            s.push_str(port);
+        }
+
+        match normalized.path_str() {
+            "" => s.push('/'),
+            path => s.push_str(path),
+        }
+
+        if let Some(query) = normalized.query_str() {
+            s.push('?');
+            s.push_str(query);
+        }
+
+        if let Some(fragment) = normalized.fragment_str() {
+            s.push('#');
+            s.push_str(fragment);
+        }
+
+        IriString::try_from(s).expect("Normalizing the authority and path keeps IRIs valid")
+    }
+
+    fn origin(&self) -> Origin {
+        let normalized = self.normalized();
+
+        let authority = match normalized.authority_components() {
+            Some(authority) if !authority.host().is_empty() => authority,
+            _ => return Origin::Opaque,
+        };
+
+        let port = match authority.port().filter(|port| !port.is_empty()) {
+            Some(port) => match port.parse() {
+                Ok(port) => Some(port),
+                Err(_) => return Origin::Opaque,
+            },
+            None => default_port(normalized.scheme_str()),
+        };
+
+        Origin::Tuple {
+            scheme: normalized.scheme_str().to_owned(),
+            host: authority.host().to_owned(),
+            port,
+        }
+    }
+
+    #[cfg(feature = "http")]
+    fn to_http_uri(&self) -> Result<http::Uri, http::uri::InvalidUri> {
+        http::Uri::try_from(self.to_absolute().encode_to_uri().to_string())
+    }
+}
+
+impl XsdAnyUriExt for IriString {
+    fn with_query_param(&self, key: &str, value: &str) -> IriString {
+        self.as_slice().with_query_param(key, value)
+    }
+
+    fn strip_fragment(&self) -> IriString {
+        self.as_slice().strip_fragment()
+    }
+
+    fn host(&self) -> Option<&str> {
+        self.as_slice().host()
+    }
+
+    fn port(&self) -> Option<u16> {
+        self.as_slice().port()
+    }
+
+    fn with_path(&self, path: &str) -> Result<IriString, iri_string::validate::Error> {
+        self.as_slice().with_path(path)
+    }
+
+    fn normalized(&self) -> IriString {
+        self.as_slice().normalized()
+    }
+
+    fn origin(&self) -> Origin {
+        self.as_slice().origin()
+    }
+
+    #[cfg(feature = "http")]
+    fn to_http_uri(&self) -> Result<http::Uri, http::uri::InvalidUri> {
+        self.as_slice().to_http_uri()
+    }
+}
+
+/// The origin of an IRI, as returned by `XsdAnyUriExt::origin`
+///
+/// Two tuple origins are equal when their schemes, hosts, and ports are equal. Opaque origins
+/// aren't equal to anything, so comparing origins with `==` is safe for security checks.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, prelude::*};
+///
+/// let origin = iri!("https://example.com:8443/inbox").origin();
+///
+/// assert_eq!(origin.scheme(), Some("https"));
+/// assert_eq!(origin.port(), Some(8443));
+/// assert_eq!(origin.to_string(), "https://example.com:8443");
+/// assert_ne!(iri!("data:,hi").origin(), iri!("data:,hi").origin());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub enum Origin {
+    /// An origin made from an IRI's scheme, host, and port
+    Tuple {
+        /// The lowercased scheme
+        scheme: String,
+
+        /// The normalized host
+        host: String,
+
+        /// The explicit port, or the scheme's default port if it has one
+        port: Option<u16>,
+    },
+
+    /// The origin of an IRI without a host
+    Opaque,
+}
+
+impl Origin {
+    /// Fetch the scheme of a tuple origin
+    pub fn scheme(&self) -> Option<&str> {
+        match self {
+            Origin::Tuple { scheme, .. } => Some(scheme),
+            Origin::Opaque => None,
+        }
+    }
+
+    /// Fetch the host of a tuple origin
+    pub fn host(&self) -> Option<&str> {
+        match self {
+            Origin::Tuple { host, .. } => Some(host),
+            Origin::Opaque => None,
+        }
+    }
+
+    /// Fetch the port of a tuple origin
+    pub fn port(&self) -> Option<u16> {
+        match self {
+            Origin::Tuple { port, .. } => *port,
+            Origin::Opaque => None,
+        }
+    }
+
+    /// Check whether the origin is opaque
+    pub fn is_opaque(&self) -> bool {
+        matches!(self, Origin::Opaque)
+    }
+}
+
+impl PartialEq for Origin {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (
+                Origin::Tuple {
+                    scheme: lscheme,
+                    host: lhost,
+                    port: lport,
+                },
+                Origin::Tuple {
+                    scheme: rscheme,
+                    host: rhost,
+                    port: rport,
+                },
+            ) => lscheme == rscheme && lhost == rhost && lport == rport,
+            _ => false,
+        }
+    }
+}
+
+impl std::fmt::Display for Origin {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Origin::Tuple { scheme, host, port } => {
+                write!(f, "{}://{}", scheme, host)?;
+
+                match port {
+                    Some(port) if Some(*port) != default_port(scheme) => {
+                        write!(f, ":{}", port)
+                    }
+                    _ => Ok(()),
+                }
+            }
+            Origin::Opaque => f.write_str("null"),
+        }
+    }
+}
+
+fn default_port(scheme: &str) -> Option<u16> {
+    match scheme {
+        "http" | "ws" => Some(80),
+        "https" | "wss" => Some(443),
+        _ => None,
+    }
+}
+
+/// Convert an internationalized domain name to its ASCII form
+///
+/// IP literals, and hosts that aren't valid domain names, are left as they are.
+#[cfg(feature = "idna")]
+fn ascii_host(host: &str) -> std::borrow::Cow<'_, str> {
+    if host.is_ascii() {
+        return host.into();
+    }
+
+    match idna::domain_to_ascii(host) {
+        Ok(ascii) => ascii.into(),
+        Err(_) => host.into(),
+    }
+}
+
+#[cfg(not(feature = "idna"))]
+fn ascii_host(host: &str) -> std::borrow::Cow<'_, str> {
+    host.into()
+}
+
+/// Parse an IRI, repairing the mistakes commonly found in IRIs from other servers
+///
+/// Parsing with `str::parse`, or deserializing, rejects IRIs containing characters that aren't
+/// allowed in them. This instead percent-encodes those characters in the path, query, and
+/// fragment, and percent signs that don't start a percent-encoding. With the `idna` feature,
+/// internationalized hosts are also converted to their ASCII (punycode) form.
+///
+/// This still fails for IRIs that can't be repaired, like ones without a scheme.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri_string::types::IriString, primitives::parse_iri_lenient};
+///
+/// let s = "https://example.com/tags/hello world?q={rust}#100%";
+/// assert!(s.parse::<IriString>().is_err());
+///
+/// let iri = parse_iri_lenient(s)?;
+/// assert_eq!(
+///     iri.as_str(),
+///     "https://example.com/tags/hello%20world?q=%7Brust%7D#100%25"
+/// );
+///
+/// assert!(parse_iri_lenient("/tags/rust").is_err());
+/// # Ok(())
+/// # }
+/// ```
+pub fn parse_iri_lenient(s: &str) -> Result<IriString, iri_string::validate::Error> {
+    let s = s.trim();
+
+    let (scheme, mut rest) = match s.split_once(':') {
+        Some(parts) => parts,
+        None => return IriStr::new(s).map(|iri| iri.to_owned()),
+    };
+
+    let mut repaired = format!("{}:", scheme);
+
+    if let Some(hierarchical) = rest.strip_prefix("//") {
+        let end = hierarchical
+            .find(['/', '?', '#'])
+            .unwrap_or(hierarchical.len());
+        let (authority, remainder) = hierarchical.split_at(end);
+
+        let (userinfo, host_port) = match authority.rsplit_once('@') {
+            Some((userinfo, host_port)) => (Some(userinfo), host_port),
+            None => (None, authority),
+        };
+
+        let (host, port) = match host_port.rsplit_once(':') {
+            Some((host, port))
+                if !host_port.ends_with(']') && port.bytes().all(|b| b.is_ascii_digit()) =>
+            {
+                (host, Some(port))
+            }
+            _ => (host_port, None),
+        };
+
+        repaired.push_str("//");
+        if let Some(userinfo) = userinfo {
+            repaired.push_str(userinfo);
+            repaired.push('@');
+        }
+        repaired.push_str(&ascii_host(host));
+        if let Some(port) = port {
+            repaired.push(':');
+            repaired.push_str(port);
+        }
+
+        rest = remainder;
+    }
+
+    let mut in_query = false;
+    let mut in_fragment = false;
+
+    for (index, c) in rest.char_indices() {
+        let allowed = match c {
+            '?' => {
+                in_query |= !in_fragment;
+                true
+            }
+            '#' if !in_fragment => {
+                in_fragment = true;
+                true
+            }
+            '%' => rest.as_bytes()[index + 1..]
+                .get(..2)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit)),
+            c if c.is_ascii() => c.is_ascii_alphanumeric() || "-._~!$&'()*+,;=:@/".contains(c),
+            c => is_ucschar(c) || (in_query && !in_fragment && is_iprivate(c)),
+        };
+
+        if allowed {
+            repaired.push(c);
+        } else {
+            for byte in c.encode_utf8(&mut [0; 4]).bytes() {
+                repaired.push_str(&format!("%{:02X}", byte));
+            }
+        }
+    }
+
+    IriString::try_from(repaired).map_err(|e| e.validation_error())
+}
+
+/// Characters outside ASCII that RFC 3987 allows in IRIs
+fn is_ucschar(c: char) -> bool {
+    let c = u32::from(c);
+
+    match c {
+        0xA0..=0xD7FF | 0xF900..=0xFDCF | 0xFDF0..=0xFFEF | 0xE1000..=0xEFFFD => true,
+        // Planes 1 through 13, except the last two code points of each
+        0x10000..=0xDFFFF => c & 0xFFFF <= 0xFFFD,
+        _ => false,
+    }
+}
+
+/// Private use characters, which RFC 3987 only allows in the query
+fn is_iprivate(c: char) -> bool {
+    matches!(
+        u32::from(c),
+        0xE000..=0xF8FF | 0xF0000..=0xFFFFD | 0x100000..=0x10FFFD
+    )
+}
+
+/// Build an IRI from its parts
+///
+/// Path segments, query parameters, and the fragment are percent-encoded as needed, so values
+/// like usernames can be passed in as they are.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::IriBuilder;
+///
+/// let id = IriBuilder::new("example.com")
+///     .segments(["users", "asonix", "statuses"])
+///     .segment("4b0d 9f3e")
+///     .query_param("page", "true")
+///     .build()?;
+///
+/// assert_eq!(
+///     id.as_str(),
+///     "https://example.com/users/asonix/statuses/4b0d%209f3e?page=true"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct IriBuilder {
+    scheme: String,
+    host: String,
+    port: Option<u16>,
+    path: String,
+    query: Option<String>,
+    fragment: Option<String>,
+}
+
+impl IriBuilder {
+    /// Start building an `https` IRI for the given host
+    pub fn new<T>(host: T) -> Self
+    where
+        T: Into<String>,
+    {
+        IriBuilder {
+            scheme: String::from("https"),
+            host: host.into(),
+            port: None,
+            path: String::new(),
+            query: None,
+            fragment: None,
+        }
+    }
+
+    /// Use a scheme other than `https`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::IriBuilder;
+    ///
+    /// let id = IriBuilder::new("localhost").scheme("http").port(8080).build()?;
+    ///
+    /// assert_eq!(id.as_str(), "http://localhost:8080/");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn scheme<T>(mut self, scheme: T) -> Self
+    where
+        T: Into<String>,
+    {
+        self.scheme = scheme.into();
+        self
+    }
+
+    /// Set the port
+    pub fn port(mut self, port: u16) -> Self {
+        self.port = Some(port);
+        self
+    }
+
+    /// Append a path segment, percent-encoding it
+    ///
+    /// Slashes in the segment are encoded, so each call adds exactly one segment
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::IriBuilder;
+    ///
+    /// let id = IriBuilder::new("example.com").segment("tags").segment("a/b").build()?;
+    ///
+    /// assert_eq!(id.as_str(), "https://example.com/tags/a%2Fb");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn segment(mut self, segment: &str) -> Self {
+        self.path.push('/');
+        self.path
+            .push_str(&PercentEncodedForIri::from_path_segment(segment).to_string());
+        self
+    }
+
+    /// Append several path segments
+    pub fn segments<I, T>(self, segments: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        segments
+            .into_iter()
+            .fold(self, |builder, segment| builder.segment(segment.as_ref()))
+    }
+
+    /// Append a query parameter, percent-encoding the key and value
+    pub fn query_param(mut self, key: &str, value: &str) -> Self {
+        let query = self.query.get_or_insert_with(String::new);
+        if !query.is_empty() {
+            query.push('&');
+        }
+
+        query.push_str(&format!(
+            "{}={}",
+            PercentEncodedForIri::unreserve(key),
+            PercentEncodedForIri::unreserve(value)
+        ));
+        self
+    }
+
+    /// Set the fragment, percent-encoding it
+    pub fn fragment(mut self, fragment: &str) -> Self {
+        self.fragment = Some(PercentEncodedForIri::from_fragment(fragment).to_string());
+        self
+    }
+
+    /// Produce the IRI
+    ///
+    /// Characters in the host that can't appear in a domain name are percent-encoded, unless the
+    /// host is a bracketed IP literal. This fails if the scheme or IP literal aren't valid
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::IriBuilder;
+    ///
+    /// let id = IriBuilder::new("example.com/admin").build()?;
+    /// assert_eq!(id.as_str(), "https://example.com%2Fadmin/");
+    ///
+    /// assert!(IriBuilder::new("[::1]").build().is_ok());
+    /// assert!(IriBuilder::new("[not an ip]").build().is_err());
+    /// assert!(IriBuilder::new("example.com").scheme("ht tp").build().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn build(&self) -> Result<IriString, iri_string::validate::Error> {
+        let mut s = format!("{}://", self.scheme);
+
+        if self.host.starts_with('[') {
+            s.push_str(&self.host);
+        } else {
+            s.push_str(&PercentEncodedForIri::from_reg_name(&self.host).to_string());
+        }
+
+        if let Some(port) = self.port {
+            s.push_str(&format!(":{}", port));
+        }
+
+        if self.path.is_empty() {
+            s.push('/');
+        } else {
+            s.push_str(&self.path);
+        }
+
+        if let Some(query) = &self.query {
+            s.push('?');
+            s.push_str(query);
+        }
+
+        if let Some(fragment) = &self.fragment {
+            s.push('#');
+            s.push_str(fragment);
+        }
+
+        IriString::try_from(s).map_err(|e| e.validation_error())
+    }
+}
+
+/// Generate a new id by appending a prefix and a random v4 UUID to the base IRI's path
+///
+/// The prefix may contain several segments separated by slashes, and each is percent-encoded.
+/// The base IRI's query and fragment aren't carried over.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, primitives::generate_id};
+///
+/// let base = iri!("https://example.com/");
+///
+/// let id = generate_id(&base, "users/asonix/statuses");
+/// let uuid = id.as_str().trim_start_matches("https://example.com/users/asonix/statuses/");
+///
+/// assert_eq!(uuid.len(), 36);
+/// assert_ne!(id, generate_id(&base, "users/asonix/statuses"));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "uuid")]
+pub fn generate_id(base: &IriStr, prefix: &str) -> IriString {
+    let mut s = format!("{}:", base.scheme_str());
+
+    if let Some(authority) = base.authority_str() {
+        s.push_str("//");
+        s.push_str(authority);
+    }
+
+    s.push_str(base.path_str().trim_end_matches('/'));
+
+    for segment in prefix.split('/').filter(|segment| !segment.is_empty()) {
+        s.push('/');
+        s.push_str(&PercentEncodedForIri::from_path_segment(segment).to_string());
+    }
+
+    s.push('/');
+    s.push_str(&uuid::Uuid::new_v4().hyphenated().to_string());
+
+    IriString::try_from(s).expect("Appending percent-encoded segments keeps IRIs valid")
+}
+
+/// Resolve a possibly-relative IRI reference against a base IRI
+///
+/// The spec allows relative references in IRI-valued properties, which are relative to the
+/// document's own IRI. Absolute references are returned unchanged, and the base's fragment is
+/// ignored.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{iri, primitives::resolve_against};
+///
+/// let base = iri!("https://example.com/users/asonix");
+///
+/// assert_eq!(
+///     resolve_against("outbox", &base)?.as_str(),
+///     "https://example.com/users/outbox"
+/// );
+/// assert_eq!(
+///     resolve_against("/notes/1#replies", &base)?.as_str(),
+///     "https://example.com/notes/1#replies"
+/// );
+/// assert_eq!(
+///     resolve_against("https://other.example/", &base)?.as_str(),
+///     "https://other.example/"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn resolve_against(
+    reference: &str,
+    base: &IriStr,
+) -> Result<IriString, iri_string::validate::Error> {
+    let reference = IriReferenceStr::new(reference)?;
+    let resolved = reference.resolve_against(base.to_absolute()).to_string();
+
+    IriString::try_from(resolved).map_err(|e| e.validation_error())
+}
+
+/// Resolve the relative references in IRI-valued properties of a json document against `base`
+///
+/// References that can't be resolved are left alone, so deserializing the document reports them.
+pub(crate) fn resolve_json_references(value: &mut serde_json::Value, base: &IriStr) {
+    match value {
+        serde_json::Value::Array(values) => {
+            for value in values {
+                resolve_json_references(value, base);
+            }
+        }
+        serde_json::Value::Object(map) => {
+            for (key, value) in map.iter_mut() {
+                if key == "@context" {
+                    continue;
+                }
+
+                if URI_PROPERTIES.contains(&key.as_str()) {
+                    resolve_strings(value, base);
+                }
+
+                resolve_json_references(value, base);
+            }
+        }
+        _ => {}
+    }
+}
+
+fn resolve_strings(value: &mut serde_json::Value, base: &IriStr) {
+    match value {
+        serde_json::Value::String(reference) => {
+            if let Ok(resolved) = resolve_against(reference, base) {
+                *reference = resolved.into();
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                if let serde_json::Value::String(_) = value {
+                    resolve_strings(value, base);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse_iri_lenient, resolve_json_references};
+    use iri_string::types::IriStr;
+    use serde_json::json;
+
+    #[test]
+    fn resolve_every_iri_property() {
+        let mut json = json!({
+            "type": "Person",
+            "id": "asonix",
+            "inbox": "asonix/inbox",
+            "summary": "see asonix/outbox",
+            "endpoints": {
+                "sharedInbox": "inbox",
+                "uploadMedia": "upload",
+                "proxyUrl": "proxy",
+                "oauthAuthorizationEndpoint": "oauth/authorize"
+            }
+        });
+
+        resolve_json_references(
+            &mut json,
+            IriStr::new("https://example.com/users/").unwrap(),
+        );
+
+        assert_eq!(json["id"], "https://example.com/users/asonix");
+        assert_eq!(json["inbox"], "https://example.com/users/asonix/inbox");
+        assert_eq!(json["summary"], "see asonix/outbox");
+        assert_eq!(
+            json["endpoints"]["sharedInbox"],
+            "https://example.com/users/inbox"
+        );
+        assert_eq!(
+            json["endpoints"]["uploadMedia"],
+            "https://example.com/users/upload"
+        );
+        assert_eq!(
+            json["endpoints"]["proxyUrl"],
+            "https://example.com/users/proxy"
+        );
+        assert_eq!(
+            json["endpoints"]["oauthAuthorizationEndpoint"],
+            "https://example.com/users/oauth/authorize"
+        );
+    }
+
+    #[test]
+    fn lenient_keeps_valid_iris() {
+        for s in [
+            "https://example.com/users/asonix",
+            "https://user@[::1]:8443/caf%C3%A9?q=1#top",
+            "https://example.com/café?ü=ö#frag?ment",
+            "urn:uuid:4b0d9f3e-8c1a-4e0a-9a3e-1f2b3c4d5e6f",
+        ] {
+            assert_eq!(parse_iri_lenient(s).unwrap().as_str(), s);
+        }
+    }
+
+    #[test]
+    fn lenient_encodes_invalid_characters() {
+        let iri = parse_iri_lenient(" https://example.com/a|b/\u{E000}?\u{E000}#a#b ").unwrap();
+
+        assert_eq!(
+            iri.as_str(),
+            "https://example.com/a%7Cb/%EE%80%80?\u{E000}#a%23b"
+        );
+    }
+
+    #[test]
+    fn path_without_authority_stays_a_path() {
+        use super::XsdAnyUriExt;
+
+        let iri = IriStr::new("foo:/a?q#f")
+            .unwrap()
+            .with_path("//evil")
+            .unwrap();
+
+        assert_eq!(iri.as_str(), "foo:/.//evil?q#f");
+        assert_eq!(iri.host(), None);
+        assert_eq!(iri.normalized().host(), None);
+    }
+
+    #[cfg(feature = "idna")]
+    #[test]
+    fn ascii_hosts() {
+        use super::XsdAnyUriExt;
+        use iri_string::types::IriString;
+
+        let unicode = parse_iri_lenient("https://Bücher.example/").unwrap();
+        let ascii: IriString = "https://xn--bcher-kva.example/".parse().unwrap();
+
+        assert_eq!(unicode, ascii);
+        assert!(ascii.is_equivalent(&"https://bücher.example".parse::<IriString>().unwrap()));
+    }
+}
@@ -0,0 +1,278 @@
+use crate::primitives::Error;
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:base64Binary represents arbitrary binary data, encoded with the Base64 alphabet
+/// from RFC 4648.
+///
+/// Whitespace in the encoded form is ignored when parsing, and padding is required. Values are
+/// always written without whitespace.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdBase64Binary;
+///
+/// let digest: XsdBase64Binary = serde_json::from_str(r#""aGVsbG8=""#)?;
+///
+/// assert_eq!(digest.as_bytes(), b"hello");
+/// assert_eq!(serde_json::to_string(&digest)?, r#""aGVsbG8=""#);
+/// assert!(serde_json::from_str::<XsdBase64Binary>(r#""aGVsbG8""#).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdBase64Binary(pub Vec<u8>);
+
+/// The error type produced when an XsdBase64Binary cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdBase64BinaryError;
+
+impl std::fmt::Display for XsdBase64BinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse Base64Binary")
+    }
+}
+
+impl std::error::Error for XsdBase64BinaryError {}
+
+const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+impl XsdBase64Binary {
+    /// Create a new XsdBase64Binary from bytes
+    pub fn new<T>(bytes: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        XsdBase64Binary(bytes.into())
+    }
+
+    /// Retrieve the inner bytes
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Borrow the inner bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+fn decode_sextet(byte: u8) -> Option<u8> {
+    match byte {
+        b'A'..=b'Z' => Some(byte - b'A'),
+        b'a'..=b'z' => Some(byte - b'a' + 26),
+        b'0'..=b'9' => Some(byte - b'0' + 52),
+        b'+' => Some(62),
+        b'/' => Some(63),
+        _ => None,
+    }
+}
+
+fn decode(s: &str) -> Option<Vec<u8>> {
+    let encoded: Vec<u8> = s.bytes().filter(|b| !b.is_ascii_whitespace()).collect();
+
+    if !encoded.len().is_multiple_of(4) {
+        return None;
+    }
+
+    let mut bytes = Vec::with_capacity(encoded.len() / 4 * 3);
+    let last = encoded.len() / 4;
+
+    for (index, chunk) in encoded.chunks(4).enumerate() {
+        let padding = chunk.iter().rev().take_while(|b| **b == b'=').count();
+
+        if padding > 2 || (padding > 0 && index + 1 != last) {
+            return None;
+        }
+
+        let mut group = 0u32;
+        for byte in &chunk[..4 - padding] {
+            group = (group << 6) | u32::from(decode_sextet(*byte)?);
+        }
+        group <<= 6 * padding;
+
+        let decoded = [(group >> 16) as u8, (group >> 8) as u8, group as u8];
+        let len = 3 - padding;
+
+        // The unused bits of the final character must be zero for the encoding to be canonical
+        if decoded[len..].iter().any(|b| *b != 0) {
+            return None;
+        }
+
+        bytes.extend_from_slice(&decoded[..len]);
+    }
+
+    Some(bytes)
+}
+
+impl Deref for XsdBase64Binary {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdBase64Binary {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for XsdBase64Binary {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for XsdBase64Binary {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for XsdBase64Binary {
+    fn from(bytes: Vec<u8>) -> Self {
+        XsdBase64Binary(bytes)
+    }
+}
+
+impl From<&[u8]> for XsdBase64Binary {
+    fn from(bytes: &[u8]) -> Self {
+        XsdBase64Binary(bytes.to_vec())
+    }
+}
+
+impl From<XsdBase64Binary> for Vec<u8> {
+    fn from(b: XsdBase64Binary) -> Self {
+        b.0
+    }
+}
+
+impl std::str::FromStr for XsdBase64Binary {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode(s)
+            .map(XsdBase64Binary)
+            .ok_or(Error::Base64Binary(XsdBase64BinaryError))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdBase64Binary {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdBase64Binary {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdBase64Binary {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdBase64Binary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut s = String::with_capacity(self.0.len().div_ceil(3) * 4);
+
+        for chunk in self.0.chunks(3) {
+            let group = chunk.iter().enumerate().fold(0u32, |group, (index, byte)| {
+                group | (u32::from(*byte) << (16 - 8 * index))
+            });
+
+            for index in 0..4 {
+                if index <= chunk.len() {
+                    let sextet = (group >> (18 - 6 * index)) & 0x3f;
+                    s.push(char::from(ALPHABET[sextet as usize]));
+                } else {
+                    s.push('=');
+                }
+            }
+        }
+
+        f.write_str(&s)
+    }
+}
+
+impl serde::ser::Serialize for XsdBase64Binary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for XsdBase64Binary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdBase64Binary;
+
+    #[test]
+    fn rfc4648_vectors() {
+        let vectors = [
+            ("", ""),
+            ("f", "Zg=="),
+            ("fo", "Zm8="),
+            ("foo", "Zm9v"),
+            ("foob", "Zm9vYg=="),
+            ("fooba", "Zm9vYmE="),
+            ("foobar", "Zm9vYmFy"),
+        ];
+
+        for (bytes, encoded) in vectors {
+            let parsed: XsdBase64Binary = encoded.parse().unwrap();
+
+            assert_eq!(parsed.as_bytes(), bytes.as_bytes());
+            assert_eq!(XsdBase64Binary::new(bytes).to_string(), encoded);
+        }
+    }
+
+    #[test]
+    fn ignore_whitespace() {
+        let parsed: XsdBase64Binary = "Zm9v\nYmFy\r\n".parse().unwrap();
+
+        assert_eq!(parsed.as_bytes(), b"foobar");
+    }
+
+    #[test]
+    fn dont_parse_invalid() {
+        for case in [
+            "Zg", "Zg=", "Zg===", "Zh==", "Zg==Zm9v", "Zm9v-_==", "Zm9v!",
+        ] {
+            assert!(case.parse::<XsdBase64Binary>().is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let binary = XsdBase64Binary::new(bytes);
+        let json = serde_json::to_string(&binary).unwrap();
+
+        assert_eq!(
+            serde_json::from_str::<XsdBase64Binary>(&json).unwrap(),
+            binary
+        );
+    }
+}
@@ -1,13 +1,38 @@
-use crate::primitives::Either;
+use crate::primitives::{Either, Error};
 use serde::{Deserialize, Serialize};
 use std::ops::{Deref, DerefMut};
 
 /// The type xsd:boolean represents logical yes/no values. The valid values for xsd:boolean are
 /// true, false, 0, and 1. Values that are capitalized (e.g. TRUE) or abbreviated (e.g. T) are not
 /// valid.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdBoolean;
+///
+/// let b: XsdBoolean = "1".parse()?;
+///
+/// assert_eq!(b, true);
+/// assert_eq!(b.to_string(), "true");
+/// assert!("TRUE".parse::<XsdBoolean>().is_err());
+/// # Ok(())
+/// # }
+/// ```
 #[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct XsdBoolean(pub bool);
 
+/// The error type produced when an XsdBoolean cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdBooleanError;
+
+impl std::fmt::Display for XsdBooleanError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse Boolean")
+    }
+}
+
+impl std::error::Error for XsdBooleanError {}
+
 impl XsdBoolean {
     /// Construct a new XsdBoolean
     pub fn new(b: bool) -> Self {
@@ -70,6 +95,48 @@ impl From<XsdBoolean> for bool {
     }
 }
 
+impl std::str::FromStr for XsdBoolean {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "true" | "1" => Ok(XsdBoolean(true)),
+            "false" | "0" => Ok(XsdBoolean(false)),
+            _ => Err(Error::Boolean(XsdBooleanError)),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdBoolean {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdBoolean {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdBoolean {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdBoolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
 impl<'de> Deserialize<'de> for XsdBoolean {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -166,4 +233,15 @@ mod tests {
 
         assert_eq!(structs, new_structs);
     }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["true", "false"] {
+            let parsed: XsdBoolean = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdBoolean>(&json).unwrap(), parsed);
+        }
+    }
 }
@@ -0,0 +1,249 @@
+use crate::primitives::{Error, XsdDateTime};
+
+/// The type xsd:date represents a calendar date in the format CCYY-MM-DD.
+///
+/// Some implementations publish bare dates in fields like `startTime` or `published`, this type
+/// allows reading them without a time of day.
+///
+/// An optional time zone expression may be added at the end of the value, in the same format as
+/// for xsd:dateTime. It is accepted when parsing, but not kept, since a date on its own doesn't
+/// refer to a single instant.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{primitives::XsdDate, time::Month};
+///
+/// let date: XsdDate = "2020-04-20".parse()?;
+///
+/// assert_eq!(date.as_date().month(), Month::April);
+/// assert_eq!(date.to_string(), "2020-04-20");
+/// assert_eq!(date.midnight_utc().to_string(), "2020-04-20T00:00:00Z");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdDate(pub time::Date);
+
+/// The error type produced when an XsdDate cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdDateError;
+
+impl std::fmt::Display for XsdDateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse Date")
+    }
+}
+
+impl std::error::Error for XsdDateError {}
+
+impl XsdDate {
+    /// Create an XsdDate from a time::Date
+    pub fn new(d: time::Date) -> Self {
+        XsdDate(d)
+    }
+
+    /// Extract the time::Date from an XsdDate
+    pub fn into_inner(self) -> time::Date {
+        self.0
+    }
+
+    /// Borrow the underlying `time::Date`
+    pub fn as_date(&self) -> &time::Date {
+        self.as_ref()
+    }
+
+    /// Mutably borrow the underlying `time::Date`
+    pub fn as_date_mut(&mut self) -> &mut time::Date {
+        self.as_mut()
+    }
+
+    /// Produce the XsdDateTime for the start of this date in UTC
+    pub fn midnight_utc(&self) -> XsdDateTime {
+        XsdDateTime::new(self.0.midnight().assume_utc())
+    }
+}
+
+impl From<time::Date> for XsdDate {
+    fn from(d: time::Date) -> Self {
+        XsdDate(d)
+    }
+}
+
+impl From<XsdDate> for time::Date {
+    fn from(d: XsdDate) -> Self {
+        d.0
+    }
+}
+
+impl AsRef<time::Date> for XsdDate {
+    fn as_ref(&self) -> &time::Date {
+        &self.0
+    }
+}
+
+impl AsMut<time::Date> for XsdDate {
+    fn as_mut(&mut self) -> &mut time::Date {
+        &mut self.0
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdDate {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdDate {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdDate {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::str::FromStr for XsdDate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_date(s)?)
+    }
+}
+
+fn parse_date(s: &str) -> Result<XsdDate, XsdDateError> {
+    let (negative, s) = match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
+    };
+
+    let (year, s) = s.split_once('-').ok_or(XsdDateError)?;
+    if year.len() < 4 || (year.len() > 4 && year.starts_with('0')) {
+        return Err(XsdDateError);
+    }
+
+    let month = s.get(..2).ok_or(XsdDateError)?;
+    let day = s.get(3..5).ok_or(XsdDateError)?;
+    if s.get(2..3) != Some("-") || !is_timezone(&s[5..]) {
+        return Err(XsdDateError);
+    }
+
+    let year: i32 = parse_digits(year)?;
+    let month: u8 = parse_digits(month)?;
+    let day: u8 = parse_digits(day)?;
+
+    let year = if negative { -year } else { year };
+    let month = time::Month::try_from(month).map_err(|_| XsdDateError)?;
+
+    time::Date::from_calendar_date(year, month, day)
+        .map(XsdDate)
+        .map_err(|_| XsdDateError)
+}
+
+fn parse_digits<T>(s: &str) -> Result<T, XsdDateError>
+where
+    T: std::str::FromStr,
+{
+    if !s.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(XsdDateError);
+    }
+
+    s.parse().map_err(|_| XsdDateError)
+}
+
+fn is_timezone(s: &str) -> bool {
+    match s.as_bytes() {
+        [] | [b'Z'] => true,
+        [b'+' | b'-', h1, h2, b':', m1, m2] => [h1, h2, m1, m2].iter().all(|b| b.is_ascii_digit()),
+        _ => false,
+    }
+}
+
+impl std::fmt::Display for XsdDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let year = self.0.year();
+        let sign = if year < 0 { "-" } else { "" };
+
+        write!(
+            f,
+            "{}{:04}-{:02}-{:02}",
+            sign,
+            year.unsigned_abs(),
+            u8::from(self.0.month()),
+            self.0.day()
+        )
+    }
+}
+
+impl serde::ser::Serialize for XsdDate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for XsdDate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdDate;
+
+    #[test]
+    fn parse_with_timezone() {
+        let date: XsdDate = "2020-04-20-05:00".parse().unwrap();
+        assert_eq!(date.to_string(), "2020-04-20");
+
+        let date: XsdDate = "2020-04-20Z".parse().unwrap();
+        assert_eq!(date.to_string(), "2020-04-20");
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for s in [
+            "2020-4-20",
+            "20-04-20",
+            "2020-02-30",
+            "2020-04-20T00:00:00Z",
+            "+2020-04-20",
+        ] {
+            assert!(s.parse::<XsdDate>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn negative_years() {
+        let date: XsdDate = "-0044-03-15".parse().unwrap();
+
+        assert_eq!(date.0.year(), -44);
+        assert_eq!(date.to_string(), "-0044-03-15");
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["2020-04-20", "-0044-03-15"] {
+            let parsed: XsdDate = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdDate>(&json).unwrap(), parsed);
+        }
+    }
+}
@@ -17,6 +17,8 @@
  * along with ActivityStreams.  If not, see <http://www.gnu.org/licenses/>.
  */
 
+use crate::primitives::{Error, XsdDuration};
+
 /// The type xsd:dateTime represents a specific date and time in the format
 /// CCYY-MM-DDThh:mm:ss.sss, which is a concatenation of the date and time forms, separated by a
 /// literal letter "T".
@@ -30,18 +32,150 @@
 /// range from -14:00 to 14:00. For example, US Eastern Standard Time, which is five hours behind
 /// UTC, is represented as -05:00. If no time zone value is present, it is considered unknown; it
 /// is not assumed to be UTC.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct XsdDateTime(pub time::OffsetDateTime);
+///
+/// Values without a time zone are read as if they were in UTC, and are written back out without
+/// one. Comparisons only look at the instant, so `2014-12-12T12:12:12` and `2014-12-12T12:12:12Z`
+/// are equal.
+///
+/// Parsed values remember the string they were parsed from, and write it back out verbatim until
+/// the datetime is changed. This keeps signatures over the original JSON valid when a document is
+/// passed through unmodified, even if it used a different precision or offset format.
+///
+/// The fields are private so the datetime can't fall out of step with the string it was parsed
+/// from. Build one with `XsdDateTime::new` or `From<time::OffsetDateTime>`, and read it back with
+/// `as_datetime` or `into_inner`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdDateTime;
+///
+/// let published: XsdDateTime = "2014-12-12T12:12:12".parse()?;
+///
+/// assert!(!published.has_offset());
+/// assert_eq!(published.to_string(), "2014-12-12T12:12:12");
+/// assert_eq!(published, "2014-12-12T12:12:12Z".parse()?);
+///
+/// let mut updated: XsdDateTime = "2014-12-12T12:12:12.000+00:00".parse()?;
+/// assert_eq!(updated.to_string(), "2014-12-12T12:12:12.000+00:00");
+///
+/// *updated.as_datetime_mut() += std::time::Duration::from_secs(1);
+/// assert_eq!(updated.to_string(), "2014-12-12T12:12:13Z");
+/// # Ok(())
+/// # }
+/// ```
+///
+/// `XsdDuration`s can be added to and subtracted from an XsdDateTime, and subtracting two
+/// XsdDateTimes produces the XsdDuration between them. The operators panic if the result is out
+/// of range, so use `checked_add` and `checked_sub` for durations that come from untrusted input.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::{XsdDateTime, XsdDuration};
+///
+/// let created: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+/// let window: XsdDuration = "PT5M".parse()?;
+///
+/// let expires = created.clone() + window.clone();
+/// assert_eq!(expires.to_string(), "2020-04-20T04:25:00Z");
+/// assert_eq!(expires - created, window);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct XsdDateTime {
+    datetime: time::OffsetDateTime,
+
+    /// Whether the parsed value included a time zone
+    has_offset: bool,
+
+    lexical: Option<Lexical>,
+}
+
+/// The string a value was parsed from, and the datetime it was parsed as
+#[derive(Clone, Debug)]
+struct Lexical {
+    source: Box<str>,
+    parsed: time::OffsetDateTime,
+}
+
+impl Lexical {
+    /// The original string, if `datetime` is still what it was parsed as
+    ///
+    /// OffsetDateTime's equality only compares instants, so the offset is checked separately.
+    fn source_for(&self, datetime: &time::OffsetDateTime) -> Option<&str> {
+        if self.parsed == *datetime && self.parsed.offset() == datetime.offset() {
+            Some(&self.source)
+        } else {
+            None
+        }
+    }
+}
 
 impl XsdDateTime {
     /// Create a XsdDateTime from a time::OffsetDateTime
     pub fn new(d: time::OffsetDateTime) -> Self {
-        XsdDateTime(d)
+        XsdDateTime {
+            datetime: d,
+            has_offset: true,
+            lexical: None,
+        }
+    }
+
+    /// Create a XsdDateTime for the current time in UTC
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let closed: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    ///
+    /// assert!(XsdDateTime::now_utc().is_after(&closed));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn now_utc() -> Self {
+        XsdDateTime::new(time::OffsetDateTime::now_utc())
+    }
+
+    /// Check whether this datetime has a known time zone
+    ///
+    /// This is only `false` for values parsed without a time zone, which are treated as UTC.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let published: XsdDateTime = "2020-04-20T04:20:00-05:00".parse()?;
+    /// assert!(published.has_offset());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn has_offset(&self) -> bool {
+        self.has_offset
+    }
+
+    /// The string this datetime was parsed from, if it hasn't been changed since
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let published: XsdDateTime = "2020-04-20T04:20:00.000000Z".parse()?;
+    /// assert_eq!(published.lexical_form(), Some("2020-04-20T04:20:00.000000Z"));
+    ///
+    /// assert_eq!(XsdDateTime::now_utc().lexical_form(), None);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn lexical_form(&self) -> Option<&str> {
+        self.lexical
+            .as_ref()
+            .and_then(|lexical| lexical.source_for(&self.datetime))
     }
 
     /// Extract the time::OffsetDateTime from XsdDateTime
     pub fn into_inner(self) -> time::OffsetDateTime {
-        self.0
+        self.datetime
     }
 
     /// Borrow the underlying `time::OffsetDateTime`
@@ -53,34 +187,367 @@ impl XsdDateTime {
     pub fn as_datetime_mut(&mut self) -> &mut time::OffsetDateTime {
         self.as_mut()
     }
+
+    /// Compute the duration elapsed between `other` and this datetime
+    ///
+    /// If `other` is later than this datetime, the resulting duration is negative
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let created: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    /// let received: XsdDateTime = "2020-04-20T04:25:00+00:00".parse()?;
+    ///
+    /// assert_eq!(received.duration_since(&created).to_string(), "PT5M");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn duration_since(&self, other: &XsdDateTime) -> XsdDuration {
+        XsdDuration::new(self.datetime - other.datetime)
+    }
+
+    /// Check whether this datetime happens before `other`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let closed: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    /// let now = XsdDateTime::now_utc();
+    ///
+    /// assert!(closed.is_before(&now));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_before(&self, other: &XsdDateTime) -> bool {
+        self.datetime < other.datetime
+    }
+
+    /// Check whether this datetime happens after `other`
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDateTime;
+    ///
+    /// let closed: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    /// let now = XsdDateTime::now_utc();
+    ///
+    /// assert!(now.is_after(&closed));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn is_after(&self, other: &XsdDateTime) -> bool {
+        self.datetime > other.datetime
+    }
+
+    /// Add a duration to this datetime, returning `None` if the result is out of range
+    ///
+    /// The result has to fall in the years 0000 through 9999, since those are the only years
+    /// xsd:dateTime's lexical form can be written out with here.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::{XsdDateTime, XsdDuration};
+    ///
+    /// let created: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    ///
+    /// let expires = created.clone().checked_add("PT5M".parse()?);
+    /// assert_eq!(expires.map(|d| d.to_string()).as_deref(), Some("2020-04-20T04:25:00Z"));
+    ///
+    /// assert!(created.checked_add("P9999999DT1S".parse::<XsdDuration>()?).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_add(self, duration: XsdDuration) -> Option<XsdDateTime> {
+        Some(XsdDateTime {
+            datetime: in_range(self.datetime.checked_add(duration.into_inner())?)?,
+            lexical: None,
+            ..self
+        })
+    }
+
+    /// Subtract a duration from this datetime, returning `None` if the result is out of range
+    ///
+    /// The result has to fall in the years 0000 through 9999, since those are the only years
+    /// xsd:dateTime's lexical form can be written out with here.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::{XsdDateTime, XsdDuration};
+    ///
+    /// let created: XsdDateTime = "2020-04-20T04:20:00Z".parse()?;
+    ///
+    /// let earlier = created.clone().checked_sub("PT5M".parse()?);
+    /// assert_eq!(earlier.map(|d| d.to_string()).as_deref(), Some("2020-04-20T04:15:00Z"));
+    ///
+    /// assert!(created.checked_sub("P9999999DT1S".parse::<XsdDuration>()?).is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn checked_sub(self, duration: XsdDuration) -> Option<XsdDateTime> {
+        Some(XsdDateTime {
+            datetime: in_range(self.datetime.checked_sub(duration.into_inner())?)?,
+            lexical: None,
+            ..self
+        })
+    }
+}
+
+/// Only keep datetimes whose year can be formatted as RFC 3339
+fn in_range(datetime: time::OffsetDateTime) -> Option<time::OffsetDateTime> {
+    if (0..=9999).contains(&datetime.year()) {
+        Some(datetime)
+    } else {
+        None
+    }
+}
+
+impl PartialEq for XsdDateTime {
+    fn eq(&self, other: &Self) -> bool {
+        self.datetime == other.datetime
+    }
+}
+
+impl Eq for XsdDateTime {}
+
+impl PartialOrd for XsdDateTime {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for XsdDateTime {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.datetime.cmp(&other.datetime)
+    }
+}
+
+impl std::hash::Hash for XsdDateTime {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.datetime.hash(state)
+    }
+}
+
+/// # Panics
+///
+/// This panics if the result is outside the years 0000 through 9999. Use
+/// `XsdDateTime::checked_add` for durations that come from untrusted input.
+impl std::ops::Add<XsdDuration> for XsdDateTime {
+    type Output = XsdDateTime;
+
+    fn add(self, rhs: XsdDuration) -> Self::Output {
+        self.checked_add(rhs)
+            .expect("resulting value is out of range")
+    }
+}
+
+/// # Panics
+///
+/// This panics if the result is outside the years 0000 through 9999. Use
+/// `XsdDateTime::checked_add` for durations that come from untrusted input.
+impl std::ops::AddAssign<XsdDuration> for XsdDateTime {
+    fn add_assign(&mut self, rhs: XsdDuration) {
+        *self = self.clone() + rhs;
+    }
+}
+
+/// # Panics
+///
+/// This panics if the result is outside the years 0000 through 9999. Use
+/// `XsdDateTime::checked_sub` for durations that come from untrusted input.
+impl std::ops::Sub<XsdDuration> for XsdDateTime {
+    type Output = XsdDateTime;
+
+    fn sub(self, rhs: XsdDuration) -> Self::Output {
+        self.checked_sub(rhs)
+            .expect("resulting value is out of range")
+    }
+}
+
+/// # Panics
+///
+/// This panics if the result is outside the years 0000 through 9999. Use
+/// `XsdDateTime::checked_sub` for durations that come from untrusted input.
+impl std::ops::SubAssign<XsdDuration> for XsdDateTime {
+    fn sub_assign(&mut self, rhs: XsdDuration) {
+        *self = self.clone() - rhs;
+    }
+}
+
+impl std::ops::Sub<XsdDateTime> for XsdDateTime {
+    type Output = XsdDuration;
+
+    fn sub(self, rhs: XsdDateTime) -> Self::Output {
+        self.duration_since(&rhs)
+    }
 }
 
 impl From<time::OffsetDateTime> for XsdDateTime {
     fn from(d: time::OffsetDateTime) -> Self {
-        XsdDateTime(d)
+        XsdDateTime::new(d)
     }
 }
 
 impl From<XsdDateTime> for time::OffsetDateTime {
     fn from(d: XsdDateTime) -> Self {
-        d.0
+        d.datetime
+    }
+}
+
+/// The error type produced when converting a date or duration into a type that can't represent
+/// its value
+#[derive(Clone, Debug)]
+pub struct OutOfRangeError;
+
+impl std::fmt::Display for OutOfRangeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Value is out of range")
+    }
+}
+
+impl std::error::Error for OutOfRangeError {}
+
+/// Convert a SystemTime into an XsdDateTime in UTC
+///
+/// This fails if the SystemTime is outside the years 0000 through 9999, which are the only years
+/// xsd:dateTime's lexical form can be written out with here.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdDateTime;
+/// use std::time::{Duration, SystemTime};
+///
+/// let published =
+///     XsdDateTime::try_from(SystemTime::UNIX_EPOCH + Duration::from_secs(1_587_356_400))?;
+///
+/// assert_eq!(published.to_string(), "2020-04-20T04:20:00Z");
+/// assert_eq!(
+///     SystemTime::try_from(published)?,
+///     SystemTime::UNIX_EPOCH + Duration::from_secs(1_587_356_400)
+/// );
+/// # Ok(())
+/// # }
+/// ```
+impl std::convert::TryFrom<std::time::SystemTime> for XsdDateTime {
+    type Error = Error;
+
+    fn try_from(t: std::time::SystemTime) -> Result<Self, Self::Error> {
+        let datetime = match t.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(since_epoch) => time::Duration::try_from(since_epoch)
+                .ok()
+                .and_then(|since_epoch| time::OffsetDateTime::UNIX_EPOCH.checked_add(since_epoch)),
+            Err(e) => time::Duration::try_from(e.duration())
+                .ok()
+                .and_then(|before_epoch| {
+                    time::OffsetDateTime::UNIX_EPOCH.checked_sub(before_epoch)
+                }),
+        };
+
+        datetime
+            .and_then(in_range)
+            .map(XsdDateTime::new)
+            .ok_or(Error::OutOfRange(OutOfRangeError))
+    }
+}
+
+impl std::convert::TryFrom<XsdDateTime> for std::time::SystemTime {
+    type Error = Error;
+
+    fn try_from(d: XsdDateTime) -> Result<Self, Self::Error> {
+        let since_epoch = d.datetime - time::OffsetDateTime::UNIX_EPOCH;
+        let magnitude =
+            std::time::Duration::try_from(since_epoch.abs()).map_err(|_| OutOfRangeError)?;
+
+        if since_epoch.is_negative() {
+            std::time::SystemTime::UNIX_EPOCH.checked_sub(magnitude)
+        } else {
+            std::time::SystemTime::UNIX_EPOCH.checked_add(magnitude)
+        }
+        .ok_or(Error::OutOfRange(OutOfRangeError))
+    }
+}
+
+/// Convert a chrono DateTime, keeping its offset from UTC
+///
+/// This fails if the DateTime is outside the years 0000 through 9999 in its own offset.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     chrono::{DateTime, FixedOffset, TimeZone, Utc},
+///     primitives::XsdDateTime,
+/// };
+///
+/// let published = Utc.with_ymd_and_hms(2020, 4, 20, 4, 20, 0).unwrap();
+/// let datetime = XsdDateTime::try_from(published)?;
+/// assert_eq!(datetime.to_string(), "2020-04-20T04:20:00Z");
+///
+/// let offset: DateTime<FixedOffset> = "2020-04-20T04:20:00-05:00".parse::<XsdDateTime>()?.try_into()?;
+/// assert_eq!(offset.to_rfc3339(), "2020-04-20T04:20:00-05:00");
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "chrono")]
+impl<Tz> std::convert::TryFrom<chrono::DateTime<Tz>> for XsdDateTime
+where
+    Tz: chrono::TimeZone,
+{
+    type Error = Error;
+
+    fn try_from(d: chrono::DateTime<Tz>) -> Result<Self, Self::Error> {
+        use chrono::Offset;
+
+        let offset = time::UtcOffset::from_whole_seconds(d.offset().fix().local_minus_utc())
+            .map_err(|_| OutOfRangeError)?;
+
+        time::OffsetDateTime::from_unix_timestamp(d.timestamp())
+            .and_then(|utc| utc.replace_nanosecond(d.timestamp_subsec_nanos()))
+            .ok()
+            .and_then(|utc| in_range(utc.to_offset(offset)))
+            .map(XsdDateTime::new)
+            .ok_or(Error::OutOfRange(OutOfRangeError))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<XsdDateTime> for chrono::DateTime<chrono::FixedOffset> {
+    type Error = Error;
+
+    fn try_from(d: XsdDateTime) -> Result<Self, Self::Error> {
+        let offset = chrono::FixedOffset::east_opt(d.datetime.offset().whole_seconds())
+            .ok_or(OutOfRangeError)?;
+
+        chrono::DateTime::from_timestamp(d.datetime.unix_timestamp(), d.datetime.nanosecond())
+            .map(|utc| utc.with_timezone(&offset))
+            .ok_or(Error::OutOfRange(OutOfRangeError))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<XsdDateTime> for chrono::DateTime<chrono::Utc> {
+    type Error = Error;
+
+    fn try_from(d: XsdDateTime) -> Result<Self, Self::Error> {
+        chrono::DateTime::from_timestamp(d.datetime.unix_timestamp(), d.datetime.nanosecond())
+            .ok_or(Error::OutOfRange(OutOfRangeError))
     }
 }
 
 impl AsRef<time::OffsetDateTime> for XsdDateTime {
     fn as_ref(&self) -> &time::OffsetDateTime {
-        &self.0
+        &self.datetime
     }
 }
 
 impl AsMut<time::OffsetDateTime> for XsdDateTime {
     fn as_mut(&mut self) -> &mut time::OffsetDateTime {
-        &mut self.0
+        &mut self.datetime
     }
 }
 
 impl std::convert::TryFrom<String> for XsdDateTime {
-    type Error = time::error::Parse;
+    type Error = Error;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
         s.parse()
@@ -88,7 +555,7 @@ impl std::convert::TryFrom<String> for XsdDateTime {
 }
 
 impl std::convert::TryFrom<&str> for XsdDateTime {
-    type Error = time::error::Parse;
+    type Error = Error;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         s.parse()
@@ -96,7 +563,7 @@ impl std::convert::TryFrom<&str> for XsdDateTime {
 }
 
 impl std::convert::TryFrom<&mut str> for XsdDateTime {
-    type Error = time::error::Parse;
+    type Error = Error;
 
     fn try_from(s: &mut str) -> Result<Self, Self::Error> {
         s.parse()
@@ -104,22 +571,58 @@ impl std::convert::TryFrom<&mut str> for XsdDateTime {
 }
 
 impl std::str::FromStr for XsdDateTime {
-    type Err = time::error::Parse;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(XsdDateTime(time::OffsetDateTime::parse(
-            s,
-            &time::format_description::well_known::Rfc3339,
-        )?))
+        let format = &time::format_description::well_known::Rfc3339;
+
+        let (d, has_offset) = match time::OffsetDateTime::parse(s, format) {
+            Ok(d) => (d, true),
+            Err(e) => time::OffsetDateTime::parse(&format!("{}Z", s), format)
+                .map(|d| (d, false))
+                .map_err(|_| e)?,
+        };
+
+        let lexical = Lexical {
+            source: s.into(),
+            parsed: d,
+        };
+
+        Ok(XsdDateTime {
+            datetime: d,
+            has_offset,
+            lexical: Some(lexical),
+        })
+    }
+}
+
+impl XsdDateTime {
+    /// Write this datetime out, failing if its year can't be formatted as RFC 3339
+    fn format(&self) -> Result<std::borrow::Cow<'_, str>, time::error::Format> {
+        if let Some(s) = self.lexical_form() {
+            return Ok(s.into());
+        }
+
+        let mut s = self
+            .datetime
+            .format(&time::format_description::well_known::Rfc3339)?;
+
+        if !self.has_offset() {
+            let len = if s.ends_with('Z') {
+                s.len() - 1
+            } else {
+                s.len() - "+00:00".len()
+            };
+            s.truncate(len);
+        }
+
+        Ok(s.into())
     }
 }
 
 impl std::fmt::Display for XsdDateTime {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let s = self
-            .0
-            .format(&time::format_description::well_known::Rfc3339)
-            .map_err(|_| std::fmt::Error)?;
+        let s = self.format().map_err(|_| std::fmt::Error)?;
         std::fmt::Display::fmt(&s, f)
     }
 }
@@ -129,7 +632,8 @@ impl serde::ser::Serialize for XsdDateTime {
     where
         S: serde::ser::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        let s = self.format().map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&s)
     }
 }
 
@@ -142,3 +646,144 @@ impl<'de> serde::de::Deserialize<'de> for XsdDateTime {
         s.parse().map_err(serde::de::Error::custom)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::XsdDateTime;
+
+    #[test]
+    fn parse_without_offset() {
+        let datetime: XsdDateTime = "2014-12-12T12:12:12.5".parse().unwrap();
+
+        assert!(!datetime.has_offset());
+        assert_eq!(datetime.datetime.offset(), time::UtcOffset::UTC);
+        assert_eq!(datetime.to_string(), "2014-12-12T12:12:12.5");
+        assert_eq!(
+            serde_json::to_string(&datetime).unwrap(),
+            r#""2014-12-12T12:12:12.5""#
+        );
+    }
+
+    #[test]
+    fn keep_missing_offset_through_arithmetic() {
+        let datetime: XsdDateTime = "2014-12-12T12:12:12".parse().unwrap();
+        let later = datetime + "PT1H".parse().unwrap();
+
+        assert_eq!(later.to_string(), "2014-12-12T13:12:12");
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for s in [
+            "2014-12-12",
+            "2014-12-12T12:12",
+            "2014-12-12T12:12:12ZZ",
+            "2014-12-12T12:12:12+00:00Z",
+        ] {
+            assert!(s.parse::<XsdDateTime>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in [
+            "2020-04-20T04:20:00Z",
+            "2020-04-20T04:20:00.5-05:00",
+            "2014-12-12T12:12:12",
+        ] {
+            let parsed: XsdDateTime = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdDateTime>(&json).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn preserve_lexical_form() {
+        for s in [
+            "2020-04-20T04:20:00.000Z",
+            "2020-04-20T04:20:00+00:00",
+            "2020-04-20T04:20:00.123456789-05:00",
+            "2020-04-20t04:20:00z",
+        ] {
+            let parsed: XsdDateTime = s.parse().unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(
+                serde_json::to_string(&parsed).unwrap(),
+                format!("\"{}\"", s)
+            );
+        }
+    }
+
+    #[test]
+    fn reformat_after_changing_offset() {
+        let mut datetime: XsdDateTime = "2020-04-20T04:20:00.000-05:00".parse().unwrap();
+        datetime.datetime = datetime.datetime.to_offset(time::UtcOffset::UTC);
+
+        assert_eq!(datetime.lexical_form(), None);
+        assert_eq!(datetime.to_string(), "2020-04-20T09:20:00Z");
+    }
+
+    #[test]
+    fn checked_arithmetic_out_of_range() {
+        let created: XsdDateTime = "2020-04-20T04:20:00Z".parse().unwrap();
+
+        assert!(created
+            .clone()
+            .checked_sub("P800000D".parse().unwrap())
+            .is_none());
+        assert!(created.checked_add("P3000000D".parse().unwrap()).is_none());
+
+        let first: XsdDateTime = "0000-01-01T00:00:00Z".parse().unwrap();
+        assert!(first.clone().checked_sub("PT1S".parse().unwrap()).is_none());
+        assert_eq!(
+            serde_json::to_string(&first).unwrap(),
+            r#""0000-01-01T00:00:00Z""#
+        );
+    }
+
+    #[test]
+    fn system_time_out_of_range() {
+        use std::time::{Duration, SystemTime};
+
+        let year_10000 = SystemTime::UNIX_EPOCH + Duration::from_secs(253_402_300_800);
+        assert!(XsdDateTime::try_from(year_10000).is_err());
+
+        let before_epoch = SystemTime::UNIX_EPOCH - Duration::from_secs(86_400);
+        assert_eq!(
+            XsdDateTime::try_from(before_epoch).unwrap().to_string(),
+            "1969-12-31T00:00:00Z"
+        );
+
+        let before_year_0 = SystemTime::UNIX_EPOCH - Duration::from_secs(3_000 * 31_556_952);
+        assert!(XsdDateTime::try_from(before_year_0).is_err());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_out_of_range() {
+        use chrono::{TimeZone, Utc};
+
+        let before_year_0 = Utc.with_ymd_and_hms(-1, 12, 31, 23, 59, 59).unwrap();
+        assert!(XsdDateTime::try_from(before_year_0).is_err());
+
+        let year_0 = Utc.with_ymd_and_hms(0, 1, 1, 0, 0, 0).unwrap();
+        assert_eq!(
+            XsdDateTime::try_from(year_0).unwrap().to_string(),
+            "0000-01-01T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn unformattable_year_errors() {
+        use std::fmt::Write;
+
+        let mut datetime: XsdDateTime = "2020-04-20T04:20:00Z".parse().unwrap();
+        *datetime.as_datetime_mut() = datetime.datetime.replace_year(-1).unwrap();
+
+        assert!(serde_json::to_string(&datetime).is_err());
+        assert!(write!(String::new(), "{}", datetime).is_err());
+    }
+}
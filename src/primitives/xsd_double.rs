@@ -0,0 +1,200 @@
+use crate::primitives::{Either, Error, XsdFloat};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:double represents an IEEE double-precision 64-bit floating-point number.
+///
+/// ActivityStreams itself only uses xsd:float, but extensions carrying measurements or scores
+/// declare their properties as xsd:double, and this type keeps those distinct in the type system.
+///
+/// Like XsdFloat, this deserializes from both JSON numbers and numeric strings, but always
+/// serializes as a JSON number.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdDouble;
+///
+/// let from_number: XsdDouble = serde_json::from_str("0.1234567890123456")?;
+/// let from_string: XsdDouble = serde_json::from_str(r#""0.1234567890123456""#)?;
+///
+/// assert_eq!(from_number, from_string);
+/// assert_eq!(serde_json::to_string(&from_string)?, "0.1234567890123456");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct XsdDouble(pub f64);
+
+/// The error type produced when an XsdDouble cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdDoubleError;
+
+impl std::fmt::Display for XsdDoubleError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse Double")
+    }
+}
+
+impl std::error::Error for XsdDoubleError {}
+
+impl XsdDouble {
+    /// Construct a new XsdDouble
+    pub fn new(f: f64) -> Self {
+        Self(f)
+    }
+
+    /// Retreive the inner f64
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl PartialEq<f64> for XsdDouble {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<XsdDouble> for f64 {
+    fn eq(&self, other: &XsdDouble) -> bool {
+        *self == other.0
+    }
+}
+
+impl Deref for XsdDouble {
+    type Target = f64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdDouble {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<f64> for XsdDouble {
+    fn as_ref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl AsMut<f64> for XsdDouble {
+    fn as_mut(&mut self) -> &mut f64 {
+        &mut self.0
+    }
+}
+
+impl From<f64> for XsdDouble {
+    fn from(f: f64) -> Self {
+        Self(f)
+    }
+}
+
+impl From<XsdDouble> for f64 {
+    fn from(f: XsdDouble) -> Self {
+        f.0
+    }
+}
+
+impl From<XsdFloat> for XsdDouble {
+    fn from(f: XsdFloat) -> Self {
+        Self(f.0)
+    }
+}
+
+impl std::str::FromStr for XsdDouble {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().parse::<f64>() {
+            Ok(f) if f.is_finite() => Ok(XsdDouble(f)),
+            _ => Err(Error::Double(XsdDoubleError)),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdDouble {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdDouble {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdDouble {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdDouble {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdDouble {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<f64, String> = Deserialize::<'de>::deserialize(deserializer)?;
+
+        match helper {
+            Either::Left(f) => Ok(XsdDouble(f)),
+            Either::Right(s) => match s.trim().parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(XsdDouble(f)),
+                _ => Err(serde::de::Error::custom("Invalid double")),
+            },
+        }
+    }
+}
+
+impl Serialize for XsdDouble {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdDouble;
+
+    #[test]
+    fn dont_deserialize_invalid_strings() {
+        for case in [r#""""#, r#""north""#, r#""NaN""#, r#""-inf""#, "true"] {
+            assert!(serde_json::from_str::<XsdDouble>(case).is_err());
+        }
+    }
+
+    #[cfg(feature = "float-roundtrip")]
+    #[test]
+    fn round_trip_beyond_single_precision() {
+        for s in ["3.141592653589793", "1e300", "-2.2250738585072014e-308"] {
+            let parsed: XsdDouble = serde_json::from_str(s).unwrap();
+
+            assert_eq!(parsed, s.parse::<f64>().unwrap());
+            assert_eq!(
+                serde_json::from_str::<XsdDouble>(&serde_json::to_string(&parsed).unwrap())
+                    .unwrap(),
+                parsed
+            );
+        }
+    }
+}
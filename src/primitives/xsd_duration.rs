@@ -17,6 +17,8 @@
  * along with ActivityStreams.  If not, see <http://www.gnu.org/licenses/>.
  */
 
+use crate::primitives::{Error, OutOfRangeError};
+
 /// The type xsd:duration represents a duration of time expressed as a number of years, months,
 /// days, hours, minutes, and seconds.
 ///
@@ -36,13 +38,69 @@
 /// - A minus sign may appear before the P to specify a negative duration.
 /// - If no time items (hour, minute, second) are present, the letter T must not appear.
 ///
+/// ISO8601's weeks designator (e.g. `P2W`) is not part of xsd:duration, but is accepted when
+/// parsing since it shows up in the wild. Weeks are always written back out as days.
+///
 /// ### Note
 ///
-/// This implementation converts Months to Days by multiplying by 31, and converts Years to days by
-/// multiplying by 365. If this is an issue for your application, look into specifying days
-/// directly.
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-pub struct XsdDuration(pub time::Duration);
+/// The `time::Duration` view of this type converts Months to Days by multiplying by 31, and
+/// converts Years to days by multiplying by 365. The years and months that were parsed are kept
+/// alongside it, so a parsed duration is written back out in the same form it was read in.
+///
+/// `PartialEq`, `Ord` and `Hash` only look at the `time::Duration`, so `P1M` and `P31D` are
+/// equal and hash the same, even though they are written differently. Compare `to_string()` to
+/// tell them apart.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdDuration;
+///
+/// let month: XsdDuration = "P1M".parse()?;
+/// let days: XsdDuration = "P31D".parse()?;
+///
+/// assert_eq!(month, days);
+/// assert_ne!(month.to_string(), days.to_string());
+/// # Ok(())
+/// # }
+/// ```
+///
+/// The fields are private so the years and months can't fall out of step with the duration.
+/// Build one with `XsdDuration::new` or `From<time::Duration>`, and read it back with
+/// `as_duration` or `into_inner`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{primitives::XsdDuration, time::Duration};
+///
+/// let duration: XsdDuration = "P1Y2MT3H".parse()?;
+///
+/// assert_eq!(duration.years(), 1);
+/// assert_eq!(duration.months(), 2);
+/// assert_eq!(
+///     *duration.as_duration(),
+///     Duration::days(365 + 62) + Duration::hours(3)
+/// );
+/// assert_eq!(duration.to_string(), "P1Y2MT3H");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct XsdDuration(time::Duration, Calendar);
+
+/// The years and months an XsdDuration was parsed from
+#[derive(Clone, Copy, Debug, Default)]
+struct Calendar {
+    years: u64,
+    months: u64,
+}
+
+impl Calendar {
+    fn days(&self) -> Option<i64> {
+        let years = i64::try_from(self.years).ok()?.checked_mul(365)?;
+        let months = i64::try_from(self.months).ok()?.checked_mul(31)?;
+        years.checked_add(months)
+    }
+}
 
 /// The error type produced when an XsdDuration cannot be parsed
 #[derive(Clone, Debug)]
@@ -59,7 +117,7 @@ impl std::error::Error for XsdDurationError {}
 impl XsdDuration {
     /// Create a new XsdDuration from a time::Duration
     pub fn new(duration: time::Duration) -> Self {
-        XsdDuration(duration)
+        XsdDuration(duration, Calendar::default())
     }
 
     /// Extract the time::Duration from an XsdDuration
@@ -76,11 +134,155 @@ impl XsdDuration {
     pub fn as_duration_mut(&mut self) -> &mut time::Duration {
         self.as_mut()
     }
+
+    /// Create a new XsdDuration from days, hours, minutes, and seconds
+    ///
+    /// Values are not required to be normalized, so `from_parts(0, 0, 90, 0)` is the same as
+    /// `from_parts(0, 1, 30, 0)`. This fails if the total doesn't fit in a `time::Duration`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDuration;
+    ///
+    /// let duration = XsdDuration::from_parts(1, 2, 90, 5)?;
+    ///
+    /// assert_eq!(duration.to_string(), "P1DT3H30M5S");
+    /// assert_eq!(duration.days(), 1);
+    /// assert_eq!(duration.hours(), 3);
+    /// assert_eq!(duration.minutes(), 30);
+    /// assert_eq!(duration.seconds(), 5);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn from_parts(
+        days: u64,
+        hours: u64,
+        minutes: u64,
+        seconds: u64,
+    ) -> Result<Self, OutOfRangeError> {
+        let seconds = days
+            .checked_mul(SECONDS_PER_DAY)
+            .and_then(|total| total.checked_add(hours.checked_mul(3600)?))
+            .and_then(|total| total.checked_add(minutes.checked_mul(60)?))
+            .and_then(|total| total.checked_add(seconds))
+            .and_then(|total| i64::try_from(total).ok())
+            .ok_or(OutOfRangeError)?;
+
+        Ok(XsdDuration::new(time::Duration::seconds(seconds)))
+    }
+
+    /// Check whether this is a negative duration
+    pub fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+
+    /// The number of years this duration was written with
+    ///
+    /// This is zero for durations created from a `time::Duration`.
+    pub fn years(&self) -> u64 {
+        self.1.years
+    }
+
+    /// The number of months this duration was written with
+    ///
+    /// This is zero for durations created from a `time::Duration`.
+    pub fn months(&self) -> u64 {
+        self.1.months
+    }
+
+    /// The number of days this duration is written with
+    ///
+    /// Like the other components, this is taken from the duration's magnitude, after any years
+    /// and months it was parsed with, so `-P1Y2D` has 2 days.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDuration;
+    ///
+    /// let duration: XsdDuration = "-P1Y2DT36H".parse()?;
+    ///
+    /// assert!(duration.is_negative());
+    /// assert_eq!(duration.years(), 1);
+    /// assert_eq!(duration.days(), 3);
+    /// assert_eq!(duration.hours(), 12);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn days(&self) -> u64 {
+        self.components().days
+    }
+
+    /// The number of hours this duration is written with, from 0 to 23
+    pub fn hours(&self) -> u64 {
+        self.components().hours
+    }
+
+    /// The number of minutes this duration is written with, from 0 to 59
+    pub fn minutes(&self) -> u64 {
+        self.components().minutes
+    }
+
+    /// The number of whole seconds this duration is written with, from 0 to 59
+    pub fn seconds(&self) -> u64 {
+        self.components().seconds
+    }
+
+    /// The fractional part of this duration's seconds, in nanoseconds
+    pub fn nanoseconds(&self) -> u32 {
+        self.components().nanoseconds
+    }
+
+    /// Convert the duration into a `std::time::Duration`
+    ///
+    /// This fails for negative durations, since std durations can't be negative
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::primitives::XsdDuration;
+    /// use std::time::Duration;
+    ///
+    /// let duration: XsdDuration = "PT1M30.5S".parse()?;
+    /// assert_eq!(duration.try_into_std()?, Duration::from_millis(90_500));
+    ///
+    /// let duration: XsdDuration = "-PT1M".parse()?;
+    /// assert!(duration.try_into_std().is_err());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn try_into_std(&self) -> Result<std::time::Duration, OutOfRangeError> {
+        std::time::Duration::try_from(self.0).map_err(|_| OutOfRangeError)
+    }
+}
+
+impl PartialEq for XsdDuration {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl Eq for XsdDuration {}
+
+impl PartialOrd for XsdDuration {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for XsdDuration {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.0.cmp(&other.0)
+    }
+}
+
+impl std::hash::Hash for XsdDuration {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.0.hash(state)
+    }
 }
 
 impl From<time::Duration> for XsdDuration {
     fn from(d: time::Duration) -> Self {
-        XsdDuration(d)
+        XsdDuration::new(d)
     }
 }
 
@@ -90,6 +292,85 @@ impl From<XsdDuration> for time::Duration {
     }
 }
 
+/// Convert a `std::time::Duration`
+///
+/// This fails for durations longer than `i64::MAX` seconds
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{object::Video, prelude::*, primitives::XsdDuration};
+/// use std::time::Duration;
+///
+/// let mut video = Video::new();
+/// video.set_duration(XsdDuration::try_from(Duration::from_secs(272))?.into_inner());
+///
+/// assert_eq!(
+///     serde_json::to_value(&video)?["duration"],
+///     serde_json::json!("PT4M32S")
+/// );
+/// # Ok(())
+/// # }
+/// ```
+impl std::convert::TryFrom<std::time::Duration> for XsdDuration {
+    type Error = Error;
+
+    fn try_from(d: std::time::Duration) -> Result<Self, Self::Error> {
+        time::Duration::try_from(d)
+            .map(XsdDuration::new)
+            .map_err(|_| Error::OutOfRange(OutOfRangeError))
+    }
+}
+
+impl std::convert::TryFrom<XsdDuration> for std::time::Duration {
+    type Error = Error;
+
+    fn try_from(d: XsdDuration) -> Result<Self, Self::Error> {
+        Ok(d.try_into_std()?)
+    }
+}
+
+/// Convert a chrono Duration
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{chrono, primitives::XsdDuration};
+///
+/// let duration = XsdDuration::from(chrono::Duration::milliseconds(-1500));
+/// assert_eq!(duration.to_string(), "-PT1.5S");
+///
+/// let duration: chrono::Duration = "P1DT2H".parse::<XsdDuration>()?.try_into()?;
+/// assert_eq!(duration, chrono::Duration::hours(26));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "chrono")]
+impl From<chrono::Duration> for XsdDuration {
+    fn from(d: chrono::Duration) -> Self {
+        XsdDuration::new(time::Duration::new(d.num_seconds(), d.subsec_nanos()))
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl std::convert::TryFrom<XsdDuration> for chrono::Duration {
+    type Error = Error;
+
+    fn try_from(d: XsdDuration) -> Result<Self, Self::Error> {
+        let seconds = d.0.whole_seconds();
+        let nanoseconds = d.0.subsec_nanoseconds();
+
+        // chrono keeps nanoseconds positive, borrowing a second from negative durations
+        let duration = if nanoseconds < 0 {
+            seconds.checked_sub(1).and_then(|seconds| {
+                chrono::Duration::new(seconds, (nanoseconds + 1_000_000_000) as u32)
+            })
+        } else {
+            chrono::Duration::new(seconds, nanoseconds as u32)
+        };
+
+        duration.ok_or(Error::OutOfRange(OutOfRangeError))
+    }
+}
+
 impl AsRef<time::Duration> for XsdDuration {
     fn as_ref(&self) -> &time::Duration {
         &self.0
@@ -103,7 +384,7 @@ impl AsMut<time::Duration> for XsdDuration {
 }
 
 impl std::convert::TryFrom<String> for XsdDuration {
-    type Error = XsdDurationError;
+    type Error = Error;
 
     fn try_from(s: String) -> Result<Self, Self::Error> {
         s.parse()
@@ -111,7 +392,7 @@ impl std::convert::TryFrom<String> for XsdDuration {
 }
 
 impl std::convert::TryFrom<&str> for XsdDuration {
-    type Error = XsdDurationError;
+    type Error = Error;
 
     fn try_from(s: &str) -> Result<Self, Self::Error> {
         s.parse()
@@ -119,7 +400,7 @@ impl std::convert::TryFrom<&str> for XsdDuration {
 }
 
 impl std::convert::TryFrom<&mut str> for XsdDuration {
-    type Error = XsdDurationError;
+    type Error = Error;
 
     fn try_from(s: &mut str) -> Result<Self, Self::Error> {
         s.parse()
@@ -127,49 +408,84 @@ impl std::convert::TryFrom<&mut str> for XsdDuration {
 }
 
 impl std::str::FromStr for XsdDuration {
-    type Err = XsdDurationError;
+    type Err = Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        if s.find('P') != Some(0) {
-            return Err(XsdDurationError);
-        }
+        Ok(parse_duration(s)?)
+    }
+}
 
-        let s = s.trim_start_matches('P');
+fn parse_duration(s: &str) -> Result<XsdDuration, XsdDurationError> {
+    let (negative, s) = strip_sign(s);
+    let s = s.strip_prefix('P').ok_or(XsdDurationError)?;
 
-        let negative = Some(0) == s.find('-');
-        let s = s.trim_start_matches('-');
+    // Older versions of this crate wrote the sign after the P, keep reading that form
+    let (negative, s) = if negative {
+        (negative, s)
+    } else {
+        strip_sign(s)
+    };
 
-        let (large, small) = if let Some(index) = s.find('T') {
-            let (l, s) = s.split_at(index);
-            (l, s.trim_start_matches('T'))
-        } else {
-            (s, "")
-        };
+    let (large, small) = if let Some(index) = s.find('T') {
+        let (l, s) = s.split_at(index);
+        (l, s.trim_start_matches('T'))
+    } else {
+        (s, "")
+    };
+
+    let (years, large) = parse_next(large, 'Y')?;
+    let (months, large) = parse_next(large, 'M')?;
+    let (weeks, large) = parse_next(large, 'W')?;
+    let (days, _) = parse_next(large, 'D')?;
 
-        let (years, large) = parse_next(large, 'Y')?;
-        let (months, large) = parse_next(large, 'M')?;
-        let (days, _) = parse_next(large, 'D')?;
+    let (hours, small) = parse_next(small, 'H')?;
+    let (minutes, small) = parse_next(small, 'M')?;
+    let seconds = parse_seconds(small)?;
 
-        let (hours, small) = parse_next(small, 'H')?;
-        let (minutes, small) = parse_next(small, 'M')?;
-        let (seconds, _) = parse_next(small, 'S')?;
+    let calendar = Calendar {
+        years: years as u64,
+        months: months as u64,
+    };
 
-        let mut duration = time::Duration::days(365 * years);
-        duration += time::Duration::days(31 * months);
-        duration += time::Duration::days(days);
-        duration += time::Duration::hours(hours);
-        duration += time::Duration::minutes(minutes);
-        duration += time::Duration::seconds(seconds);
+    let duration = [
+        calendar
+            .days()
+            .and_then(|days| days.checked_mul(SECONDS_PER_DAY as i64)),
+        weeks.checked_mul(7 * SECONDS_PER_DAY as i64),
+        days.checked_mul(SECONDS_PER_DAY as i64),
+        hours.checked_mul(3600),
+        minutes.checked_mul(60),
+    ]
+    .into_iter()
+    .try_fold(seconds, |duration, component| {
+        duration.checked_add(time::Duration::seconds(component?))
+    })
+    .ok_or(XsdDurationError)?;
+
+    let duration = if negative {
+        duration.checked_neg().ok_or(XsdDurationError)?
+    } else {
+        duration
+    };
 
-        duration = if negative { duration * -1 } else { duration };
+    Ok(XsdDuration(duration, calendar))
+}
 
-        Ok(XsdDuration(duration))
+fn strip_sign(s: &str) -> (bool, &str) {
+    match s.strip_prefix('-') {
+        Some(s) => (true, s),
+        None => (false, s),
     }
 }
 
 fn parse_next(s: &str, c: char) -> Result<(i64, &str), XsdDurationError> {
     let res = if let Some(index) = s.find(c) {
         let (beginning, end) = s.split_at(index);
+
+        if !beginning.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(XsdDurationError);
+        }
+
         let i = beginning.parse().map_err(|_| XsdDurationError)?;
         (i, end.trim_start_matches(c))
     } else {
@@ -179,49 +495,143 @@ fn parse_next(s: &str, c: char) -> Result<(i64, &str), XsdDurationError> {
     Ok(res)
 }
 
+fn parse_seconds(s: &str) -> Result<time::Duration, XsdDurationError> {
+    let Some(index) = s.find('S') else {
+        return Ok(time::Duration::ZERO);
+    };
+
+    let (whole, fraction) = match s[..index].split_once('.') {
+        Some((whole, fraction)) => (whole, Some(fraction)),
+        None => (&s[..index], None),
+    };
+
+    if whole.is_empty() || !whole.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(XsdDurationError);
+    }
+
+    let seconds: i64 = whole.parse().map_err(|_| XsdDurationError)?;
+
+    let nanoseconds = match fraction {
+        Some(fraction) => {
+            if fraction.is_empty() || !fraction.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(XsdDurationError);
+            }
+
+            // Digits past nanosecond precision are dropped
+            let digits = &fraction[..fraction.len().min(9)];
+            digits.parse::<i32>().map_err(|_| XsdDurationError)?
+                * 10i32.pow(9 - digits.len() as u32)
+        }
+        None => 0,
+    };
+
+    time::Duration::seconds(seconds)
+        .checked_add(time::Duration::nanoseconds(nanoseconds.into()))
+        .ok_or(XsdDurationError)
+}
+
+const SECONDS_PER_DAY: u64 = 86_400;
+
+/// The magnitude of an XsdDuration, split into the fields it is written with
+struct Components {
+    calendar: Calendar,
+    days: u64,
+    hours: u64,
+    minutes: u64,
+    seconds: u64,
+    nanoseconds: u32,
+}
+
+impl XsdDuration {
+    fn components(&self) -> Components {
+        // abs saturates rather than overflowing, so this conversion can't fail
+        let mut duration = std::time::Duration::try_from(self.0.abs()).unwrap_or_default();
+
+        // Only use the parsed years and months if the duration still covers them
+        let calendar = self
+            .1
+            .days()
+            .and_then(|days| u64::try_from(days).ok())
+            .and_then(|days| days.checked_mul(SECONDS_PER_DAY))
+            .map(std::time::Duration::from_secs)
+            .filter(|days| duration >= *days && (self.1.years > 0 || self.1.months > 0))
+            .map(|days| {
+                duration -= days;
+                self.1
+            })
+            .unwrap_or_default();
+
+        let seconds = duration.as_secs();
+
+        Components {
+            calendar,
+            days: seconds / SECONDS_PER_DAY,
+            hours: seconds % SECONDS_PER_DAY / 3600,
+            minutes: seconds % 3600 / 60,
+            seconds: seconds % 60,
+            nanoseconds: duration.subsec_nanos(),
+        }
+    }
+}
+
 impl std::fmt::Display for XsdDuration {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let (s, mut duration) = if time::Duration::seconds(0) > self.0 {
-            ("P-".to_string(), self.0 * -1)
-        } else {
-            ("P".to_string(), self.0)
-        };
+        let Components {
+            calendar,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanoseconds,
+        } = self.components();
+
+        if calendar.years == 0
+            && calendar.months == 0
+            && days == 0
+            && hours == 0
+            && minutes == 0
+            && seconds == 0
+            && nanoseconds == 0
+        {
+            return std::fmt::Display::fmt("PT0S", f);
+        }
 
-        let s = if duration.whole_days() > 0 {
-            format!("{}{}D", s, duration.whole_days())
+        let mut s = if self.0.is_negative() {
+            "-P".to_string()
         } else {
-            s
+            "P".to_string()
         };
 
-        duration -= time::Duration::days(duration.whole_days());
+        if calendar.years > 0 {
+            s.push_str(&format!("{}Y", calendar.years));
+        }
 
-        let s = if duration.whole_seconds() > 0 {
-            format!("{}T", s)
-        } else {
-            s
-        };
+        if calendar.months > 0 {
+            s.push_str(&format!("{}M", calendar.months));
+        }
 
-        let s = if duration.whole_hours() > 0 {
-            format!("{}{}H", s, duration.whole_hours())
-        } else {
-            s
-        };
+        if days > 0 {
+            s.push_str(&format!("{}D", days));
+        }
 
-        duration -= time::Duration::hours(duration.whole_hours());
+        if hours > 0 || minutes > 0 || seconds > 0 || nanoseconds > 0 {
+            s.push('T');
+        }
 
-        let s = if duration.whole_minutes() > 0 {
-            format!("{}{}M", s, duration.whole_minutes())
-        } else {
-            s
-        };
+        if hours > 0 {
+            s.push_str(&format!("{}H", hours));
+        }
 
-        duration -= time::Duration::minutes(duration.whole_minutes());
+        if minutes > 0 {
+            s.push_str(&format!("{}M", minutes));
+        }
 
-        let s = if duration.whole_seconds() > 0 {
-            format!("{}{}S", s, duration.whole_seconds())
-        } else {
-            s
-        };
+        if nanoseconds > 0 {
+            let fraction = format!("{:09}", nanoseconds);
+            s.push_str(&format!("{}.{}S", seconds, fraction.trim_end_matches('0')));
+        } else if seconds > 0 {
+            s.push_str(&format!("{}S", seconds));
+        }
 
         std::fmt::Display::fmt(&s, f)
     }
@@ -245,3 +655,203 @@ impl<'de> serde::de::Deserialize<'de> for XsdDuration {
         s.parse().map_err(serde::de::Error::custom)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::XsdDuration;
+
+    #[test]
+    fn parse_weeks() {
+        let duration: XsdDuration = "P2W".parse().unwrap();
+
+        assert_eq!(duration.0, time::Duration::days(14));
+        assert_eq!(duration.to_string(), "P14D");
+    }
+
+    #[test]
+    fn parse_weeks_with_days() {
+        let duration: XsdDuration = "P1W3DT4H".parse().unwrap();
+
+        assert_eq!(
+            duration.0,
+            time::Duration::days(10) + time::Duration::hours(4)
+        );
+    }
+
+    #[test]
+    fn display_zero() {
+        let duration = XsdDuration::new(time::Duration::ZERO);
+
+        assert_eq!(duration.to_string(), "PT0S");
+        assert_eq!(
+            XsdDuration::new(time::Duration::nanoseconds(0)).to_string(),
+            "PT0S"
+        );
+    }
+
+    #[test]
+    fn display_negative() {
+        let duration = XsdDuration::new(time::Duration::days(-1) - time::Duration::hours(2));
+
+        assert_eq!(duration.to_string(), "-P1DT2H");
+    }
+
+    #[test]
+    fn parse_negative() {
+        let expected = time::Duration::days(-1);
+
+        assert_eq!("-P1D".parse::<XsdDuration>().unwrap().0, expected);
+        assert_eq!("P-1D".parse::<XsdDuration>().unwrap().0, expected);
+        assert!("--P1D".parse::<XsdDuration>().is_err());
+        assert!("-P-1D".parse::<XsdDuration>().is_err());
+    }
+
+    #[test]
+    fn parse_fractional_seconds() {
+        let duration: XsdDuration = "PT0.5S".parse().unwrap();
+        assert_eq!(duration.0, time::Duration::milliseconds(500));
+        assert_eq!(duration.to_string(), "PT0.5S");
+
+        let duration: XsdDuration = "PT4.20S".parse().unwrap();
+        assert_eq!(duration.0, time::Duration::milliseconds(4200));
+        assert_eq!(duration.to_string(), "PT4.2S");
+
+        let duration: XsdDuration = "-P1DT0.000000001999S".parse().unwrap();
+        assert_eq!(
+            duration.0,
+            -(time::Duration::days(1) + time::Duration::nanoseconds(1))
+        );
+        assert_eq!(duration.to_string(), "-P1DT0.000000001S");
+
+        assert_eq!(
+            XsdDuration::new(time::Duration::milliseconds(-500)).to_string(),
+            "-PT0.5S"
+        );
+    }
+
+    #[test]
+    fn dont_parse_invalid_seconds() {
+        for s in ["PT.5S", "PT1.S", "PT1.5.5S", "PT-1.5S", "PT1,5S"] {
+            assert!(s.parse::<XsdDuration>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let components = [0, 1, 2, 9, 10, 23, 59, 60, 61, 3599, 3600, 86399, 86400];
+
+        for days in [0, 1, 2, 30, 365, 36500] {
+            for seconds in components {
+                for negative in [false, true] {
+                    let mut duration =
+                        time::Duration::days(days) + time::Duration::seconds(seconds);
+
+                    if negative {
+                        duration = -duration;
+                    }
+
+                    let original = XsdDuration::new(duration);
+                    let s = original.to_string();
+                    let parsed: XsdDuration = s.parse().unwrap();
+
+                    assert_eq!(original, parsed, "{} did not round-trip", s);
+                    assert_eq!(parsed.to_string(), s);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn round_trip_pseudorandom() {
+        // A fixed linear congruential generator keeps this test deterministic while still
+        // covering durations spanning many components
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+
+        for _ in 0..10_000 {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1_442_695_040_888_963_407);
+
+            let seconds = (state >> 24) as i64 - (1 << 39);
+            let original = XsdDuration::new(time::Duration::seconds(seconds));
+            let s = original.to_string();
+            let parsed: XsdDuration = s.parse().unwrap();
+
+            assert_eq!(original, parsed, "{} did not round-trip", s);
+        }
+    }
+
+    #[test]
+    fn round_trip_calendar() {
+        for s in ["P1M", "P1Y", "P1Y2M", "P2Y10DT1S", "-P1Y1MT5M", "P14M"] {
+            let parsed: XsdDuration = s.parse().unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+        }
+
+        let month: XsdDuration = "P1M".parse().unwrap();
+        assert_eq!(month, "P31D".parse::<XsdDuration>().unwrap());
+        assert_eq!(month.0, time::Duration::days(31));
+    }
+
+    #[test]
+    fn display_calendar_after_mutation() {
+        let mut duration: XsdDuration = "P1Y".parse().unwrap();
+
+        *duration.as_duration_mut() += time::Duration::days(1);
+        assert_eq!(duration.to_string(), "P1Y1D");
+
+        *duration.as_duration_mut() = time::Duration::days(30);
+        assert_eq!(duration.to_string(), "P30D");
+    }
+
+    #[test]
+    fn dont_parse_invalid_weeks() {
+        assert!("P1.5W".parse::<XsdDuration>().is_err());
+        assert!("PW".parse::<XsdDuration>().is_err());
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["PT0S", "P1Y2M3DT4H5M6.5S", "-P14D"] {
+            let parsed: XsdDuration = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdDuration>(&json).unwrap(), parsed);
+        }
+    }
+
+    #[test]
+    fn dont_parse_out_of_range() {
+        for s in [
+            "P9999999999999999W",
+            "P99999999999999999D",
+            "PT9999999999999999H",
+            "PT999999999999999999M",
+            "PT9223372036854775808S",
+            "P9223372036854775807Y",
+            "P106751991167300DT9223372036854775807S",
+        ] {
+            assert!(s.parse::<XsdDuration>().is_err(), "{}", s);
+            assert!(
+                serde_json::from_str::<XsdDuration>(&format!("{:?}", s)).is_err(),
+                "{}",
+                s
+            );
+        }
+
+        let max = format!("PT{}S", i64::MAX);
+        assert_eq!(
+            max.parse::<XsdDuration>().unwrap().into_inner(),
+            time::Duration::seconds(i64::MAX)
+        );
+    }
+
+    #[test]
+    fn from_parts_out_of_range() {
+        assert!(XsdDuration::from_parts(u64::MAX, 0, 0, 0).is_err());
+        assert!(XsdDuration::from_parts(0, 0, 0, u64::MAX).is_err());
+        assert!(XsdDuration::from_parts(0, 0, 0, i64::MAX as u64).is_ok());
+    }
+}
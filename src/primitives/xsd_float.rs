@@ -0,0 +1,301 @@
+use crate::primitives::{Either, Error};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:float represents an IEEE single-precision 32-bit floating-point number.
+///
+/// This is stored as an f64 to match the precision other implementations expect for coordinates.
+///
+/// Some implementations serialize floats as strings, so this type deserializes from both JSON
+/// numbers and numeric strings, but always serializes as a JSON number.
+///
+/// With the `float-roundtrip` feature, JSON numbers are parsed to the nearest f64, so a
+/// value written by any implementation that prints floats in their shortest form, like the
+/// coordinates of a Place, is read back exactly. Without it, serde_json's faster parser may be
+/// off by one in the last digit.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdFloat;
+///
+/// let from_number: XsdFloat = serde_json::from_str("51.5")?;
+/// let from_string: XsdFloat = serde_json::from_str(r#""51.5""#)?;
+///
+/// assert_eq!(from_number, from_string);
+/// assert_eq!(serde_json::to_string(&from_string)?, "51.5");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct XsdFloat(pub f64);
+
+/// The error type produced when an XsdFloat cannot be parsed, or falls outside of its allowed
+/// range
+#[derive(Clone, Debug)]
+pub struct XsdFloatError {
+    /// The value, minimum, and maximum of an out of range float
+    range: Option<(f64, f64, f64)>,
+}
+
+impl std::fmt::Display for XsdFloatError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.range {
+            Some((value, min, max)) => {
+                write!(f, "Float {} is not between {} and {}", value, min, max)
+            }
+            None => write!(f, "Could not parse Float"),
+        }
+    }
+}
+
+impl std::error::Error for XsdFloatError {}
+
+impl XsdFloat {
+    /// Construct a new XsdFloat
+    pub fn new(f: f64) -> Self {
+        Self(f)
+    }
+
+    /// Construct a new XsdFloat, checking that it is between `min` and `max`, inclusive
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdFloat;
+    ///
+    /// assert!(XsdFloat::bounded(51.5, -90.0, 90.0).is_ok());
+    /// assert!(XsdFloat::bounded(151.5, -90.0, 90.0).is_err());
+    /// assert!(XsdFloat::bounded(f64::NAN, -90.0, 90.0).is_err());
+    /// ```
+    pub fn bounded(f: f64, min: f64, max: f64) -> Result<Self, XsdFloatError> {
+        if min <= f && f <= max {
+            Ok(Self(f))
+        } else {
+            Err(XsdFloatError {
+                range: Some((f, min, max)),
+            })
+        }
+    }
+
+    /// Retreive the inner f64
+    pub fn into_inner(self) -> f64 {
+        self.0
+    }
+}
+
+impl PartialEq<f64> for XsdFloat {
+    fn eq(&self, other: &f64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<XsdFloat> for f64 {
+    fn eq(&self, other: &XsdFloat) -> bool {
+        *self == other.0
+    }
+}
+
+impl Deref for XsdFloat {
+    type Target = f64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdFloat {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<f64> for XsdFloat {
+    fn as_ref(&self) -> &f64 {
+        &self.0
+    }
+}
+
+impl AsMut<f64> for XsdFloat {
+    fn as_mut(&mut self) -> &mut f64 {
+        &mut self.0
+    }
+}
+
+impl From<f64> for XsdFloat {
+    fn from(f: f64) -> Self {
+        Self(f)
+    }
+}
+
+impl From<XsdFloat> for f64 {
+    fn from(f: XsdFloat) -> Self {
+        f.0
+    }
+}
+
+impl std::str::FromStr for XsdFloat {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().parse::<f64>() {
+            Ok(f) if f.is_finite() => Ok(XsdFloat(f)),
+            _ => Err(Error::Float(XsdFloatError { range: None })),
+        }
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdFloat {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdFloat {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdFloat {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdFloat {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdFloat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<f64, String> = Deserialize::<'de>::deserialize(deserializer)?;
+
+        match helper {
+            Either::Left(f) => Ok(XsdFloat(f)),
+            Either::Right(s) => match s.trim().parse::<f64>() {
+                Ok(f) if f.is_finite() => Ok(XsdFloat(f)),
+                _ => Err(serde::de::Error::custom("Invalid float")),
+            },
+        }
+    }
+}
+
+/// Deserialize an optional XsdFloat, rejecting values outside of `min..=max`
+pub(crate) fn deserialize_bounded<'de, D>(
+    deserializer: D,
+    min: f64,
+    max: f64,
+) -> Result<Option<XsdFloat>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    Option::<XsdFloat>::deserialize(deserializer)?
+        .map(|f| XsdFloat::bounded(f.0, min, max))
+        .transpose()
+        .map_err(serde::de::Error::custom)
+}
+
+impl Serialize for XsdFloat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdFloat;
+
+    #[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
+    struct MyStruct {
+        field: XsdFloat,
+    }
+
+    #[test]
+    fn deserialize_number() {
+        let json = r#"[{"field":1.5},{"field":-20},{"field":0}]"#;
+
+        let structs: Vec<MyStruct> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(structs[0].field, 1.5);
+        assert_eq!(structs[1].field, -20.0);
+        assert_eq!(structs[2].field, 0.0);
+    }
+
+    #[test]
+    fn deserialize_string() {
+        let json = r#"[{"field":"1.5"},{"field":"-20"},{"field":" 0 "}]"#;
+
+        let structs: Vec<MyStruct> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(structs[0].field, 1.5);
+        assert_eq!(structs[1].field, -20.0);
+        assert_eq!(structs[2].field, 0.0);
+    }
+
+    #[test]
+    fn dont_deserialize_invalid_strings() {
+        let invalids = [
+            r#"{"field":""}"#,
+            r#"{"field":"north"}"#,
+            r#"{"field":"NaN"}"#,
+            r#"{"field":"inf"}"#,
+            r#"{"field":true}"#,
+        ];
+
+        for case in invalids {
+            assert!(serde_json::from_str::<MyStruct>(case).is_err());
+        }
+    }
+
+    #[test]
+    fn serialize_as_number() {
+        let s = serde_json::to_string(&MyStruct {
+            field: XsdFloat(51.5),
+        })
+        .unwrap();
+
+        assert_eq!(s, r#"{"field":51.5}"#);
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["51.5", "-0.25", "0"] {
+            let parsed: XsdFloat = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdFloat>(&json).unwrap(), parsed);
+        }
+    }
+
+    #[cfg(feature = "float-roundtrip")]
+    #[test]
+    fn round_trip_precise_coordinates() {
+        let coordinates = [
+            "10.885001501416781",
+            "-19.531102488060938",
+            "51.507351",
+            "-122.41941550000001",
+        ];
+
+        for s in coordinates {
+            let json = format!(r#"{{"field":{}}}"#, s);
+            let parsed: MyStruct = serde_json::from_str(&json).unwrap();
+
+            assert_eq!(parsed.field.to_string(), s);
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+use crate::primitives::Error;
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:hexBinary represents arbitrary binary data, encoded as pairs of hexadecimal
+/// digits.
+///
+/// Both uppercase and lowercase digits are accepted when parsing. Values are always written in
+/// lowercase.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdHexBinary;
+///
+/// let digest: XsdHexBinary = serde_json::from_str(r#""0FB7""#)?;
+///
+/// assert_eq!(digest.as_bytes(), [0x0f, 0xb7]);
+/// assert_eq!(serde_json::to_string(&digest)?, r#""0fb7""#);
+/// assert!(serde_json::from_str::<XsdHexBinary>(r#""0FB""#).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdHexBinary(pub Vec<u8>);
+
+/// The error type produced when an XsdHexBinary cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdHexBinaryError;
+
+impl std::fmt::Display for XsdHexBinaryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse HexBinary")
+    }
+}
+
+impl std::error::Error for XsdHexBinaryError {}
+
+impl XsdHexBinary {
+    /// Create a new XsdHexBinary from bytes
+    pub fn new<T>(bytes: T) -> Self
+    where
+        T: Into<Vec<u8>>,
+    {
+        XsdHexBinary(bytes.into())
+    }
+
+    /// Retrieve the inner bytes
+    pub fn into_inner(self) -> Vec<u8> {
+        self.0
+    }
+
+    /// Borrow the inner bytes
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+fn decode(s: &str) -> Option<Vec<u8>> {
+    if !s.len().is_multiple_of(2) {
+        return None;
+    }
+
+    s.as_bytes()
+        .chunks(2)
+        .map(|pair| {
+            let high = char::from(pair[0]).to_digit(16)?;
+            let low = char::from(pair[1]).to_digit(16)?;
+
+            Some(((high << 4) | low) as u8)
+        })
+        .collect()
+}
+
+impl Deref for XsdHexBinary {
+    type Target = [u8];
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdHexBinary {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<[u8]> for XsdHexBinary {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for XsdHexBinary {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl From<Vec<u8>> for XsdHexBinary {
+    fn from(bytes: Vec<u8>) -> Self {
+        XsdHexBinary(bytes)
+    }
+}
+
+impl From<&[u8]> for XsdHexBinary {
+    fn from(bytes: &[u8]) -> Self {
+        XsdHexBinary(bytes.to_vec())
+    }
+}
+
+impl From<XsdHexBinary> for Vec<u8> {
+    fn from(h: XsdHexBinary) -> Self {
+        h.0
+    }
+}
+
+impl std::str::FromStr for XsdHexBinary {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        decode(s)
+            .map(XsdHexBinary)
+            .ok_or(Error::HexBinary(XsdHexBinaryError))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdHexBinary {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdHexBinary {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdHexBinary {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdHexBinary {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        for byte in &self.0 {
+            write!(f, "{:02x}", byte)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl serde::ser::Serialize for XsdHexBinary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for XsdHexBinary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdHexBinary;
+
+    #[test]
+    fn parse_either_case() {
+        let lower: XsdHexBinary = "deadbeef".parse().unwrap();
+        let upper: XsdHexBinary = "DEADBEEF".parse().unwrap();
+
+        assert_eq!(lower, upper);
+        assert_eq!(lower.as_bytes(), [0xde, 0xad, 0xbe, 0xef]);
+        assert_eq!(upper.to_string(), "deadbeef");
+    }
+
+    #[test]
+    fn dont_parse_invalid() {
+        for case in ["a", "0g", "+1", " 01", "éé"] {
+            assert!(case.parse::<XsdHexBinary>().is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let bytes: Vec<u8> = (0..=255).collect();
+        let binary = XsdHexBinary::new(bytes);
+        let json = serde_json::to_string(&binary).unwrap();
+
+        assert_eq!(serde_json::from_str::<XsdHexBinary>(&json).unwrap(), binary);
+    }
+}
@@ -0,0 +1,235 @@
+use crate::primitives::{Either, Error};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:integer represents a whole number, positive or negative.
+///
+/// xsd:integer has no bounds, but values are stored as an i64, which covers every value seen in
+/// practice. Like `XsdNonNegativeInteger`, this deserializes from both JSON numbers and numeric
+/// strings, and always serializes as a JSON number.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdInteger;
+///
+/// let from_number: XsdInteger = serde_json::from_str("-12")?;
+/// let from_string: XsdInteger = serde_json::from_str(r#""-12""#)?;
+///
+/// assert_eq!(from_number, from_string);
+/// assert_eq!(serde_json::to_string(&from_string)?, "-12");
+/// assert_eq!(i64::from(XsdInteger::from(7u32)), 7);
+/// assert!(XsdInteger::try_from(u64::MAX).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdInteger(pub i64);
+
+impl XsdInteger {
+    /// Construct a new XsdInteger
+    pub fn new(i: i64) -> Self {
+        Self(i)
+    }
+
+    /// Retreive the inner i64
+    pub fn into_inner(self) -> i64 {
+        self.0
+    }
+}
+
+impl PartialEq<i64> for XsdInteger {
+    fn eq(&self, other: &i64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<XsdInteger> for i64 {
+    fn eq(&self, other: &XsdInteger) -> bool {
+        *self == other.0
+    }
+}
+
+impl Deref for XsdInteger {
+    type Target = i64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdInteger {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<i64> for XsdInteger {
+    fn as_ref(&self) -> &i64 {
+        &self.0
+    }
+}
+
+impl AsMut<i64> for XsdInteger {
+    fn as_mut(&mut self) -> &mut i64 {
+        &mut self.0
+    }
+}
+
+impl From<i64> for XsdInteger {
+    fn from(i: i64) -> Self {
+        Self(i)
+    }
+}
+
+impl From<i32> for XsdInteger {
+    fn from(i: i32) -> Self {
+        Self(i.into())
+    }
+}
+
+impl From<u32> for XsdInteger {
+    fn from(u: u32) -> Self {
+        Self(u.into())
+    }
+}
+
+impl TryFrom<u64> for XsdInteger {
+    type Error = Error;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        Ok(XsdInteger(i64::try_from(u)?))
+    }
+}
+
+impl From<XsdInteger> for i64 {
+    fn from(i: XsdInteger) -> Self {
+        i.0
+    }
+}
+
+impl TryFrom<XsdInteger> for u64 {
+    type Error = Error;
+
+    fn try_from(i: XsdInteger) -> Result<Self, Self::Error> {
+        Ok(u64::try_from(i.0)?)
+    }
+}
+
+impl std::str::FromStr for XsdInteger {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(XsdInteger(s.trim().parse()?))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdInteger {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdInteger {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdInteger {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdInteger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdInteger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<Either<i64, u64>, String> =
+            Deserialize::<'de>::deserialize(deserializer)?;
+
+        match helper {
+            Either::Left(Either::Left(i)) => Ok(XsdInteger(i)),
+            Either::Left(Either::Right(u)) => Err(serde::de::Error::custom(format!(
+                "Invalid integer, {} is out of range",
+                u
+            ))),
+            Either::Right(s) => {
+                let s = s.trim();
+
+                s.parse()
+                    .map(XsdInteger)
+                    .map_err(|_| serde::de::Error::custom(format!("Invalid integer, {:?}", s)))
+            }
+        }
+    }
+}
+
+impl Serialize for XsdInteger {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdInteger;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+    struct MyStruct {
+        field: XsdInteger,
+    }
+
+    #[test]
+    fn deserialize_numbers_and_strings() {
+        let json = r#"[{"field":-5},{"field":"42"},{"field":" 0 "}]"#;
+
+        let structs: Vec<MyStruct> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(structs[0].field, -5);
+        assert_eq!(structs[1].field, 42);
+        assert_eq!(structs[2].field, 0);
+    }
+
+    #[test]
+    fn dont_deserialize_invalid() {
+        let invalids = [
+            r#"{"field":1.5}"#,
+            r#"{"field":18446744073709551615}"#,
+            r#"{"field":"1.5"}"#,
+            r#"{"field":""}"#,
+            r#"{"field":true}"#,
+        ];
+
+        for case in invalids {
+            assert!(serde_json::from_str::<MyStruct>(case).is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["-20", "0", "9223372036854775807"] {
+            let parsed: XsdInteger = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdInteger>(&json).unwrap(), parsed);
+        }
+    }
+}
@@ -0,0 +1,308 @@
+use crate::primitives::{Either, Error};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:nonNegativeInteger represents an integer that is greater than or equal to zero.
+///
+/// Some implementations serialize these values as strings (e.g. `"totalItems": "523"`), so this
+/// type deserializes from both JSON numbers and numeric strings, but always serializes as a JSON
+/// number. Negative values are rejected.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdNonNegativeInteger;
+///
+/// let from_number: XsdNonNegativeInteger = serde_json::from_str("523")?;
+/// let from_string: XsdNonNegativeInteger = serde_json::from_str(r#""523""#)?;
+///
+/// assert_eq!(from_number, from_string);
+/// assert_eq!(serde_json::to_string(&from_string)?, "523");
+/// assert!(serde_json::from_str::<XsdNonNegativeInteger>("-1").is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdNonNegativeInteger(pub u64);
+
+impl XsdNonNegativeInteger {
+    /// Construct a new XsdNonNegativeInteger
+    pub fn new(u: u64) -> Self {
+        Self(u)
+    }
+
+    /// Retreive the inner u64
+    pub fn into_inner(self) -> u64 {
+        self.0
+    }
+
+    /// Add to the integer, returning None if the result would overflow
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdNonNegativeInteger;
+    ///
+    /// let total = XsdNonNegativeInteger::new(5);
+    ///
+    /// assert_eq!(total.checked_add(2), Some(XsdNonNegativeInteger::new(7)));
+    /// assert_eq!(total.checked_add(u64::MAX), None);
+    /// ```
+    pub fn checked_add(self, rhs: u64) -> Option<Self> {
+        self.0.checked_add(rhs).map(Self)
+    }
+
+    /// Subtract from the integer, stopping at zero
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdNonNegativeInteger;
+    ///
+    /// let total = XsdNonNegativeInteger::new(5);
+    ///
+    /// assert_eq!(total.saturating_sub(2), 3);
+    /// assert_eq!(total.saturating_sub(10), 0);
+    /// ```
+    pub fn saturating_sub(self, rhs: u64) -> Self {
+        Self(self.0.saturating_sub(rhs))
+    }
+
+    /// Add one to the integer, stopping at `u64::MAX`
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdNonNegativeInteger;
+    ///
+    /// let mut total = XsdNonNegativeInteger::default();
+    /// total.increment().increment();
+    ///
+    /// assert_eq!(total, 2);
+    /// ```
+    pub fn increment(&mut self) -> &mut Self {
+        self.0 = self.0.saturating_add(1);
+        self
+    }
+
+    /// Subtract one from the integer, stopping at zero
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdNonNegativeInteger;
+    ///
+    /// let mut total = XsdNonNegativeInteger::new(1);
+    /// total.decrement().decrement();
+    ///
+    /// assert_eq!(total, 0);
+    /// ```
+    pub fn decrement(&mut self) -> &mut Self {
+        self.0 = self.0.saturating_sub(1);
+        self
+    }
+}
+
+impl PartialEq<u64> for XsdNonNegativeInteger {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<XsdNonNegativeInteger> for u64 {
+    fn eq(&self, other: &XsdNonNegativeInteger) -> bool {
+        *self == other.0
+    }
+}
+
+impl Deref for XsdNonNegativeInteger {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdNonNegativeInteger {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<u64> for XsdNonNegativeInteger {
+    fn as_ref(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl AsMut<u64> for XsdNonNegativeInteger {
+    fn as_mut(&mut self) -> &mut u64 {
+        &mut self.0
+    }
+}
+
+impl From<u64> for XsdNonNegativeInteger {
+    fn from(u: u64) -> Self {
+        Self(u)
+    }
+}
+
+impl From<XsdNonNegativeInteger> for u64 {
+    fn from(u: XsdNonNegativeInteger) -> Self {
+        u.0
+    }
+}
+
+impl std::str::FromStr for XsdNonNegativeInteger {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(XsdNonNegativeInteger(s.trim().parse()?))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdNonNegativeInteger {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdNonNegativeInteger {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdNonNegativeInteger {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdNonNegativeInteger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdNonNegativeInteger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<Either<u64, i64>, String> =
+            Deserialize::<'de>::deserialize(deserializer)?;
+
+        match helper {
+            Either::Left(Either::Left(u)) => Ok(XsdNonNegativeInteger(u)),
+            Either::Left(Either::Right(i)) => Err(serde::de::Error::custom(format!(
+                "Invalid nonNegativeInteger, {} is negative",
+                i
+            ))),
+            Either::Right(s) => {
+                let s = s.trim();
+
+                if s.starts_with('-') {
+                    return Err(serde::de::Error::custom(format!(
+                        "Invalid nonNegativeInteger, {} is negative",
+                        s
+                    )));
+                }
+
+                s.parse().map(XsdNonNegativeInteger).map_err(|_| {
+                    serde::de::Error::custom(format!("Invalid nonNegativeInteger, {:?}", s))
+                })
+            }
+        }
+    }
+}
+
+impl Serialize for XsdNonNegativeInteger {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdNonNegativeInteger;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+    struct MyStruct {
+        field: XsdNonNegativeInteger,
+    }
+
+    #[test]
+    fn deserialize_number() {
+        let json = r#"[{"field":523},{"field":0}]"#;
+
+        let structs: Vec<MyStruct> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(structs[0].field, 523);
+        assert_eq!(structs[1].field, 0);
+    }
+
+    #[test]
+    fn deserialize_string() {
+        let json = r#"[{"field":"523"},{"field":"0"}]"#;
+
+        let structs: Vec<MyStruct> = serde_json::from_str(json).unwrap();
+
+        assert_eq!(structs[0].field, 523);
+        assert_eq!(structs[1].field, 0);
+    }
+
+    #[test]
+    fn dont_deserialize_negatives() {
+        for case in [r#"{"field":-1}"#, r#"{"field":"-1"}"#] {
+            let err = serde_json::from_str::<MyStruct>(case).unwrap_err();
+
+            assert!(err.to_string().contains("is negative"), "{}", err);
+        }
+    }
+
+    #[test]
+    fn dont_deserialize_invalid() {
+        let invalids = [
+            r#"{"field":1.5}"#,
+            r#"{"field":"1.5"}"#,
+            r#"{"field":""}"#,
+            r#"{"field":"many"}"#,
+            r#"{"field":true}"#,
+        ];
+
+        for case in invalids {
+            assert!(serde_json::from_str::<MyStruct>(case).is_err());
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let structs = vec![
+            MyStruct {
+                field: XsdNonNegativeInteger(0),
+            },
+            MyStruct {
+                field: XsdNonNegativeInteger(u64::MAX),
+            },
+        ];
+        let string = serde_json::to_string(&structs).unwrap();
+        let new_structs: Vec<MyStruct> = serde_json::from_str(&string).unwrap();
+
+        assert_eq!(structs, new_structs);
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["0", "523", "18446744073709551615"] {
+            let parsed: XsdNonNegativeInteger = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(
+                serde_json::from_str::<XsdNonNegativeInteger>(&json).unwrap(),
+                parsed
+            );
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use crate::primitives::{Either, Error, XsdNonNegativeInteger};
+use serde::{Deserialize, Serialize};
+use std::num::NonZeroU64;
+
+/// The type xsd:positiveInteger represents an integer that is greater than zero.
+///
+/// This deserializes from both JSON numbers and numeric strings, and always serializes as a JSON
+/// number. Zero and negative values are rejected.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdPositiveInteger;
+///
+/// let from_number: XsdPositiveInteger = serde_json::from_str("3")?;
+/// let from_string: XsdPositiveInteger = serde_json::from_str(r#""3""#)?;
+///
+/// assert_eq!(from_number, from_string);
+/// assert_eq!(serde_json::to_string(&from_string)?, "3");
+/// assert!(serde_json::from_str::<XsdPositiveInteger>("0").is_err());
+/// assert!(XsdPositiveInteger::try_from(0u64).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdPositiveInteger(pub NonZeroU64);
+
+impl XsdPositiveInteger {
+    /// Construct a new XsdPositiveInteger
+    pub fn new(u: NonZeroU64) -> Self {
+        Self(u)
+    }
+
+    /// Retreive the inner NonZeroU64
+    pub fn into_inner(self) -> NonZeroU64 {
+        self.0
+    }
+
+    /// Retreive the value as a u64
+    pub fn get(self) -> u64 {
+        self.0.get()
+    }
+}
+
+impl PartialEq<u64> for XsdPositiveInteger {
+    fn eq(&self, other: &u64) -> bool {
+        self.0.get() == *other
+    }
+}
+
+impl PartialEq<XsdPositiveInteger> for u64 {
+    fn eq(&self, other: &XsdPositiveInteger) -> bool {
+        *self == other.0.get()
+    }
+}
+
+impl AsRef<NonZeroU64> for XsdPositiveInteger {
+    fn as_ref(&self) -> &NonZeroU64 {
+        &self.0
+    }
+}
+
+impl From<NonZeroU64> for XsdPositiveInteger {
+    fn from(u: NonZeroU64) -> Self {
+        Self(u)
+    }
+}
+
+impl TryFrom<u64> for XsdPositiveInteger {
+    type Error = Error;
+
+    fn try_from(u: u64) -> Result<Self, Self::Error> {
+        Ok(XsdPositiveInteger(NonZeroU64::try_from(u)?))
+    }
+}
+
+impl TryFrom<XsdNonNegativeInteger> for XsdPositiveInteger {
+    type Error = Error;
+
+    fn try_from(u: XsdNonNegativeInteger) -> Result<Self, Self::Error> {
+        Self::try_from(u.0)
+    }
+}
+
+impl From<XsdPositiveInteger> for NonZeroU64 {
+    fn from(u: XsdPositiveInteger) -> Self {
+        u.0
+    }
+}
+
+impl From<XsdPositiveInteger> for u64 {
+    fn from(u: XsdPositiveInteger) -> Self {
+        u.0.get()
+    }
+}
+
+impl From<XsdPositiveInteger> for XsdNonNegativeInteger {
+    fn from(u: XsdPositiveInteger) -> Self {
+        XsdNonNegativeInteger(u.0.get())
+    }
+}
+
+impl std::str::FromStr for XsdPositiveInteger {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(XsdPositiveInteger(s.trim().parse()?))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdPositiveInteger {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdPositiveInteger {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdPositiveInteger {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdPositiveInteger {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdPositiveInteger {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<Either<u64, i64>, String> =
+            Deserialize::<'de>::deserialize(deserializer)?;
+
+        let u = match helper {
+            Either::Left(Either::Left(u)) => u,
+            Either::Left(Either::Right(i)) => {
+                return Err(serde::de::Error::custom(format!(
+                    "Invalid positiveInteger, {} is negative",
+                    i
+                )))
+            }
+            Either::Right(s) => {
+                let s = s.trim();
+
+                if s.starts_with('-') {
+                    return Err(serde::de::Error::custom(format!(
+                        "Invalid positiveInteger, {} is negative",
+                        s
+                    )));
+                }
+
+                s.parse().map_err(|_| {
+                    serde::de::Error::custom(format!("Invalid positiveInteger, {:?}", s))
+                })?
+            }
+        };
+
+        NonZeroU64::new(u)
+            .map(XsdPositiveInteger)
+            .ok_or_else(|| serde::de::Error::custom("Invalid positiveInteger, 0 is not positive"))
+    }
+}
+
+impl Serialize for XsdPositiveInteger {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.get().serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdPositiveInteger;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+    struct MyStruct {
+        field: XsdPositiveInteger,
+    }
+
+    #[test]
+    fn dont_deserialize_zero_or_negatives() {
+        for case in [
+            r#"{"field":0}"#,
+            r#"{"field":"0"}"#,
+            r#"{"field":-1}"#,
+            r#"{"field":"-1"}"#,
+        ] {
+            assert!(serde_json::from_str::<MyStruct>(case).is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip() {
+        let json = r#"{"field":18446744073709551615}"#;
+
+        let my_struct: MyStruct = serde_json::from_str(json).unwrap();
+
+        assert_eq!(my_struct.field, u64::MAX);
+        assert_eq!(serde_json::to_string(&my_struct).unwrap(), json);
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["1", "523", "18446744073709551615"] {
+            let parsed: XsdPositiveInteger = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(
+                serde_json::from_str::<XsdPositiveInteger>(&json).unwrap(),
+                parsed
+            );
+        }
+    }
+}
@@ -0,0 +1,230 @@
+use crate::primitives::AnyString;
+use std::{borrow::Cow, ops::Deref};
+
+/// A possibly-borrowed xsd:string
+///
+/// The types in this crate own their data, so deserializing a large payload allocates for every
+/// string in it. When only a handful of fields are needed, like checking the `type`, `id`, and
+/// `actor` of an incoming activity before deciding whether to process it, a struct of `XsdStr`
+/// fields marked with `#[serde(borrow)]` can read them straight out of the input.
+/// [`BaseRef`](crate::base::BaseRef) is one such struct, covering `id`, `type`, `name`, and
+/// `content`.
+///
+/// Strings are borrowed when the input contains them verbatim, and copied when they had to be
+/// unescaped.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdStr;
+///
+/// #[derive(serde::Deserialize)]
+/// struct Peek<'a> {
+///     #[serde(borrow)]
+///     id: XsdStr<'a>,
+///     #[serde(borrow, rename = "type")]
+///     kind: XsdStr<'a>,
+///     #[serde(borrow)]
+///     content: Option<XsdStr<'a>>,
+/// }
+///
+/// let json = r#"{
+///     "id": "https://example.com/notes/1",
+///     "type": "Note",
+///     "content": "<p>\"quoted\"</p>",
+///     "attributedTo": "https://example.com/users/asonix"
+/// }"#;
+///
+/// let peek: Peek = serde_json::from_str(json)?;
+///
+/// assert_eq!(peek.kind, "Note");
+/// assert!(peek.id.is_borrowed());
+/// assert_eq!(peek.content.as_deref(), Some(r#"<p>"quoted"</p>"#));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdStr<'a>(Cow<'a, str>);
+
+impl<'a> XsdStr<'a> {
+    /// Create a new XsdStr from a borrowed or owned string
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdStr;
+    ///
+    /// let s = XsdStr::new("hi");
+    /// assert!(s.is_borrowed());
+    /// ```
+    pub fn new<T>(s: T) -> Self
+    where
+        T: Into<Cow<'a, str>>,
+    {
+        XsdStr(s.into())
+    }
+
+    /// Borrow the inner str
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Check whether the string is borrowed from its input
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.0, Cow::Borrowed(_))
+    }
+
+    /// Take the string as an owned String, copying it if it was borrowed
+    pub fn into_owned(self) -> String {
+        self.0.into_owned()
+    }
+
+    /// Copy the string if it was borrowed, so it no longer refers to its input
+    ///
+    /// ```rust
+    /// use activitystreams::primitives::XsdStr;
+    ///
+    /// let s: XsdStr<'static> = XsdStr::new(&String::from("hi")[..]).into_static();
+    /// assert!(!s.is_borrowed());
+    /// ```
+    pub fn into_static(self) -> XsdStr<'static> {
+        XsdStr(Cow::Owned(self.0.into_owned()))
+    }
+}
+
+impl Deref for XsdStr<'_> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<str> for XsdStr<'_> {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for XsdStr<'_> {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for XsdStr<'_> {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<'a> From<&'a str> for XsdStr<'a> {
+    fn from(s: &'a str) -> Self {
+        XsdStr(Cow::Borrowed(s))
+    }
+}
+
+impl From<String> for XsdStr<'_> {
+    fn from(s: String) -> Self {
+        XsdStr(Cow::Owned(s))
+    }
+}
+
+impl<'a> From<Cow<'a, str>> for XsdStr<'a> {
+    fn from(s: Cow<'a, str>) -> Self {
+        XsdStr(s)
+    }
+}
+
+impl From<XsdStr<'_>> for String {
+    fn from(s: XsdStr<'_>) -> Self {
+        s.into_owned()
+    }
+}
+
+impl From<XsdStr<'_>> for AnyString {
+    fn from(s: XsdStr<'_>) -> Self {
+        AnyString::from_xsd_string(s.into_owned())
+    }
+}
+
+impl std::fmt::Display for XsdStr<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl serde::ser::Serialize for XsdStr<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de: 'a, 'a> serde::de::Deserialize<'de> for XsdStr<'a> {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_str(XsdStrVisitor)
+    }
+}
+
+struct XsdStrVisitor;
+
+impl<'de> serde::de::Visitor<'de> for XsdStrVisitor {
+    type Value = XsdStr<'de>;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("a string")
+    }
+
+    fn visit_borrowed_str<E>(self, v: &'de str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(XsdStr(Cow::Borrowed(v)))
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(XsdStr(Cow::Owned(v.to_owned())))
+    }
+
+    fn visit_string<E>(self, v: String) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        Ok(XsdStr(Cow::Owned(v)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdStr;
+
+    #[test]
+    fn borrow_unescaped() {
+        let s: XsdStr = serde_json::from_str(r#""https://example.com/users/asonix""#).unwrap();
+
+        assert!(s.is_borrowed());
+        assert_eq!(s, "https://example.com/users/asonix");
+    }
+
+    #[test]
+    fn copy_escaped() {
+        let s: XsdStr = serde_json::from_str(r#""line\nbreak""#).unwrap();
+
+        assert!(!s.is_borrowed());
+        assert_eq!(s, "line\nbreak");
+    }
+
+    #[test]
+    fn round_trip() {
+        let s: XsdStr = serde_json::from_str(r#""café \"au lait\"""#).unwrap();
+        let json = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(serde_json::from_str::<XsdStr>(&json).unwrap(), s);
+    }
+}
@@ -0,0 +1,307 @@
+use crate::primitives::Error;
+
+/// The type xsd:time represents a time of day in the format hh:mm:ss.sss, where the fractional
+/// seconds are optional.
+///
+/// An optional time zone expression may be added at the end of the value, in the same format as
+/// for xsd:dateTime. If no time zone value is present, it is considered unknown; it is not assumed
+/// to be UTC. The value `24:00:00` is read as midnight, `00:00:00`.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{primitives::XsdTime, time::UtcOffset};
+///
+/// let opens: XsdTime = "09:30:00-05:00".parse()?;
+///
+/// assert_eq!(opens.as_time().hour(), 9);
+/// assert_eq!(opens.offset(), Some(UtcOffset::from_hms(-5, 0, 0)?));
+/// assert_eq!(opens.to_string(), "09:30:00-05:00");
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdTime {
+    time: time::Time,
+    offset: Option<time::UtcOffset>,
+}
+
+/// The error type produced when an XsdTime cannot be parsed
+#[derive(Clone, Debug)]
+pub struct XsdTimeError;
+
+impl std::fmt::Display for XsdTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Could not parse Time")
+    }
+}
+
+impl std::error::Error for XsdTimeError {}
+
+impl XsdTime {
+    /// Create an XsdTime from a time::Time, with no time zone
+    pub fn new(t: time::Time) -> Self {
+        XsdTime {
+            time: t,
+            offset: None,
+        }
+    }
+
+    /// Create an XsdTime from a time::Time in the given time zone
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{
+    ///     primitives::XsdTime,
+    ///     time::{Time, UtcOffset},
+    /// };
+    ///
+    /// let time = XsdTime::with_offset(Time::from_hms_milli(4, 20, 0, 500)?, UtcOffset::UTC);
+    ///
+    /// assert_eq!(time.to_string(), "04:20:00.5Z");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn with_offset(t: time::Time, offset: time::UtcOffset) -> Self {
+        XsdTime {
+            time: t,
+            offset: Some(offset),
+        }
+    }
+
+    /// Extract the time::Time from an XsdTime, dropping the time zone
+    pub fn into_inner(self) -> time::Time {
+        self.time
+    }
+
+    /// Borrow the underlying `time::Time`
+    pub fn as_time(&self) -> &time::Time {
+        self.as_ref()
+    }
+
+    /// Mutably borrow the underlying `time::Time`
+    pub fn as_time_mut(&mut self) -> &mut time::Time {
+        self.as_mut()
+    }
+
+    /// Fetch the time zone, if one was given
+    pub fn offset(&self) -> Option<time::UtcOffset> {
+        self.offset
+    }
+}
+
+impl From<time::Time> for XsdTime {
+    fn from(t: time::Time) -> Self {
+        XsdTime::new(t)
+    }
+}
+
+impl From<XsdTime> for time::Time {
+    fn from(t: XsdTime) -> Self {
+        t.time
+    }
+}
+
+impl AsRef<time::Time> for XsdTime {
+    fn as_ref(&self) -> &time::Time {
+        &self.time
+    }
+}
+
+impl AsMut<time::Time> for XsdTime {
+    fn as_mut(&mut self) -> &mut time::Time {
+        &mut self.time
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdTime {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdTime {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdTime {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::str::FromStr for XsdTime {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(parse_time(s)?)
+    }
+}
+
+fn parse_time(s: &str) -> Result<XsdTime, XsdTimeError> {
+    let hour = two_digits(s.get(..2))?;
+    let minute = two_digits(s.get(3..5))?;
+    let second = two_digits(s.get(6..8))?;
+    if s.get(2..3) != Some(":") || s.get(5..6) != Some(":") {
+        return Err(XsdTimeError);
+    }
+
+    let mut rest = &s[8..];
+    let mut nanosecond = 0;
+
+    if let Some(fraction) = rest.strip_prefix('.') {
+        let len = fraction
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(fraction.len());
+        if len == 0 {
+            return Err(XsdTimeError);
+        }
+
+        // Digits past nanosecond precision are dropped
+        let digits = &fraction[..len.min(9)];
+        nanosecond =
+            digits.parse::<u32>().map_err(|_| XsdTimeError)? * 10u32.pow(9 - digits.len() as u32);
+        rest = &fraction[len..];
+    }
+
+    let offset = match rest.as_bytes() {
+        [] => None,
+        [b'Z'] => Some(time::UtcOffset::UTC),
+        [sign @ (b'+' | b'-'), _, _, b':', _, _] => {
+            let hours = two_digits(rest.get(1..3))?;
+            let minutes = two_digits(rest.get(4..6))?;
+            if hours > 14 || minutes > 59 || (hours == 14 && minutes > 0) {
+                return Err(XsdTimeError);
+            }
+
+            let (hours, minutes) = if *sign == b'-' {
+                (-(hours as i8), -(minutes as i8))
+            } else {
+                (hours as i8, minutes as i8)
+            };
+
+            Some(time::UtcOffset::from_hms(hours, minutes, 0).map_err(|_| XsdTimeError)?)
+        }
+        _ => return Err(XsdTimeError),
+    };
+
+    let time = if (hour, minute, second, nanosecond) == (24, 0, 0, 0) {
+        time::Time::MIDNIGHT
+    } else {
+        time::Time::from_hms_nano(hour, minute, second, nanosecond).map_err(|_| XsdTimeError)?
+    };
+
+    Ok(XsdTime { time, offset })
+}
+
+fn two_digits(s: Option<&str>) -> Result<u8, XsdTimeError> {
+    match s.map(str::as_bytes) {
+        Some([a, b]) if a.is_ascii_digit() && b.is_ascii_digit() => {
+            Ok((a - b'0') * 10 + (b - b'0'))
+        }
+        _ => Err(XsdTimeError),
+    }
+}
+
+impl std::fmt::Display for XsdTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let (hour, minute, second, nanosecond) = self.time.as_hms_nano();
+
+        write!(f, "{:02}:{:02}:{:02}", hour, minute, second)?;
+
+        if nanosecond > 0 {
+            let fraction = format!("{:09}", nanosecond);
+            write!(f, ".{}", fraction.trim_end_matches('0'))?;
+        }
+
+        match self.offset {
+            None => Ok(()),
+            Some(offset) if offset.is_utc() => write!(f, "Z"),
+            Some(offset) => {
+                let (hours, minutes, _) = offset.as_hms();
+                let sign = if offset.is_negative() { '-' } else { '+' };
+
+                write!(
+                    f,
+                    "{}{:02}:{:02}",
+                    sign,
+                    hours.unsigned_abs(),
+                    minutes.unsigned_abs()
+                )
+            }
+        }
+    }
+}
+
+impl serde::ser::Serialize for XsdTime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        serializer.serialize_str(&self.to_string())
+    }
+}
+
+impl<'de> serde::de::Deserialize<'de> for XsdTime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdTime;
+
+    #[test]
+    fn parse_fractional_seconds() {
+        let time: XsdTime = "13:20:00.123456789123".parse().unwrap();
+
+        assert_eq!(time.as_time().nanosecond(), 123_456_789);
+        assert_eq!(time.offset(), None);
+        assert_eq!(time.to_string(), "13:20:00.123456789");
+    }
+
+    #[test]
+    fn parse_end_of_day() {
+        let time: XsdTime = "24:00:00Z".parse().unwrap();
+
+        assert_eq!(time.into_inner(), time::Time::MIDNIGHT);
+        assert!("24:00:01".parse::<XsdTime>().is_err());
+    }
+
+    #[test]
+    fn reject_invalid() {
+        for s in [
+            "1:20:00",
+            "13:20",
+            "13:20:00.",
+            "13:60:00",
+            "13:20:00+15:00",
+            "13:20:00 ",
+        ] {
+            assert!(s.parse::<XsdTime>().is_err(), "{}", s);
+        }
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["04:20:00", "13:20:00.5Z", "09:30:00-05:00"] {
+            let parsed: XsdTime = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(serde_json::from_str::<XsdTime>(&json).unwrap(), parsed);
+        }
+    }
+}
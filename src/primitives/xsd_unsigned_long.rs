@@ -0,0 +1,233 @@
+use crate::primitives::{Either, Error, XsdNonNegativeInteger};
+use serde::{Deserialize, Serialize};
+use std::ops::{Deref, DerefMut};
+
+/// The type xsd:unsignedLong represents an integer between 0 and 18446744073709551615.
+///
+/// This deserializes from both JSON numbers and numeric strings, and always serializes as a JSON
+/// number. Negative values, and values that don't fit in a u64, are rejected.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::primitives::XsdUnsignedLong;
+///
+/// let size: XsdUnsignedLong = serde_json::from_str(r#""1048576""#)?;
+///
+/// assert_eq!(size, 1_048_576);
+/// assert_eq!(serde_json::to_string(&size)?, "1048576");
+/// assert!(XsdUnsignedLong::try_from(-1i64).is_err());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct XsdUnsignedLong(pub u64);
+
+impl XsdUnsignedLong {
+    /// Construct a new XsdUnsignedLong
+    pub fn new(u: u64) -> Self {
+        Self(u)
+    }
+
+    /// Retreive the inner u64
+    pub fn into_inner(self) -> u64 {
+        self.0
+    }
+}
+
+impl PartialEq<u64> for XsdUnsignedLong {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<XsdUnsignedLong> for u64 {
+    fn eq(&self, other: &XsdUnsignedLong) -> bool {
+        *self == other.0
+    }
+}
+
+impl Deref for XsdUnsignedLong {
+    type Target = u64;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for XsdUnsignedLong {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+impl AsRef<u64> for XsdUnsignedLong {
+    fn as_ref(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl AsMut<u64> for XsdUnsignedLong {
+    fn as_mut(&mut self) -> &mut u64 {
+        &mut self.0
+    }
+}
+
+impl From<u64> for XsdUnsignedLong {
+    fn from(u: u64) -> Self {
+        Self(u)
+    }
+}
+
+impl From<u32> for XsdUnsignedLong {
+    fn from(u: u32) -> Self {
+        Self(u.into())
+    }
+}
+
+impl TryFrom<i64> for XsdUnsignedLong {
+    type Error = Error;
+
+    fn try_from(i: i64) -> Result<Self, Self::Error> {
+        Ok(XsdUnsignedLong(u64::try_from(i)?))
+    }
+}
+
+impl TryFrom<usize> for XsdUnsignedLong {
+    type Error = Error;
+
+    fn try_from(u: usize) -> Result<Self, Self::Error> {
+        Ok(XsdUnsignedLong(u64::try_from(u)?))
+    }
+}
+
+impl From<XsdUnsignedLong> for u64 {
+    fn from(u: XsdUnsignedLong) -> Self {
+        u.0
+    }
+}
+
+impl From<XsdNonNegativeInteger> for XsdUnsignedLong {
+    fn from(u: XsdNonNegativeInteger) -> Self {
+        Self(u.0)
+    }
+}
+
+impl From<XsdUnsignedLong> for XsdNonNegativeInteger {
+    fn from(u: XsdUnsignedLong) -> Self {
+        XsdNonNegativeInteger(u.0)
+    }
+}
+
+impl std::str::FromStr for XsdUnsignedLong {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(XsdUnsignedLong(s.trim().parse()?))
+    }
+}
+
+impl std::convert::TryFrom<String> for XsdUnsignedLong {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&str> for XsdUnsignedLong {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::convert::TryFrom<&mut str> for XsdUnsignedLong {
+    type Error = Error;
+
+    fn try_from(s: &mut str) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl std::fmt::Display for XsdUnsignedLong {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for XsdUnsignedLong {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let helper: Either<Either<u64, i64>, String> =
+            Deserialize::<'de>::deserialize(deserializer)?;
+
+        match helper {
+            Either::Left(Either::Left(u)) => Ok(XsdUnsignedLong(u)),
+            Either::Left(Either::Right(i)) => Err(serde::de::Error::custom(format!(
+                "Invalid unsignedLong, {} is negative",
+                i
+            ))),
+            Either::Right(s) => {
+                let s = s.trim();
+
+                if s.starts_with('-') {
+                    return Err(serde::de::Error::custom(format!(
+                        "Invalid unsignedLong, {} is negative",
+                        s
+                    )));
+                }
+
+                s.parse()
+                    .map(XsdUnsignedLong)
+                    .map_err(|_| serde::de::Error::custom(format!("Invalid unsignedLong, {:?}", s)))
+            }
+        }
+    }
+}
+
+impl Serialize for XsdUnsignedLong {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::XsdUnsignedLong;
+
+    #[derive(Debug, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
+    struct MyStruct {
+        field: XsdUnsignedLong,
+    }
+
+    #[test]
+    fn dont_deserialize_out_of_range() {
+        for case in [
+            r#"{"field":-1}"#,
+            r#"{"field":"-1"}"#,
+            r#"{"field":"18446744073709551616"}"#,
+        ] {
+            assert!(serde_json::from_str::<MyStruct>(case).is_err(), "{}", case);
+        }
+    }
+
+    #[test]
+    fn round_trip_str() {
+        for s in ["0", "523", "18446744073709551615"] {
+            let parsed: XsdUnsignedLong = s.parse().unwrap();
+            let json = serde_json::to_string(&parsed).unwrap();
+
+            assert_eq!(parsed.to_string(), s);
+            assert_eq!(
+                serde_json::from_str::<XsdUnsignedLong>(&json).unwrap(),
+                parsed
+            );
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! A registry for materializing application-defined types from `AnyBase`
+//!
+//! Properties like `object` and `attachment` are stored as `AnyBase`s, and the `Any*` enums only
+//! know about the types defined by the spec. A `KindRegistry` lets an application say "documents
+//! with this `type` should become this struct" once at startup, and then turn any `AnyBase` it
+//! comes across into the registered type.
+//!
+//! The registry is an ordinary value rather than global state, so different parts of an
+//! application, or different tests, can use different registrations.
+//!
+//! ```rust
+//! # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! use activitystreams::{
+//!     activity::Create, kind, object::Object, prelude::*, registry::KindRegistry,
+//! };
+//!
+//! kind!(EmojiReactType, EmojiReact);
+//! type EmojiReact = Object<EmojiReactType>;
+//!
+//! let mut registry = KindRegistry::new();
+//! registry.register::<EmojiReact>("EmojiReact");
+//!
+//! let create: Create = serde_json::from_str(r#"{
+//!     "type": "Create",
+//!     "actor": "https://example.com/users/asonix",
+//!     "object": {
+//!         "type": "EmojiReact",
+//!         "content": "🦀"
+//!     }
+//! }"#)?;
+//!
+//! let object = create.object_unchecked().as_one().expect("One object");
+//! let react = registry
+//!     .materialize(object)?
+//!     .expect("EmojiReact is registered")
+//!     .downcast::<EmojiReact>()
+//!     .expect("EmojiReact was materialized");
+//!
+//! let content = react.content().expect("Content is present");
+//! assert_eq!(content.as_one().map(|c| c.as_str()), Some("🦀"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! The `Any*` enums consult a registry in their `from_base_with` and `from_any_base_with`
+//! constructors, producing the registered type in place of their own variants, including the
+//! generic `Other` fallback.
+use crate::base::{AnyBase, Base, BaseExt, ExtendsExt};
+use std::{any::Any, collections::HashMap, error::Error};
+
+/// The error produced when a registered type fails to materialize
+pub type MaterializeError = Box<dyn Error + Send + Sync>;
+
+type Materializer = Box<
+    dyn Fn(AnyBase) -> Result<Option<Box<dyn Any + Send + Sync>>, MaterializeError> + Send + Sync,
+>;
+
+/// A document read through a `KindRegistry`
+#[derive(Debug)]
+pub enum Materialized<T> {
+    /// The document's `type` was registered, and this holds the registered type
+    ///
+    /// This can be turned into the registered type with `Box::downcast`.
+    Registered(Box<dyn Any + Send + Sync>),
+
+    /// The document's `type` wasn't registered, so it was read as usual
+    Unregistered(T),
+}
+
+impl<T> Materialized<T> {
+    /// Take the registered type, if the document's `type` was registered as `U`
+    pub fn downcast<U>(self) -> Option<Box<U>>
+    where
+        U: Any,
+    {
+        match self {
+            Materialized::Registered(any) => any.downcast().ok(),
+            Materialized::Unregistered(_) => None,
+        }
+    }
+
+    /// Take the value read without the registry, if the document's `type` wasn't registered
+    pub fn unregistered(self) -> Option<T> {
+        match self {
+            Materialized::Registered(_) => None,
+            Materialized::Unregistered(t) => Some(t),
+        }
+    }
+}
+
+/// A mapping from `type` strings to the application-defined types that represent them
+#[derive(Default)]
+pub struct KindRegistry {
+    materializers: HashMap<String, Materializer>,
+}
+
+impl KindRegistry {
+    /// Create an empty registry
+    ///
+    /// ```rust
+    /// use activitystreams::registry::KindRegistry;
+    ///
+    /// let registry = KindRegistry::new();
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register `T` as the type for documents whose `type` is `kind`
+    ///
+    /// Registering a kind again replaces the previous registration.
+    ///
+    /// ```rust
+    /// use activitystreams::{object::GenericObject, registry::KindRegistry};
+    ///
+    /// let mut registry = KindRegistry::new();
+    /// registry.register::<GenericObject>("ChatMessage");
+    ///
+    /// assert!(registry.is_registered("ChatMessage"));
+    /// ```
+    pub fn register<T>(&mut self, kind: &str) -> &mut Self
+    where
+        T: ExtendsExt + Send + Sync + 'static,
+        T::Kind: serde::de::DeserializeOwned,
+        T::Error: From<serde_json::Error> + Error + Send + Sync + 'static,
+    {
+        self.register_with(kind, T::from_any_base)
+    }
+
+    /// Register a function that materializes documents whose `type` is `kind`
+    ///
+    /// This is for types that aren't built with `Extends`. The function returns `Ok(None)` if the
+    /// AnyBase doesn't hold a full document.
+    ///
+    /// ```rust
+    /// use activitystreams::registry::KindRegistry;
+    ///
+    /// let mut registry = KindRegistry::new();
+    /// registry.register_with("ChatMessage", |any_base| {
+    ///     Ok::<_, serde_json::Error>(any_base.id().map(|id| id.to_string()))
+    /// });
+    /// ```
+    pub fn register_with<F, T, E>(&mut self, kind: &str, f: F) -> &mut Self
+    where
+        F: Fn(AnyBase) -> Result<Option<T>, E> + Send + Sync + 'static,
+        T: Send + Sync + 'static,
+        E: Into<MaterializeError>,
+    {
+        self.materializers.insert(
+            kind.to_owned(),
+            Box::new(move |any_base| match (f)(any_base) {
+                Ok(opt) => Ok(opt.map(|t| Box::new(t) as Box<dyn Any + Send + Sync>)),
+                Err(e) => Err(e.into()),
+            }),
+        );
+        self
+    }
+
+    /// Check whether a type has been registered for `kind`
+    pub fn is_registered(&self, kind: &str) -> bool {
+        self.materializers.contains_key(kind)
+    }
+
+    /// Materialize the type registered for the AnyBase's `type`
+    ///
+    /// This returns `Ok(None)` if the AnyBase is only an id, has no `type`, or has a `type` that
+    /// hasn't been registered. The result can be turned into the registered type with
+    /// `Box::downcast`.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// use activitystreams::{base::AnyBase, iri, registry::KindRegistry};
+    ///
+    /// let registry = KindRegistry::new();
+    /// let any_base = AnyBase::from(iri!("https://example.com/objects/1"));
+    ///
+    /// assert!(registry.materialize(&any_base)?.is_none());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn materialize(
+        &self,
+        any_base: &AnyBase,
+    ) -> Result<Option<Box<dyn Any + Send + Sync>>, MaterializeError> {
+        match any_base
+            .kind_str()
+            .and_then(|kind| self.materializers.get(kind))
+        {
+            Some(materializer) => (materializer)(any_base.clone()),
+            None => Ok(None),
+        }
+    }
+}
+
+impl KindRegistry {
+    /// Materialize the type registered for the Base's `type`, or read it with `f`
+    pub(crate) fn materialize_base<T, F>(
+        &self,
+        base: Base<serde_json::Value>,
+        f: F,
+    ) -> Result<Materialized<T>, MaterializeError>
+    where
+        F: FnOnce(Base<serde_json::Value>) -> Result<T, serde_json::Error>,
+    {
+        let materializer = base
+            .kind()
+            .and_then(serde_json::Value::as_str)
+            .and_then(|kind| self.materializers.get(kind));
+
+        if let Some(materializer) = materializer {
+            if let Some(registered) = (materializer)(AnyBase::from_base(base.clone()))? {
+                return Ok(Materialized::Registered(registered));
+            }
+        }
+
+        Ok(Materialized::Unregistered(f(base)?))
+    }
+}
+
+impl std::fmt::Debug for KindRegistry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("KindRegistry")
+            .field("kinds", &self.materializers.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
@@ -0,0 +1,190 @@
+//! Types and functions for following links to remote objects
+//!
+//! This crate doesn't perform any network requests itself. Instead, the functions here accept a
+//! `Resolver`, which is responsible for turning an IRI into the document it identifies, whether
+//! that's by fetching it over HTTP, loading it from a database, or reading it from a cache.
+//!
+//! ```rust
+//! # fn block_on<F: std::future::Future>(future: F) -> F::Output {
+//! #     let mut cx = std::task::Context::from_waker(std::task::Waker::noop());
+//! #     match std::pin::pin!(future).poll(&mut cx) {
+//! #         std::task::Poll::Ready(output) => output,
+//! #         std::task::Poll::Pending => unreachable!(),
+//! #     }
+//! # }
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{
+//!     base::AnyBase,
+//!     iri,
+//!     object::Note,
+//!     prelude::*,
+//!     resolve::{resolve_thread_root, Resolver},
+//! };
+//! use iri_string::types::IriString;
+//! use std::{collections::HashMap, future::Future};
+//!
+//! struct MapResolver(HashMap<IriString, AnyBase>);
+//!
+//! impl Resolver for MapResolver {
+//!     type Error = String;
+//!
+//!     fn resolve(&self, id: &IriString) -> impl Future<Output = Result<AnyBase, String>> + Send {
+//!         std::future::ready(self.0.get(id).cloned().ok_or_else(|| format!("{} not found", id)))
+//!     }
+//! }
+//!
+//! let mut root = Note::new();
+//! root.set_id(iri!("https://example.com/notes/1"));
+//!
+//! let mut parent = Note::new();
+//! parent
+//!     .set_id(iri!("https://example.com/notes/2"))
+//!     .set_in_reply_to(iri!("https://example.com/notes/1"));
+//!
+//! let mut reply = Note::new();
+//! reply
+//!     .set_id(iri!("https://example.com/notes/3"))
+//!     .set_in_reply_to(iri!("https://example.com/notes/2"));
+//!
+//! let mut documents = HashMap::new();
+//! documents.insert(iri!("https://example.com/notes/1"), root.into_any_base()?);
+//! documents.insert(iri!("https://example.com/notes/2"), parent.into_any_base()?);
+//! let resolver = MapResolver(documents);
+//!
+//! // block_on stands in for your executor of choice
+//! let chain = block_on(resolve_thread_root(reply.into_any_base()?, &resolver, 10))
+//!     .map_err(anyhow::Error::msg)?;
+//!
+//! let ids: Vec<_> = chain.iter().filter_map(|object| object.id()).collect();
+//! assert_eq!(
+//!     ids,
+//!     [
+//!         "https://example.com/notes/3",
+//!         "https://example.com/notes/2",
+//!         "https://example.com/notes/1",
+//!     ]
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use crate::{base::AnyBase, primitives::OneOrMany};
+use iri_string::types::IriString;
+use std::{collections::HashSet, future::Future};
+
+/// A source of ActivityStreams documents, keyed by their IRI
+///
+/// Resolvers are shared between tasks and their futures are required to be `Send`, so the
+/// futures returned by the functions in this module can be spawned onto a multi-threaded
+/// executor.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     base::AnyBase,
+///     iri,
+///     object::Note,
+///     prelude::*,
+///     resolve::{resolve_thread_root, Resolver},
+/// };
+/// use iri_string::types::IriString;
+/// use std::{future::Future, sync::Arc};
+///
+/// struct NotFound;
+///
+/// impl Resolver for NotFound {
+///     type Error = String;
+///
+///     fn resolve(&self, id: &IriString) -> impl Future<Output = Result<AnyBase, String>> + Send {
+///         let id = id.clone();
+///
+///         async move {
+///             tokio::task::yield_now().await;
+///             Err(format!("{} not found", id))
+///         }
+///     }
+/// }
+///
+/// let mut reply = Note::new();
+/// reply.set_in_reply_to(iri!("https://example.com/notes/1"));
+/// let reply = reply.into_any_base()?;
+///
+/// let runtime = tokio::runtime::Builder::new_multi_thread().build()?;
+/// let resolver = Arc::new(NotFound);
+///
+/// let result = runtime.block_on(async move {
+///     tokio::spawn(async move { resolve_thread_root(reply, &*resolver, 10).await }).await
+/// })?;
+///
+/// assert_eq!(result.unwrap_err(), "https://example.com/notes/1 not found");
+/// # Ok(())
+/// # }
+/// ```
+pub trait Resolver: Sync {
+    /// The error produced when a document can't be resolved
+    type Error;
+
+    /// Fetch the document identified by `id`
+    fn resolve(&self, id: &IriString) -> impl Future<Output = Result<AnyBase, Self::Error>> + Send;
+}
+
+/// Follow `inReplyTo` from `object` up to the root of its thread
+///
+/// The returned chain starts with `object` and ends with the furthest ancestor that could be
+/// reached. Parents that are embedded in full are used as-is, and parents that are only referenced
+/// by id are fetched through the `resolver`.
+///
+/// Walking stops when an object has no `inReplyTo`, when `max_hops` parents have been added to
+/// the chain, or when a parent has already been seen in the chain. If the last object in the chain
+/// still has an `inReplyTo`, the root was not reached.
+///
+/// Errors from the resolver are returned immediately.
+pub async fn resolve_thread_root<R>(
+    object: AnyBase,
+    resolver: &R,
+    max_hops: usize,
+) -> Result<Vec<AnyBase>, R::Error>
+where
+    R: Resolver + ?Sized,
+{
+    let mut seen: HashSet<IriString> = object.id().cloned().into_iter().collect();
+    let mut chain = vec![object];
+
+    for _ in 0..max_hops {
+        let next = match chain.last().and_then(in_reply_to) {
+            Some(next) => next,
+            None => break,
+        };
+
+        if next.id().is_some_and(|id| seen.contains(id)) {
+            break;
+        }
+
+        let parent = if next.kind_str().is_some() {
+            next
+        } else if let Some(id) = next.id() {
+            resolver.resolve(id).await?
+        } else {
+            break;
+        };
+
+        if let Some(id) = parent.id() {
+            if !seen.insert(id.clone()) {
+                break;
+            }
+        }
+
+        chain.push(parent);
+    }
+
+    Ok(chain)
+}
+
+fn in_reply_to(object: &AnyBase) -> Option<AnyBase> {
+    let value = object.as_base()?.unparsed().get("inReplyTo")?;
+
+    <OneOrMany<AnyBase> as serde::Deserialize>::deserialize(value)
+        .ok()?
+        .iter()
+        .next()
+        .cloned()
+}
@@ -309,11 +309,15 @@ pub trait UnparsedMutExt: UnparsedMut {
 }
 
 /// The Unparsed struct itself,
-#[derive(Clone, Debug, Default, serde::Deserialize, serde::Serialize)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, serde::Deserialize, serde::Serialize)]
 #[serde(transparent)]
 pub struct Unparsed(std::collections::HashMap<String, serde_json::Value>);
 
 impl Unparsed {
+    pub(crate) fn get(&self, key: &str) -> Option<&serde_json::Value> {
+        self.0.get(key)
+    }
+
     pub(crate) fn remove(&mut self, key: &str) -> serde_json::Value {
         self.0.remove(key).unwrap_or(serde_json::Value::Null)
     }
@@ -321,6 +325,10 @@ impl Unparsed {
     pub(crate) fn insert(&mut self, key: String, value: serde_json::Value) {
         self.0.insert(key, value);
     }
+
+    pub(crate) fn values(&self) -> impl Iterator<Item = &serde_json::Value> {
+        self.0.values()
+    }
 }
 
 impl UnparsedMut for Unparsed {
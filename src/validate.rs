@@ -0,0 +1,487 @@
+//! Types and traits for checking documents against the rules of the specification
+//!
+//! Parsing enforces the shape of a document, but some rules span several fields, like `endTime`
+//! not coming before `startTime`, and values set through the builder methods aren't checked at
+//! all. The `Validate` trait reports every such violation in a document, with a JSON Pointer to
+//! where it was found, so outgoing activities can be linted before they are delivered.
+//!
+//! Embedded objects and links are checked too, as the most specific type this crate has for their
+//! `type`.
+//!
+//! ```rust
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{
+//!     activity::Create, datetime, iri, object::Note, prelude::*, validate::Validate,
+//! };
+//!
+//! let mut note = Note::new();
+//! note.set_start_time(datetime!("2020-04-20T04:20:00Z"))
+//!     .set_end_time(datetime!("2020-04-19T04:20:00Z"));
+//!
+//! let mut create = Create::new(iri!("https://example.com/users/asonix"), note.into_any_base()?);
+//! create
+//!     .set_published(datetime!("2020-04-20T04:20:00Z"))
+//!     .set_updated(datetime!("2020-04-19T04:20:00Z"));
+//!
+//! let violations = create.validate();
+//!
+//! assert_eq!(violations.len(), 2);
+//! assert_eq!(violations[0].path, "/updated");
+//! assert_eq!(violations[1].path, "/object/endTime");
+//! assert_eq!(
+//!     violations[1].to_string(),
+//!     "/object/endTime: endTime is before startTime"
+//! );
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    activity::AnyActivity,
+    actor::AnyActor,
+    base::{Base, BaseExt},
+    collection::AnyCollection,
+    link::AnyLink,
+    object::AnyObject,
+    primitives::{
+        AnyString, BoundedXsdString, Either, LanguageTag, NonEmptyXsdString, OneOrMany,
+        RdfLangString, RdfLangStringMap, Unit, XsdBase64Binary, XsdBoolean, XsdDate, XsdDateTime,
+        XsdDouble, XsdDuration, XsdFloat, XsdHexBinary, XsdInteger, XsdNonNegativeInteger,
+        XsdPositiveInteger, XsdTime, XsdUnsignedLong,
+    },
+};
+use iri_string::types::IriString;
+use serde_json::Value;
+use std::fmt;
+
+pub(crate) const ACTIVITY_KINDS: [&str; 28] = [
+    "Accept",
+    "Add",
+    "Announce",
+    "Arrive",
+    "Block",
+    "Create",
+    "Delete",
+    "Dislike",
+    "Flag",
+    "Follow",
+    "Ignore",
+    "Invite",
+    "Join",
+    "Leave",
+    "Like",
+    "Listen",
+    "Move",
+    "Offer",
+    "Question",
+    "Read",
+    "Reject",
+    "Remove",
+    "TentativeAccept",
+    "TentativeReject",
+    "Travel",
+    "Undo",
+    "Update",
+    "View",
+];
+
+pub(crate) const ACTOR_KINDS: [&str; 5] =
+    ["Application", "Group", "Organization", "Person", "Service"];
+
+pub(crate) const COLLECTION_KINDS: [&str; 4] = [
+    "Collection",
+    "OrderedCollection",
+    "CollectionPage",
+    "OrderedCollectionPage",
+];
+
+pub(crate) const LINK_KINDS: [&str; 3] = ["Link", "Mention", "Hashtag"];
+
+pub(crate) const OBJECT_KINDS: [&str; 13] = [
+    "Object",
+    "Article",
+    "Audio",
+    "Document",
+    "Event",
+    "Image",
+    "Note",
+    "Page",
+    "Place",
+    "Profile",
+    "Relationship",
+    "Tombstone",
+    "Video",
+];
+
+/// A single rule a document breaks
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Violation {
+    /// The JSON Pointer to the offending value
+    pub path: String,
+
+    /// A description of the rule that was broken
+    pub message: String,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let path = if self.path.is_empty() {
+            "/"
+        } else {
+            &self.path
+        };
+
+        write!(f, "{}: {}", path, self.message)
+    }
+}
+
+/// The violations found while validating a value, and the path currently being validated
+#[derive(Debug, Default)]
+pub struct Violations {
+    path: String,
+    found: Vec<Violation>,
+}
+
+impl Violations {
+    /// Create an empty set of violations, rooted at the top of the document
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record a violation at the current path
+    pub fn report<T>(&mut self, message: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.found.push(Violation {
+            path: self.path.clone(),
+            message: message.into(),
+        });
+        self
+    }
+
+    /// Record a violation at a field of the current path
+    pub fn report_field<T>(&mut self, name: &str, message: T) -> &mut Self
+    where
+        T: Into<String>,
+    {
+        self.at(name, |violations| {
+            violations.report(message);
+        })
+    }
+
+    /// Validate a field of the current value
+    ///
+    /// The field's name is added to the path of any violations it reports.
+    pub fn field<T>(&mut self, name: &str, value: &T) -> &mut Self
+    where
+        T: Validate + ?Sized,
+    {
+        self.at(name, |violations| value.validate_into(violations))
+    }
+
+    /// Run `f` with a segment added to the current path
+    pub fn at<F>(&mut self, segment: &str, f: F) -> &mut Self
+    where
+        F: FnOnce(&mut Self),
+    {
+        let len = self.path.len();
+
+        self.path.push('/');
+        self.path
+            .push_str(&segment.replace('~', "~0").replace('/', "~1"));
+        f(self);
+        self.path.truncate(len);
+        self
+    }
+
+    /// Check whether the current path is the top of the document
+    pub(crate) fn at_root(&self) -> bool {
+        self.path.is_empty()
+    }
+
+    /// Check whether no violations were found
+    pub fn is_empty(&self) -> bool {
+        self.found.is_empty()
+    }
+
+    /// Retrieve the violations that were found
+    pub fn into_vec(self) -> Vec<Violation> {
+        self.found
+    }
+}
+
+/// A value that can be checked against the rules of the specification
+///
+/// Implement `validate_into` for extension types, delegating to the type being extended and
+/// reporting the extension's own fields.
+///
+/// ```rust
+/// use activitystreams::{
+///     object::Note,
+///     prelude::*,
+///     validate::{Validate, Violations},
+/// };
+///
+/// struct Poll {
+///     votes: i64,
+///     inner: Note,
+/// }
+///
+/// impl Validate for Poll {
+///     fn validate_into(&self, violations: &mut Violations) {
+///         if self.votes < 0 {
+///             violations.report_field("votes", "votes can't be negative");
+///         }
+///
+///         self.inner.validate_into(violations);
+///     }
+/// }
+///
+/// let poll = Poll { votes: -1, inner: Note::new() };
+///
+/// assert_eq!(poll.validate()[0].path, "/votes");
+/// ```
+pub trait Validate {
+    /// Check the value, reporting violations relative to the current path of `violations`
+    fn validate_into(&self, violations: &mut Violations);
+
+    /// Check the value, returning every violation found
+    fn validate(&self) -> Vec<Violation> {
+        let mut violations = Violations::new();
+        self.validate_into(&mut violations);
+        violations.into_vec()
+    }
+
+    /// Check the value, returning it if no violations were found
+    ///
+    /// This finishes a chain of the consuming `with_*` setters, the way `build` finishes a
+    /// builder.
+    ///
+    /// ```rust
+    /// # fn main() -> Result<(), anyhow::Error> {
+    /// use activitystreams::{datetime, object::Note, prelude::*, validate::Validate};
+    ///
+    /// let note = Note::new()
+    ///     .with_content("hi")
+    ///     .with_published(datetime!("2020-04-20T04:20:00Z"))
+    ///     .validated()
+    ///     .expect("The note is valid");
+    ///
+    /// let violations = Note::new()
+    ///     .with_published(datetime!("2020-04-20T04:20:00Z"))
+    ///     .with_updated(datetime!("2020-04-19T04:20:00Z"))
+    ///     .validated()
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(violations[0].path, "/updated");
+    /// # Ok(())
+    /// # }
+    /// ```
+    fn validated(self) -> Result<Self, Vec<Violation>>
+    where
+        Self: Sized,
+    {
+        let violations = self.validate();
+
+        if violations.is_empty() {
+            Ok(self)
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl<T> Validate for &T
+where
+    T: Validate + ?Sized,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        (**self).validate_into(violations)
+    }
+}
+
+impl<T> Validate for Box<T>
+where
+    T: Validate + ?Sized,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        (**self).validate_into(violations)
+    }
+}
+
+impl<T> Validate for Option<T>
+where
+    T: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        if let Some(value) = self {
+            value.validate_into(violations);
+        }
+    }
+}
+
+impl<T> Validate for [T]
+where
+    T: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        for (index, value) in self.iter().enumerate() {
+            violations.field(&index.to_string(), value);
+        }
+    }
+}
+
+impl<T> Validate for Vec<T>
+where
+    T: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        self.as_slice().validate_into(violations)
+    }
+}
+
+impl<T> Validate for OneOrMany<T>
+where
+    T: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        match (self.as_one(), self.as_many()) {
+            (Some(one), _) => one.validate_into(violations),
+            (_, Some(many)) => many.validate_into(violations),
+            _ => {}
+        }
+    }
+}
+
+impl<L, R> Validate for Either<L, R>
+where
+    L: Validate,
+    R: Validate,
+{
+    fn validate_into(&self, violations: &mut Violations) {
+        match self {
+            Either::Left(left) => left.validate_into(violations),
+            Either::Right(right) => right.validate_into(violations),
+        }
+    }
+}
+
+impl<const MAX: usize> Validate for BoundedXsdString<MAX> {
+    fn validate_into(&self, _: &mut Violations) {}
+}
+
+impl Validate for XsdFloat {
+    fn validate_into(&self, violations: &mut Violations) {
+        if !self.0.is_finite() {
+            violations.report("xsd:float must be a finite number");
+        }
+    }
+}
+
+impl Validate for XsdDouble {
+    fn validate_into(&self, violations: &mut Violations) {
+        if !self.0.is_finite() {
+            violations.report("xsd:double must be a finite number");
+        }
+    }
+}
+
+impl Validate for RdfLangStringMap {
+    fn validate_into(&self, violations: &mut Violations) {
+        for (language, _) in self.iter() {
+            if LanguageTag::parse(language).is_err() {
+                violations.report_field(language, "key is not a well-formed language tag");
+            }
+        }
+    }
+}
+
+macro_rules! always_valid {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Validate for $ty {
+                fn validate_into(&self, _: &mut Violations) {}
+            }
+        )*
+    };
+}
+
+always_valid!(
+    AnyString,
+    IriString,
+    LanguageTag,
+    NonEmptyXsdString,
+    RdfLangString,
+    String,
+    Unit,
+    XsdBase64Binary,
+    XsdBoolean,
+    XsdDate,
+    XsdDateTime,
+    XsdDuration,
+    XsdHexBinary,
+    XsdInteger,
+    XsdNonNegativeInteger,
+    XsdPositiveInteger,
+    XsdTime,
+    XsdUnsignedLong,
+);
+
+/// Validate an embedded object or link as the most specific type this crate has for its `type`
+pub(crate) fn validate_embedded(base: &Base<Value>, violations: &mut Violations) {
+    let kind = base.kind().and_then(Value::as_str).unwrap_or_default();
+
+    let result = if ACTIVITY_KINDS.contains(&kind) {
+        AnyActivity::from_base(base.clone()).map(|activity| activity.validate_into(violations))
+    } else if ACTOR_KINDS.contains(&kind) {
+        // Embedded actors often leave out the inbox and outbox ActivityPub requires. Ones without
+        // an inbox are read as objects, and ApActor only requires an outbox at the top level
+        AnyActor::from_base(base.clone())
+            .map(|actor| actor.validate_into(violations))
+            .or_else(|_| {
+                AnyObject::from_base(base.clone()).map(|object| object.validate_into(violations))
+            })
+    } else if COLLECTION_KINDS.contains(&kind) {
+        AnyCollection::from_base(base.clone())
+            .map(|collection| collection.validate_into(violations))
+    } else if LINK_KINDS.contains(&kind) {
+        AnyLink::from_base(base.clone()).map(|link| link.validate_into(violations))
+    } else {
+        AnyObject::from_base(base.clone()).map(|object| object.validate_into(violations))
+    };
+
+    if let Err(e) = result {
+        violations.report(format!("could not be read: {}", e));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Validate;
+    use crate::{activity::Create, actor::AnyActor};
+
+    #[test]
+    fn only_require_outbox_at_top_level() {
+        let actor = r#"{
+            "type": "Person",
+            "id": "https://example.com/users/asonix",
+            "inbox": "https://example.com/users/asonix/inbox"
+        }"#;
+        let create = format!(
+            r#"{{
+                "type": "Create",
+                "actor": {},
+                "object": "https://example.com/notes/1"
+            }}"#,
+            actor
+        );
+
+        let create: Create = serde_json::from_str(&create).unwrap();
+        assert!(create.validate().is_empty());
+
+        let actor: AnyActor = serde_json::from_str(actor).unwrap();
+        let violations = actor.validate();
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].to_string(), "/: an actor must have an outbox");
+    }
+}
@@ -0,0 +1,862 @@
+//! Types and functions for traversing every node of an ActivityStreams document
+//!
+//! Properties that point to other objects are stored as `AnyBase`s, which in turn keep anything
+//! they don't know about as unparsed JSON, so the only reliable way to see the whole graph is to
+//! look at its JSON representation. The `walk` function serializes a value and calls into a
+//! `Visitor` for every IRI, Object, and Link it finds, including ones nested inside `tag`,
+//! `attachment`, `object`, `target`, `items`, and other properties. The `rewrite_uris`,
+//! `redact`, and `clone_with_new_ids` functions use the same traversal to replace every IRI in a
+//! document, to strip properties from it, and to re-identify the objects it contains, and the
+//! `Profiled` wrapper uses it to reduce nested objects to their ids when serializing.
+//!
+//! ```rust
+//! # fn main() -> Result<(), anyhow::Error> {
+//! use activitystreams::{
+//!     activity::Create,
+//!     base::Base,
+//!     iri,
+//!     object::Note,
+//!     prelude::*,
+//!     walk::{walk, Visitor},
+//! };
+//! use iri_string::types::IriString;
+//!
+//! #[derive(Default)]
+//! struct CountVisitor {
+//!     uris: usize,
+//!     objects: usize,
+//! }
+//!
+//! impl Visitor for CountVisitor {
+//!     fn visit_uri(&mut self, _: &IriString) {
+//!         self.uris += 1;
+//!     }
+//!
+//!     fn visit_object(&mut self, _: &Base<serde_json::Value>) {
+//!         self.objects += 1;
+//!     }
+//! }
+//!
+//! let mut note = Note::new();
+//! note.set_id(iri!("https://example.com/notes/1"));
+//!
+//! let create = Create::new(
+//!     iri!("https://example.com/users/asonix"),
+//!     note.into_any_base()?,
+//! );
+//!
+//! let mut visitor = CountVisitor::default();
+//! walk(&create, &mut visitor)?;
+//!
+//! assert_eq!(visitor.uris, 2);
+//! assert_eq!(visitor.objects, 2);
+//! # Ok(())
+//! # }
+//! ```
+use crate::{
+    base::Base,
+    validate::{ACTIVITY_KINDS, ACTOR_KINDS, COLLECTION_KINDS, LINK_KINDS, OBJECT_KINDS},
+};
+use iri_string::types::IriString;
+use serde::de::Deserialize;
+use serde_json::{Map, Value};
+
+/// Properties defined by the spec that can contain an IRI
+///
+/// Strings found in any other property are left alone, since they're likely to be content rather
+/// than references. Resolving relative references in `parse_with_base` uses the same list.
+pub(crate) const URI_PROPERTIES: &[&str] = &[
+    "actor",
+    "anyOf",
+    "attachment",
+    "attributedTo",
+    "audience",
+    "bcc",
+    "bto",
+    "cc",
+    "closed",
+    "context",
+    "current",
+    "describes",
+    "first",
+    "followers",
+    "following",
+    "generator",
+    "href",
+    "icon",
+    "id",
+    "image",
+    "inReplyTo",
+    "inbox",
+    "instrument",
+    "items",
+    "last",
+    "liked",
+    "likes",
+    "location",
+    "next",
+    "oauthAuthorizationEndpoint",
+    "oauthTokenEndpoint",
+    "object",
+    "oneOf",
+    "orderedItems",
+    "origin",
+    "outbox",
+    "partOf",
+    "prev",
+    "preview",
+    "provideClientKey",
+    "proxyUrl",
+    "relationship",
+    "replies",
+    "result",
+    "sharedInbox",
+    "shares",
+    "signClientKey",
+    "streams",
+    "subject",
+    "tag",
+    "target",
+    "to",
+    "uploadMedia",
+    "url",
+];
+
+/// Callbacks invoked by `walk` for each node of a document
+///
+/// Every method has an empty default implementation, so visitors only need to implement the ones
+/// they're interested in. Nodes are visited before their children.
+pub trait Visitor {
+    /// Called for every IRI found in an `id` or in a property that references other objects
+    ///
+    /// Strings that aren't valid IRIs are skipped
+    fn visit_uri(&mut self, _uri: &IriString) {}
+
+    /// Called for the value being walked, and for every JSON object that isn't a Link in a property
+    /// that references other objects, like `object`, `tag`, or `items`
+    ///
+    /// Maps in other properties, like `contentMap`, `source`, or `publicKey`, aren't objects and
+    /// aren't passed to the visitor, though IRIs inside them are.
+    ///
+    /// The Base can be extended into a specific type by calling `solidify` and `extend`
+    fn visit_object(&mut self, _object: &Base<serde_json::Value>) {}
+
+    /// Called for every Link in the document
+    ///
+    /// JSON objects are considered Links if their `type` is `Link`, `Mention`, or `Hashtag`, or if
+    /// they have no `type` but do have an `href`
+    fn visit_link(&mut self, _link: &Base<serde_json::Value>) {}
+
+    /// Called for every nested object or link that can't be represented as a `Base`, for example
+    /// because its `id` is not a valid IRI
+    ///
+    /// The node isn't passed to `visit_object` or `visit_link`, but the nodes inside it are still
+    /// visited.
+    fn visit_error(&mut self, _error: serde_json::Error) {}
+}
+
+/// Traverse the given value, calling into the visitor for every IRI, Object, and Link it contains
+///
+/// This produces an error if the value cannot be serialized. Objects nested in the value that
+/// can't be represented as a `Base` are passed to `Visitor::visit_error`, and the rest of the
+/// document is still walked.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     base::Base,
+///     iri,
+///     link::Mention,
+///     object::Note,
+///     prelude::*,
+///     walk::{walk, Visitor},
+/// };
+///
+/// struct MentionCollector(Vec<String>);
+///
+/// impl Visitor for MentionCollector {
+///     fn visit_link(&mut self, link: &Base<serde_json::Value>) {
+///         if link.is_kind(&serde_json::json!("Mention")) {
+///             if let Some(id) = link.id_unchecked() {
+///                 self.0.push(id.to_string());
+///             }
+///         }
+///     }
+/// }
+///
+/// let mut mention = Mention::new();
+/// mention.set_id(iri!("https://example.com/users/asonix"));
+///
+/// let mut note = Note::new();
+/// note.add_tag(mention.into_any_base()?);
+///
+/// let mut collector = MentionCollector(vec![]);
+/// walk(&note, &mut collector)?;
+///
+/// assert_eq!(collector.0, vec!["https://example.com/users/asonix"]);
+/// # Ok(())
+/// # }
+/// ```
+pub fn walk<T, V>(value: &T, visitor: &mut V) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize,
+    V: Visitor + ?Sized,
+{
+    let value = serde_json::to_value(value)?;
+
+    walk_value(&value, true, visitor);
+
+    Ok(())
+}
+
+/// Apply a mapping function to every IRI in the given value
+///
+/// This covers the same IRIs that `walk` passes to `Visitor::visit_uri`, including ids, hrefs,
+/// and addressing, in the value itself and in every object and link nested inside it. This is
+/// useful for proxying media, serving a document from a mirror, or relocating test fixtures to a
+/// different domain.
+///
+/// This produces an error if the value cannot be serialized, or if the rewritten document can no
+/// longer be deserialized as the original type.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     activity::Create,
+///     iri,
+///     object::Note,
+///     prelude::*,
+///     walk::rewrite_uris,
+/// };
+///
+/// let mut note = Note::new();
+/// note.set_id(iri!("https://example.com/notes/1"))
+///     .set_attributed_to(iri!("https://example.com/users/asonix"));
+///
+/// let mut create = Create::new(
+///     iri!("https://example.com/users/asonix"),
+///     note.into_any_base()?,
+/// );
+/// create.set_id(iri!("https://example.com/activities/1"));
+///
+/// rewrite_uris(&mut create, |uri| {
+///     uri.as_str()
+///         .replacen("https://example.com", "https://mirror.example.com", 1)
+///         .parse()
+///         .unwrap()
+/// })?;
+///
+/// assert_eq!(
+///     create.id_unchecked().unwrap().as_str(),
+///     "https://mirror.example.com/activities/1"
+/// );
+/// assert_eq!(
+///     create.actor_unchecked().as_single_id().unwrap().as_str(),
+///     "https://mirror.example.com/users/asonix"
+/// );
+///
+/// let note = Note::from_any_base(create.object_unchecked().as_one().unwrap().clone())?.unwrap();
+/// assert_eq!(
+///     note.id_unchecked().unwrap().as_str(),
+///     "https://mirror.example.com/notes/1"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn rewrite_uris<T, F>(value: &mut T, mut f: F) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize + serde::de::DeserializeOwned,
+    F: FnMut(&IriString) -> IriString,
+{
+    let mut json = serde_json::to_value(&*value)?;
+
+    rewrite_value(&mut json, true, &mut f);
+
+    *value = serde_json::from_value(json)?;
+
+    Ok(())
+}
+
+/// Remove the named properties from the given value and from every object and link nested in it
+///
+/// Property names are the ones used in JSON, e.g. `bto` or `attributedTo`. This is useful for
+/// logging stored activities, or for serving them publicly, without exposing private addressing
+/// or other sensitive properties.
+///
+/// This produces an error if the value cannot be serialized, or if the redacted document can no
+/// longer be deserialized as the original type, for example because a required property like an
+/// Activity's `actor` was removed. The value is left unchanged in that case.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     activity::Create,
+///     iri,
+///     object::Note,
+///     prelude::*,
+///     walk::redact,
+/// };
+///
+/// let mut note = Note::new();
+/// note.set_content("hi")
+///     .set_bcc(iri!("https://example.com/users/secret"));
+///
+/// let mut create = Create::new(
+///     iri!("https://example.com/users/asonix"),
+///     note.into_any_base()?,
+/// );
+/// create.set_bto(iri!("https://example.com/users/secret"));
+///
+/// redact(&mut create, &["bto", "bcc"])?;
+///
+/// let json = serde_json::to_string(&create)?;
+///
+/// assert!(create.bto().is_none());
+/// assert!(!json.contains("secret"));
+/// # Ok(())
+/// # }
+/// ```
+pub fn redact<T>(value: &mut T, fields: &[&str]) -> Result<(), serde_json::Error>
+where
+    T: serde::ser::Serialize + serde::de::DeserializeOwned,
+{
+    let mut json = serde_json::to_value(&*value)?;
+
+    redact_value(&mut json, fields);
+
+    *value = serde_json::from_value(json)?;
+
+    Ok(())
+}
+
+/// Produce a copy of the given value with new ids for it and for the objects embedded in it
+///
+/// The `id_gen` function is called with a `Node` for the value itself, and for every Object,
+/// Activity, Actor, and Collection embedded in a property that references other objects, like
+/// `object`, `attachment`, or `items`. Returning `Some` replaces that node's id, while returning
+/// `None` leaves it as it was, which is how objects that shouldn't be re-identified, such as ones
+/// owned by other servers, can be skipped.
+///
+/// Links like `Mention` and `Hashtag`, maps whose `type` isn't defined by the spec, like Mastodon's
+/// `PropertyValue`, and maps in other properties, like `publicKey`, `source`, or `contentMap`, are
+/// never passed to `id_gen`, and IRIs that only reference other objects are left alone.
+///
+/// This is useful when materializing local copies of remote content, or when wrapping content in
+/// a new activity.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     activity::Create,
+///     iri,
+///     link::Mention,
+///     object::Note,
+///     prelude::*,
+///     walk::clone_with_new_ids,
+/// };
+///
+/// let mut mention = Mention::new();
+/// mention.set_href(iri!("https://remote.example.com/users/friend"));
+///
+/// let mut note = Note::new();
+/// note.set_id(iri!("https://remote.example.com/notes/1"))
+///     .add_tag(mention.into_any_base()?);
+///
+/// let mut create = Create::new(
+///     iri!("https://remote.example.com/users/asonix"),
+///     note.into_any_base()?,
+/// );
+/// create.set_id(iri!("https://remote.example.com/activities/1"));
+///
+/// let mut paths = vec![];
+/// let local = clone_with_new_ids(&create, |node| {
+///     paths.push(node.path().to_owned());
+///     let kind = node.kind()?.to_lowercase();
+///     Some(format!("https://local.example.com/{}/{}", kind, paths.len()).parse().unwrap())
+/// })?;
+///
+/// assert_eq!(paths, ["", "/object"]);
+/// assert_eq!(
+///     local.id_unchecked().unwrap().as_str(),
+///     "https://local.example.com/create/1"
+/// );
+/// assert_eq!(
+///     local.object_unchecked().as_one().unwrap().id().unwrap().as_str(),
+///     "https://local.example.com/note/2"
+/// );
+/// assert_eq!(
+///     local.actor_unchecked().as_single_id().unwrap().as_str(),
+///     "https://remote.example.com/users/asonix"
+/// );
+/// # Ok(())
+/// # }
+/// ```
+pub fn clone_with_new_ids<T, F>(value: &T, mut id_gen: F) -> Result<T, serde_json::Error>
+where
+    T: serde::ser::Serialize + serde::de::DeserializeOwned,
+    F: FnMut(Node<'_>) -> Option<IriString>,
+{
+    let mut json = serde_json::to_value(value)?;
+    let mut path = String::new();
+
+    match &mut json {
+        Value::Object(map) => reidentify_map(map, true, &mut path, &mut id_gen),
+        value => reidentify_value(value, &mut path, &mut id_gen),
+    }
+
+    serde_json::from_value(json)
+}
+
+/// A node of a document, as passed to the `id_gen` function of `clone_with_new_ids`
+#[derive(Clone, Copy, Debug)]
+pub struct Node<'a> {
+    path: &'a str,
+    json: &'a Map<String, Value>,
+}
+
+impl<'a> Node<'a> {
+    /// The JSON Pointer to this node, which is empty for the value being cloned
+    pub fn path(&self) -> &'a str {
+        self.path
+    }
+
+    /// The node's current id, if it has one that is a valid IRI
+    pub fn id(&self) -> Option<IriString> {
+        self.json.get("id")?.as_str()?.parse().ok()
+    }
+
+    /// The node's `type`, if it is a single string
+    pub fn kind(&self) -> Option<&'a str> {
+        self.json.get("type")?.as_str()
+    }
+
+    /// The node's JSON
+    pub fn json(&self) -> &'a Map<String, Value> {
+        self.json
+    }
+}
+
+/// Controls how much of a document's nested objects are included when serializing it
+///
+/// Objects are only reduced when they appear in a property that references other objects and
+/// they have an `id` to be reduced to. Objects without ids, and the top-level value itself, are
+/// always serialized in full.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum SerializeProfile {
+    /// Serialize every nested object inline
+    #[default]
+    Full,
+
+    /// Serialize objects nested directly in the value inline, but reduce anything nested more
+    /// deeply to its `id`
+    Embedded,
+
+    /// Reduce every nested object to its `id`
+    Reference,
+}
+
+/// A wrapper that serializes the inner value according to a `SerializeProfile`
+///
+/// This lets the same in-memory data be serialized with embedded objects for delivery, and with
+/// only references for storage.
+///
+/// ```rust
+/// # fn main() -> Result<(), anyhow::Error> {
+/// use activitystreams::{
+///     activity::Create,
+///     iri,
+///     object::Note,
+///     prelude::*,
+///     walk::{Profiled, SerializeProfile},
+/// };
+///
+/// let mut note = Note::new();
+/// note.set_id(iri!("https://example.com/notes/1"))
+///     .set_content("hi");
+///
+/// let create = Create::new(
+///     iri!("https://example.com/users/asonix"),
+///     note.into_any_base()?,
+/// );
+///
+/// let full = serde_json::to_string(&Profiled::new(&create, SerializeProfile::Full))?;
+/// let reference = serde_json::to_string(&Profiled::new(&create, SerializeProfile::Reference))?;
+///
+/// assert!(full.contains(r#""content":"hi""#));
+/// assert!(reference.contains(r#""object":"https://example.com/notes/1""#));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Profiled<'a, T> {
+    value: &'a T,
+    profile: SerializeProfile,
+}
+
+impl<'a, T> Profiled<'a, T> {
+    /// Wrap a value to be serialized with the given profile
+    ///
+    /// ```rust
+    /// use activitystreams::{
+    ///     object::Video,
+    ///     walk::{Profiled, SerializeProfile},
+    /// };
+    ///
+    /// let video = Video::new();
+    ///
+    /// let profiled = Profiled::new(&video, SerializeProfile::Embedded);
+    /// ```
+    pub fn new(value: &'a T, profile: SerializeProfile) -> Self {
+        Profiled { value, profile }
+    }
+}
+
+impl<'a, T> serde::ser::Serialize for Profiled<'a, T>
+where
+    T: serde::ser::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::ser::Serializer,
+    {
+        let max_depth = match self.profile {
+            SerializeProfile::Full => return self.value.serialize(serializer),
+            SerializeProfile::Embedded => 1,
+            SerializeProfile::Reference => 0,
+        };
+
+        let mut json = serde_json::to_value(self.value).map_err(serde::ser::Error::custom)?;
+
+        if let Value::Object(map) = &mut json {
+            for (key, value) in map {
+                if URI_PROPERTIES.contains(&key.as_str()) {
+                    reduce_value(value, 0, max_depth);
+                }
+            }
+        }
+
+        json.serialize(serializer)
+    }
+}
+
+fn walk_value<V>(value: &Value, uri_property: bool, visitor: &mut V)
+where
+    V: Visitor + ?Sized,
+{
+    match value {
+        Value::String(s) if uri_property => {
+            if let Ok(uri) = s.parse::<IriString>() {
+                visitor.visit_uri(&uri);
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                walk_value(value, uri_property, visitor);
+            }
+        }
+        Value::Object(map) => {
+            if uri_property {
+                visit_node(value, map, visitor);
+            }
+
+            for (key, value) in map {
+                if key == "@context" {
+                    continue;
+                }
+
+                walk_value(value, URI_PROPERTIES.contains(&key.as_str()), visitor);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn visit_node<V>(value: &Value, map: &Map<String, Value>, visitor: &mut V)
+where
+    V: Visitor + ?Sized,
+{
+    match Base::<Value>::deserialize(value) {
+        Ok(base) if is_link(map) => visitor.visit_link(&base),
+        Ok(base) => visitor.visit_object(&base),
+        Err(e) => visitor.visit_error(e),
+    }
+}
+
+fn is_link(map: &Map<String, Value>) -> bool {
+    match map.get("type") {
+        Some(Value::String(kind)) => LINK_KINDS.contains(&kind.as_str()),
+        Some(_) => false,
+        None => map.contains_key("href"),
+    }
+}
+
+fn rewrite_value<F>(value: &mut Value, uri_property: bool, f: &mut F)
+where
+    F: FnMut(&IriString) -> IriString,
+{
+    match value {
+        Value::String(s) if uri_property => {
+            if let Ok(uri) = s.parse::<IriString>() {
+                *s = f(&uri).into();
+            }
+        }
+        Value::Array(values) => {
+            for value in values {
+                rewrite_value(value, uri_property, f);
+            }
+        }
+        Value::Object(map) => {
+            for (key, value) in map {
+                if key == "@context" {
+                    continue;
+                }
+
+                rewrite_value(value, URI_PROPERTIES.contains(&key.as_str()), f);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn redact_value(value: &mut Value, fields: &[&str]) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                redact_value(value, fields);
+            }
+        }
+        Value::Object(map) => {
+            map.retain(|key, _| !fields.contains(&key.as_str()));
+
+            for (key, value) in map {
+                if key == "@context" {
+                    continue;
+                }
+
+                redact_value(value, fields);
+            }
+        }
+        _ => (),
+    }
+}
+
+fn reidentify_value<F>(value: &mut Value, path: &mut String, id_gen: &mut F)
+where
+    F: FnMut(Node<'_>) -> Option<IriString>,
+{
+    match value {
+        Value::Array(values) => {
+            for (index, value) in values.iter_mut().enumerate() {
+                let len = path.len();
+                path.push_str(&format!("/{}", index));
+                reidentify_value(value, path, id_gen);
+                path.truncate(len);
+            }
+        }
+        Value::Object(map) => reidentify_map(map, false, path, id_gen),
+        _ => (),
+    }
+}
+
+fn reidentify_map<F>(map: &mut Map<String, Value>, root: bool, path: &mut String, id_gen: &mut F)
+where
+    F: FnMut(Node<'_>) -> Option<IriString>,
+{
+    if root || is_object(map) {
+        if let Some(new_id) = id_gen(Node { path, json: map }) {
+            map.insert("id".to_owned(), Value::String(new_id.into()));
+        }
+    }
+
+    for (key, value) in map {
+        if key == "id" || !URI_PROPERTIES.contains(&key.as_str()) {
+            continue;
+        }
+
+        let len = path.len();
+        path.push('/');
+        path.push_str(key);
+        reidentify_value(value, path, id_gen);
+        path.truncate(len);
+    }
+}
+
+/// Whether a JSON object's `type` is an Object, Activity, Actor, or Collection kind from the spec
+fn is_object(map: &Map<String, Value>) -> bool {
+    let is_object_kind = |kind: &Value| {
+        kind.as_str().is_some_and(|kind| {
+            OBJECT_KINDS.contains(&kind)
+                || ACTIVITY_KINDS.contains(&kind)
+                || ACTOR_KINDS.contains(&kind)
+                || COLLECTION_KINDS.contains(&kind)
+        })
+    };
+
+    match map.get("type") {
+        Some(Value::Array(kinds)) => kinds.iter().any(is_object_kind),
+        Some(kind) => is_object_kind(kind),
+        None => false,
+    }
+}
+
+fn reduce_value(value: &mut Value, depth: usize, max_depth: usize) {
+    match value {
+        Value::Array(values) => {
+            for value in values {
+                reduce_value(value, depth, max_depth);
+            }
+        }
+        Value::Object(map) => {
+            if depth >= max_depth {
+                if let Some(id) = map.get("id") {
+                    *value = id.clone();
+                    return;
+                }
+            }
+
+            for (key, value) in map {
+                if URI_PROPERTIES.contains(&key.as_str()) {
+                    reduce_value(value, depth + 1, max_depth);
+                }
+            }
+        }
+        _ => (),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{clone_with_new_ids, walk, Visitor};
+    use crate::{
+        actor::{ApActor, Person},
+        base::Base,
+    };
+    use iri_string::types::IriString;
+    use serde_json::json;
+
+    #[derive(Default)]
+    struct Recorder {
+        uris: Vec<String>,
+        objects: usize,
+        links: usize,
+        errors: usize,
+    }
+
+    impl Visitor for Recorder {
+        fn visit_uri(&mut self, uri: &IriString) {
+            self.uris.push(uri.to_string());
+        }
+
+        fn visit_object(&mut self, _: &Base<serde_json::Value>) {
+            self.objects += 1;
+        }
+
+        fn visit_link(&mut self, _: &Base<serde_json::Value>) {
+            self.links += 1;
+        }
+
+        fn visit_error(&mut self, _: serde_json::Error) {
+            self.errors += 1;
+        }
+    }
+
+    #[test]
+    fn keep_walking_past_malformed_nodes() {
+        let json = json!({
+            "type": "Note",
+            "attachment": [
+                { "type": "Image", "id": "not an iri", "url": "https://example.com/a.png" },
+                { "type": "Image", "url": "https://example.com/b.png" }
+            ],
+            "tag": { "type": "Mention", "href": "https://example.com/users/friend" }
+        });
+
+        let mut recorder = Recorder::default();
+        walk(&json, &mut recorder).unwrap();
+
+        assert_eq!(recorder.errors, 1);
+        assert_eq!(recorder.objects, 2);
+        assert_eq!(recorder.links, 1);
+        assert_eq!(
+            recorder.uris,
+            [
+                "https://example.com/a.png",
+                "https://example.com/b.png",
+                "https://example.com/users/friend"
+            ]
+        );
+    }
+
+    #[test]
+    fn only_visit_maps_in_object_properties() {
+        let json = json!({
+            "type": "Person",
+            "contentMap": { "en": "hi" },
+            "source": { "content": "hi", "mediaType": "text/markdown" },
+            "publicKey": {
+                "id": "https://example.com/users/asonix#main-key",
+                "owner": "https://example.com/users/asonix"
+            },
+            "endpoints": { "sharedInbox": "https://example.com/inbox" }
+        });
+
+        let mut recorder = Recorder::default();
+        walk(&json, &mut recorder).unwrap();
+
+        assert_eq!(recorder.objects, 1);
+        assert_eq!(
+            recorder.uris,
+            [
+                "https://example.com/inbox",
+                "https://example.com/users/asonix#main-key"
+            ]
+        );
+    }
+
+    #[test]
+    fn only_reidentify_objects() {
+        let json = json!({
+            "type": "Person",
+            "id": "https://remote.example.com/users/asonix",
+            "inbox": "https://remote.example.com/users/asonix/inbox",
+            "publicKey": {
+                "id": "https://remote.example.com/users/asonix#main-key",
+                "owner": "https://remote.example.com/users/asonix"
+            },
+            "tag": [
+                { "type": "Hashtag", "href": "https://remote.example.com/tags/rust", "name": "#rust" },
+                { "type": "Mention", "href": "https://remote.example.com/users/friend" }
+            ],
+            "attachment": [
+                { "type": "PropertyValue", "name": "Website", "value": "example.com" },
+                { "type": "Image", "url": "https://remote.example.com/avatar.png" }
+            ]
+        });
+        let person: ApActor<Person> = serde_json::from_value(json).unwrap();
+
+        let mut paths = vec![];
+        let local = clone_with_new_ids(&person, |node| {
+            paths.push(node.path().to_owned());
+            format!("https://local.example.com/objects/{}", paths.len())
+                .parse::<IriString>()
+                .ok()
+        })
+        .unwrap();
+
+        assert_eq!(paths, ["", "/attachment/1"]);
+
+        let local = serde_json::to_value(&local).unwrap();
+        assert_eq!(local["id"], "https://local.example.com/objects/1");
+        assert_eq!(
+            local["publicKey"]["id"],
+            "https://remote.example.com/users/asonix#main-key"
+        );
+        assert!(local["tag"][0].get("id").is_none());
+        assert!(local["tag"][1].get("id").is_none());
+        assert!(local["attachment"][0].get("id").is_none());
+        assert_eq!(
+            local["attachment"][1]["id"],
+            "https://local.example.com/objects/2"
+        );
+    }
+}